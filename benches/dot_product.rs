@@ -0,0 +1,80 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use matrix::bench_utils::{random_dyn_matrix, random_matrix, seeded_rng};
+use matrix::dynamic::DynMatrics;
+use matrix::Matrix;
+
+const SEED: u64 = 42;
+
+// 方阵规模扫描：串行/并行 × Matrix/DynMatrics
+fn bench_sizes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sizes");
+    let threads = num_cpus::get();
+
+    macro_rules! sizes {
+        ($($n:literal),*) => {$(
+            let mut rng = seeded_rng(SEED);
+            let a: Matrix<f64, $n, $n> = random_matrix(&mut rng);
+            let b: Matrix<f64, $n, $n> = random_matrix(&mut rng);
+            let (da, db): (DynMatrics<f64, $n, $n>, DynMatrics<f64, $n, $n>) =
+                (random_dyn_matrix(&mut rng), random_dyn_matrix(&mut rng));
+            group.throughput(Throughput::Elements(($n * $n * $n) as u64));
+            group.bench_with_input(BenchmarkId::new("matrix/sequential", $n), &$n, |bench, _| {
+                bench.iter(|| black_box(&a).dot_product(black_box(&b)))
+            });
+            group.bench_with_input(BenchmarkId::new("matrix/parallel", $n), &$n, |bench, _| {
+                bench.iter(|| black_box(&a).dot_product_in_parallel(black_box(&b), threads))
+            });
+            group.bench_with_input(BenchmarkId::new("dyn/sequential", $n), &$n, |bench, _| {
+                bench.iter(|| black_box(&da).dot_product(black_box(&db)))
+            });
+            group.bench_with_input(BenchmarkId::new("dyn/parallel", $n), &$n, |bench, _| {
+                bench.iter(|| black_box(&da).dot_product_in_parallel(black_box(&db), threads))
+            });
+        )*};
+    }
+
+    sizes!(16, 64, 128, 256);
+    group.finish();
+}
+
+// 元素类型扫描，规模固定
+fn bench_types(c: &mut Criterion) {
+    let mut group = c.benchmark_group("types");
+
+    macro_rules! types {
+        ($($t:ty),*) => {$(
+            let mut rng = seeded_rng(SEED);
+            let a: Matrix<$t, 128, 128> = random_matrix(&mut rng);
+            let b: Matrix<$t, 128, 128> = random_matrix(&mut rng);
+            group.bench_function(stringify!($t), |bench| {
+                bench.iter(|| black_box(&a).dot_product(black_box(&b)))
+            });
+        )*};
+    }
+
+    types!(i32, i64, f32, f64);
+    group.finish();
+}
+
+// 线程数扫描，观察并行版本的扩展性
+fn bench_threads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("threads");
+    let mut rng = seeded_rng(SEED);
+    let a: Matrix<f64, 256, 256> = random_matrix(&mut rng);
+    let b: Matrix<f64, 256, 256> = random_matrix(&mut rng);
+    for threads in [1, 2, 4, 8, 16] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |bench, &threads| {
+                bench.iter(|| black_box(&a).dot_product_in_parallel(black_box(&b), threads))
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_sizes, bench_types, bench_threads);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+// 启用 ffi 特性时用 cbindgen 根据 src/ffi.rs 生成 C 头文件，写到 OUT_DIR；
+// 构建脚本不应改动源码目录，仓库里的 include/matrix.h 由 scripts/gen-header.sh 更新
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "ffi")]
+    {
+        println!("cargo:rerun-if-changed=src/ffi.rs");
+        let dir = std::env::var("CARGO_MANIFEST_DIR").expect("set by cargo");
+        let out_dir = std::env::var("OUT_DIR").expect("set by cargo");
+        // 只导出 ffi 中的函数及其用到的不透明类型，crate 里其它 pub 常量不属于 C 接口
+        let config = cbindgen::Config {
+            usize_is_size_t: true,
+            export: cbindgen::ExportConfig {
+                item_types: vec![
+                    cbindgen::ItemType::Functions,
+                    cbindgen::ItemType::OpaqueItems,
+                ],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_crate(&dir)
+            .with_language(cbindgen::Language::C)
+            .with_include_guard("MATRIX_H")
+            .with_header("/* 由 cbindgen 生成，不要手动修改 */")
+            .generate()
+            .expect("failed to generate C bindings")
+            .write_to_file(format!("{}/matrix.h", out_dir));
+    }
+}
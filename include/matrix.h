@@ -0,0 +1,73 @@
+/* 由 cbindgen 生成，不要手动修改 */
+
+#ifndef MATRIX_H
+#define MATRIX_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * 不透明的矩阵句柄，只能通过 `matrix_*` 函数访问，用完后交给 `matrix_free` 释放。
+ */
+typedef struct MatrixF64 MatrixF64;
+
+/**
+ * 创建 rows×cols 的矩阵，从 `data` 复制 rows * cols 个元素；`data` 为空指针时全部为 0。
+ * rows * cols 溢出时返回空指针。
+ *
+ * # Safety
+ *
+ * `data` 为空指针，或指向至少 rows * cols 个可读的 double。
+ */
+struct MatrixF64 *matrix_new(size_t rows,
+                             size_t cols,
+                             const double *data);
+
+/**
+ * 释放矩阵，传入空指针时什么也不做。
+ *
+ * # Safety
+ *
+ * `matrix` 为空指针，或是由本库返回且尚未释放的句柄。
+ */
+void matrix_free(struct MatrixF64 *matrix);
+
+/**
+ * # Safety
+ *
+ * `matrix` 是有效的句柄。
+ */
+size_t matrix_rows(const struct MatrixF64 *matrix);
+
+/**
+ * # Safety
+ *
+ * `matrix` 是有效的句柄。
+ */
+size_t matrix_cols(const struct MatrixF64 *matrix);
+
+/**
+ * 返回行优先的 rows * cols 个元素，可以原地读写；指针在矩阵释放前有效。
+ *
+ * # Safety
+ *
+ * `matrix` 是有效的句柄。
+ */
+double *matrix_data(struct MatrixF64 *matrix);
+
+/**
+ * 计算 a·b 并返回新矩阵；a 的列数与 b 的行数不一致时返回空指针。
+ * `threads` 为 1 时串行，为 0 时按矩阵规模和 CPU 核数自动选择。
+ *
+ * # Safety
+ *
+ * `a` 与 `b` 是有效的句柄。
+ */
+struct MatrixF64 *matrix_multiply(const struct MatrixF64 *a,
+                                  const struct MatrixF64 *b,
+                                  size_t threads);
+
+#endif  /* MATRIX_H */
@@ -0,0 +1,9 @@
+#!/bin/sh
+# 重新生成 include/matrix.h：带 ffi 特性构建一次，从 build.rs 的 OUT_DIR 复制生成的头文件
+set -e
+cd "$(dirname "$0")/.."
+out_dir=$(cargo build --lib --features ffi --message-format=json |
+    grep '"reason":"build-script-executed"' |
+    grep '"package_id":"[^"]*#matrix@' |
+    sed 's/.*"out_dir":"\([^"]*\)".*/\1/')
+cp "$out_dir/matrix.h" include/matrix.h
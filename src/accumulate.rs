@@ -0,0 +1,142 @@
+use alloc::vec::Vec;
+use core::iter::{Skip, StepBy, Zip};
+use core::ops::Mul;
+use core::slice::Iter;
+
+use num_traits::{Float, MulAdd, Zero};
+
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// 左矩阵第 i 行与右矩阵第 j 列逐项配对
+type Terms<'a, T> = Zip<Iter<'a, T>, StepBy<Skip<Iter<'a, T>>>>;
+
+fn dot_by<'a, T, U>(
+    a: &'a [T],
+    b: &'a [T],
+    x: usize,
+    y: usize,
+    z: usize,
+    inner: impl Fn(Terms<'a, T>) -> U,
+) -> Vec<U> {
+    let mut result = Vec::with_capacity(x * z);
+    for i in 0..x {
+        for j in 0..z {
+            let column = b.iter().skip(j).step_by(z);
+            result.push(inner(a[i * y..(i + 1) * y].iter().zip(column)));
+        }
+    }
+    result
+}
+
+// Neumaier 改进的 Kahan 求和：单独累计每次加法的舍入误差，最后补回
+fn neumaier<'a, T: Float + 'a>(terms: impl Iterator<Item = (&'a T, &'a T)>) -> T {
+    let mut sum = T::zero();
+    let mut compensation = T::zero();
+    for (&a, &b) in terms {
+        let term = a * b;
+        let t = sum + term;
+        if sum.abs() >= term.abs() {
+            compensation = compensation + ((sum - t) + term);
+        } else {
+            compensation = compensation + ((term - t) + sum);
+        }
+        sum = t;
+    }
+    sum + compensation
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    pub fn dot_product_compensated<const Z: usize, S1: Storage<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Float,
+    {
+        let data = dot_by(self.as_slice(), matrix1.as_slice(), X, Y, Z, neumaier);
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+
+    // 每一步用融合乘加 a * b + sum，只舍入一次；浮点类型对应 f32::mul_add / f64::mul_add
+    pub fn dot_product_fma<const Z: usize, S1: Storage<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + MulAdd<Output = T> + Clone,
+    {
+        let data = dot_by(self.as_slice(), matrix1.as_slice(), X, Y, Z, |terms| {
+            terms.fold(T::zero(), |sum, (a, b)| a.clone().mul_add(b.clone(), sum))
+        });
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+
+    // 在更宽的类型 Acc 中相乘累加，例如 i16 -> i32、f32 -> f64
+    pub fn dot_product_widening<Acc, const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, Acc, X, Z>
+    where
+        T: Clone + Into<Acc>,
+        Acc: Zero + Mul<Output = Acc>,
+    {
+        let data = dot_by(self.as_slice(), matrix1.as_slice(), X, Y, Z, |terms| {
+            terms.fold(Acc::zero(), |sum, (a, b)| {
+                sum + a.clone().into() * b.clone().into()
+            })
+        });
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+    use alloc::vec;
+
+    #[test]
+    fn test_compensated_long_sum() {
+        let a = Matrix::<f32, 1, 10000>::try_from(vec![0.1f32; 10000]).unwrap();
+        let b = Matrix::<f32, 10000, 1>::try_from(vec![1.0f32; 10000]).unwrap();
+        let exact = (0.1f32 as f64 * 10000.0) as f32;
+        assert_eq!(a.dot_product_compensated(&b)[0][0], exact);
+        assert_ne!(a.dot_product(&b)[0][0], exact);
+    }
+
+    #[test]
+    fn test_compensated_cancellation() {
+        let a = DynMatrics::from([[1e100, 1.0, -1e100], [1.0, 2.0, 3.0]]);
+        let b = DynMatrics::from([[1.0], [1.0], [1.0]]);
+        assert_eq!(a.dot_product(&b)[0][0], 0.0);
+        let result = a.dot_product_compensated(&b);
+        assert_eq!(result.as_slice(), &[1.0, 6.0]);
+    }
+
+    #[test]
+    fn test_widening() {
+        let a = Matrix::from([[i16::MAX, i16::MAX], [1, -2]]);
+        let b = Matrix::from([[i16::MAX], [2]]);
+        let product: Matrix<i32, 2, 1> = a.dot_product_widening(&b);
+        let max = i16::MAX as i32;
+        assert_eq!(product, Matrix::from([[max * max + max * 2], [max - 4]]));
+
+        let a = DynMatrics::<f32, 1, 10000>::try_from(vec![0.1f32; 10000]).unwrap();
+        let b = DynMatrics::<f32, 10000, 1>::try_from(vec![1.0f32; 10000]).unwrap();
+        let product = a.dot_product_widening::<f64, 1>(&b);
+        assert!((product[0][0] - 0.1f32 as f64 * 10000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fma() {
+        // x * x 的精确值需要 25 位尾数，单独相乘会舍掉最低位
+        let x = 1.0f32 + 2f32.powi(-12);
+        let a = Matrix::from([[-1.0, x]]);
+        let b = Matrix::from([[1.0], [x]]);
+        assert_eq!(a.dot_product(&b)[0][0], 2f32.powi(-11));
+        assert_eq!(a.dot_product_fma(&b)[0][0], 2f32.powi(-11) + 2f32.powi(-24));
+
+        let m = DynMatrics::from([[1, 2], [3, 4]]);
+        assert_eq!(m.dot_product_fma(&m), m.dot_product(&m));
+    }
+}
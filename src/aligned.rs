@@ -0,0 +1,196 @@
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::fmt::{self, Debug, Formatter};
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use crate::storage::BoxFamily;
+use crate::{MatrixBase, Storage, StorageMut};
+
+// 缓冲区起始地址的对齐：一条缓存行，同时满足 AVX（32 字节）与 AVX-512（64 字节）的对齐加载
+pub const ALIGNMENT: usize = 64;
+
+// 按 ALIGNMENT 对齐分配的定长缓冲区；元素类型自身的对齐更大时取两者中较大者
+pub struct AlignedStorage<T> {
+    ptr: NonNull<T>,
+    // 已初始化的元素个数，只在构造过程中小于 capacity
+    len: usize,
+    capacity: usize,
+    _owns: PhantomData<T>,
+}
+
+// SAFETY: 与 Box<[T]> 相同，独占持有其中的元素
+unsafe impl<T: Send> Send for AlignedStorage<T> {}
+unsafe impl<T: Sync> Sync for AlignedStorage<T> {}
+
+fn layout<T>(len: usize) -> Layout {
+    Layout::array::<T>(len)
+        .and_then(|layout| layout.align_to(ALIGNMENT))
+        .expect("aligned buffer size overflows isize")
+}
+
+impl<T> AlignedStorage<T> {
+    pub fn from_fn(len: usize, mut f: impl FnMut(usize) -> T) -> Self {
+        let layout = layout::<T>(len);
+        let ptr = if layout.size() == 0 {
+            // 不分配内存，只需一个满足对齐的非空地址
+            NonNull::new(layout.align() as *mut T).expect("alignment is non-zero")
+        } else {
+            // SAFETY: layout 的大小不为零
+            let raw = unsafe { alloc(layout) } as *mut T;
+            NonNull::new(raw).unwrap_or_else(|| handle_alloc_error(layout))
+        };
+        // f panic 时析构已写入的元素并释放内存
+        let mut storage = AlignedStorage {
+            ptr,
+            len: 0,
+            capacity: len,
+            _owns: PhantomData,
+        };
+        for i in 0..len {
+            // SAFETY: i < len，位于分配的缓冲区内且尚未初始化
+            unsafe { storage.ptr.as_ptr().add(i).write(f(i)) };
+            storage.len = i + 1;
+        }
+        storage
+    }
+}
+
+impl<T> Drop for AlignedStorage<T> {
+    fn drop(&mut self) {
+        // SAFETY: 前 len 个元素已初始化，缓冲区按 layout(capacity) 分配
+        unsafe {
+            core::ptr::drop_in_place(core::ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr(),
+                self.len,
+            ));
+            let layout = layout::<T>(self.capacity);
+            if layout.size() != 0 {
+                dealloc(self.ptr.as_ptr() as *mut u8, layout);
+            }
+        }
+    }
+}
+
+impl<T> Storage<T> for AlignedStorage<T> {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: 前 len 个元素已初始化
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> StorageMut<T> for AlignedStorage<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: 同上，且 &mut self 保证独占
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Clone> Clone for AlignedStorage<T> {
+    fn clone(&self) -> Self {
+        let data = self.as_slice();
+        AlignedStorage::from_fn(data.len(), |i| data[i].clone())
+    }
+}
+
+impl<T: Debug> Debug for AlignedStorage<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq> PartialEq for AlignedStorage<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+pub type AlignedMatrix<T, const R: usize, const C: usize> = MatrixBase<T, R, C, AlignedStorage<T>>;
+
+impl<T, const R: usize, const C: usize> AlignedMatrix<T, R, C> {
+    pub fn from_fn_aligned(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let data = AlignedStorage::from_fn(R * C, |index| f(index / C, index % C));
+        MatrixBase::from_storage(data).expect("from_fn_aligned has exactly R * C elements")
+    }
+
+    pub fn filled_aligned(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn_aligned(|_, _| value.clone())
+    }
+
+    // 起始地址保证按 ALIGNMENT 对齐，可直接交给对齐加载的 SIMD 指令或要求对齐的 C 接口
+    pub fn as_aligned_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+
+    pub fn as_aligned_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    // 复制到对齐的缓冲区
+    pub fn to_aligned(&self) -> AlignedMatrix<T, R, C>
+    where
+        T: Clone,
+    {
+        let data = self.as_slice();
+        MatrixBase::from_storage(AlignedStorage::from_fn(R * C, |i| data[i].clone()))
+            .expect("storage has exactly R * C elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+
+    use super::*;
+    use crate::Matrix;
+
+    #[test]
+    fn test_aligned_matrix() {
+        let a = AlignedMatrix::<u8, 3, 5>::from_fn_aligned(|i, j| (i * 5 + j) as u8);
+        assert_eq!(a.as_aligned_slice().as_ptr() as usize % ALIGNMENT, 0);
+        assert_eq!(
+            a.to_matrix(),
+            Matrix::<u8, 3, 5>::from_fn(|i, j| (i * 5 + j) as u8)
+        );
+
+        let b = Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]).to_aligned();
+        let mut out = AlignedMatrix::<f32, 2, 2>::filled_aligned(0.0);
+        b.dot_product_into(&b, &mut out);
+        assert_eq!(out.as_aligned_slice(), [7.0, 10.0, 15.0, 22.0]);
+        assert_eq!(out.as_aligned_mut_slice().as_ptr() as usize % ALIGNMENT, 0);
+
+        // 空矩阵也满足对齐，且不分配内存
+        let empty = AlignedMatrix::<u64, 0, 4>::filled_aligned(1);
+        assert_eq!(empty.as_aligned_slice().as_ptr() as usize % ALIGNMENT, 0);
+    }
+
+    #[test]
+    fn test_aligned_drop() {
+        let shared = Rc::new(());
+        let a = AlignedMatrix::<Rc<()>, 2, 2>::filled_aligned(Rc::clone(&shared));
+        assert_eq!(Rc::strong_count(&shared), 5);
+        drop(a);
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_aligned_drop_on_panic() {
+        // 构造中途 panic 时，已写入的元素被析构
+        let shared = Rc::new(());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            AlignedStorage::from_fn(4, |i| {
+                assert!(i < 2);
+                Rc::clone(&shared)
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+}
@@ -0,0 +1,136 @@
+use core::mem::MaybeUninit;
+use core::ops::Mul;
+
+use allocator_api2::alloc::Allocator;
+use allocator_api2::vec::Vec;
+use num_traits::Zero;
+
+use crate::storage::BoxFamily;
+use crate::{gemm, GemmElement, MatrixBase, Storage, StorageMut};
+
+pub use allocator_api2::alloc::Global;
+
+// 元素放在用户提供的分配器中，例如 arena、大页或锁页内存
+// allocator-api2 在稳定版上提供与标准库 allocator_api 相同的接口
+pub type AllocMatrix<T, const R: usize, const C: usize, A = Global> =
+    MatrixBase<T, R, C, Vec<T, A>>;
+
+impl<T, A: Allocator> Storage<T> for Vec<T, A> {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T, A: Allocator> StorageMut<T> for Vec<T, A> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+// 在 alloc 中分配 len 个元素交给 fill 写满，与 gemm::uninit_vec 相同
+// SAFETY: 调用方保证 fill 正常返回时已写满所有元素
+unsafe fn uninit_vec_in<T, A: Allocator>(
+    len: usize,
+    alloc: A,
+    fill: impl FnOnce(&mut [MaybeUninit<T>]),
+) -> Vec<T, A> {
+    let mut data = Vec::with_capacity_in(len, alloc);
+    fill(&mut data.spare_capacity_mut()[..len]);
+    // SAFETY: 前 len 个元素均已初始化
+    unsafe { data.set_len(len) };
+    data
+}
+
+impl<T, const R: usize, const C: usize, A: Allocator> AllocMatrix<T, R, C, A> {
+    pub fn from_fn_in(mut f: impl FnMut(usize, usize) -> T, alloc: A) -> Self {
+        let mut data = Vec::with_capacity_in(R * C, alloc);
+        data.extend((0..R * C).map(|index| f(index / C, index % C)));
+        MatrixBase::from_storage(data).expect("from_fn_in has exactly R * C elements")
+    }
+
+    pub fn filled_in(value: T, alloc: A) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn_in(|_, _| value.clone(), alloc)
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 结果分配在 alloc 中，除此之外不再分配内存
+    pub fn dot_product_in<const Z: usize, S1: Storage<T>, A: Allocator>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+        alloc: A,
+    ) -> AllocMatrix<T, X, Z, A>
+    where
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_dispatch 会写满全部 X * Z 个元素
+        let data =
+            unsafe { uninit_vec_in(X * Z, alloc, |c| gemm::gemm_dispatch(a, b, X, Y, Z, c)) };
+        MatrixBase::from_storage(data).expect("product has exactly X * Z elements")
+    }
+
+    pub fn to_matrix_in<A: Allocator>(&self, alloc: A) -> AllocMatrix<T, X, Y, A>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity_in(X * Y, alloc);
+        data.extend_from_slice(self.as_slice());
+        MatrixBase::from_storage(data).expect("storage has exactly X * Y elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::alloc::Layout;
+    use core::ptr::NonNull;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use allocator_api2::alloc::AllocError;
+
+    use super::*;
+    use crate::Matrix;
+
+    // 统计分配次数，实际分配交给全局分配器
+    #[derive(Default)]
+    struct Counting(AtomicUsize);
+
+    unsafe impl Allocator for &Counting {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe { Global.deallocate(ptr, layout) }
+        }
+    }
+
+    #[test]
+    fn test_custom_allocator() {
+        let counter = Counting::default();
+        let a = AllocMatrix::<i32, 2, 3, _>::from_fn_in(|i, j| (i * 3 + j) as i32, &counter);
+        let b = Matrix::from([[1, 0], [0, 1], [1, 1]]);
+        let c = a.dot_product_in(&b, &counter);
+        assert_eq!(c.to_matrix(), a.to_matrix().dot_product(&b));
+        assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+
+        let mut d = b.to_matrix_in(&counter);
+        d[2][0] = 5;
+        assert_eq!(d.as_slice(), [1, 0, 0, 1, 5, 1]);
+        assert_eq!(
+            AllocMatrix::<u8, 2, 2>::filled_in(7, Global).as_slice(),
+            [7; 4]
+        );
+        assert_eq!(counter.0.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,118 @@
+use core::ops::Sub;
+
+use crate::{MatrixBase, Storage};
+
+fn slices_approx_eq<T>(a: &[T], b: &[T], epsilon: T) -> bool
+where
+    T: Sub<Output = T> + PartialOrd + Clone,
+{
+    a.iter().zip(b).all(|(x, y)| {
+        let diff = if x > y {
+            x.clone() - y.clone()
+        } else {
+            y.clone() - x.clone()
+        };
+        diff <= epsilon
+    })
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool
+    where
+        T: Sub<Output = T> + PartialOrd + Clone,
+    {
+        slices_approx_eq(self.as_slice(), other.as_slice(), epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, const R: usize, const C: usize, S: Storage<T>> approx::AbsDiffEq for MatrixBase<T, R, C, S>
+where
+    T: approx::AbsDiffEq,
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(x, y)| x.abs_diff_eq(y, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, const R: usize, const C: usize, S: Storage<T>> approx::RelativeEq for MatrixBase<T, R, C, S>
+where
+    T: approx::RelativeEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(x, y)| x.relative_eq(y, epsilon, max_relative))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T, const R: usize, const C: usize, S: Storage<T>> approx::UlpsEq for MatrixBase<T, R, C, S>
+where
+    T: approx::UlpsEq,
+    T::Epsilon: Copy,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(x, y)| x.ulps_eq(y, epsilon, max_ulps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Matrix::from([[0.1 + 0.2, 1.0], [2.0, 3.0]]);
+        let b = Matrix::from([[0.3, 1.0], [2.0, 3.0 + 1e-12]]);
+        assert_ne!(a, b);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&Matrix::from([[0.3, 1.0], [2.0, 3.1]]), 1e-9));
+    }
+
+    #[test]
+    fn test_approx_eq_dyn() {
+        let a = DynMatrics::from([[1.0f32, 2.0]]);
+        let b = DynMatrics::from([[1.0f32 + 1e-6, 2.0 - 1e-6]]);
+        assert!(a.approx_eq(&b, 1e-5));
+    }
+
+    #[cfg(feature = "approx")]
+    #[test]
+    fn test_approx_traits() {
+        let a = Matrix::from([[0.1 + 0.2, 1.0]]);
+        let b = Matrix::from([[0.3, 1.0]]);
+        approx::assert_relative_eq!(a, b);
+        approx::assert_abs_diff_eq!(a, b, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,353 @@
+use alloc::vec::Vec;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, SubAssign};
+
+use num_traits::{One, Signed, Zero};
+
+use crate::{
+    GemmElement, MatrixBase, OwnedMatrix, OwnedStorage, Storage, StorageFamily, StorageMut,
+};
+
+// 按值的 `+` 与 `*`：前者逐元素相加，后者为矩阵乘法
+// 有了这两个运算，方阵本身就满足 num_traits 的 Zero 与 One，可以作为其它泛型算法的元素
+// `+=`、`-=` 与同形状矩阵逐元素运算，`*=` 乘以标量，都直接改写自身，不分配内存
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> Add for MatrixBase<T, R, C, S>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let data: Vec<T> = self
+            .into_vec()
+            .into_iter()
+            .zip(rhs.into_vec())
+            .map(|(a, b)| a + b)
+            .collect();
+        MatrixBase::try_from(data).expect("sum has exactly R * C elements")
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> Neg for MatrixBase<T, R, C, S>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let data: Vec<T> = self.into_vec().into_iter().map(|a| -a).collect();
+        MatrixBase::try_from(data).expect("negation has exactly R * C elements")
+    }
+}
+
+// 以下逐元素运算按值接收并原地改写，不分配内存；
+// clamp 按值接收还使它优先于 T: Ord 时的 Ord::clamp（那是对整个矩阵比较大小）
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> MatrixBase<T, R, C, S> {
+    pub fn abs(mut self) -> Self
+    where
+        T: Signed,
+    {
+        for x in self.as_mut_slice() {
+            *x = x.abs();
+        }
+        self
+    }
+
+    // 正数为 1、负数为 -1、零为 0；浮点的 ±0 与 NaN 按 num_traits::Signed 的约定处理
+    pub fn signum(mut self) -> Self
+    where
+        T: Signed,
+    {
+        for x in self.as_mut_slice() {
+            *x = x.signum();
+        }
+        self
+    }
+
+    // 每个元素限制在 [lo, hi] 内，lo > hi 时 panic；NaN 与边界不可比较，原样保留
+    pub fn clamp(mut self, lo: T, hi: T) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        assert!(lo <= hi, "clamp requires lo <= hi");
+        for x in self.as_mut_slice() {
+            if *x < lo {
+                *x = lo.clone();
+            } else if *x > hi {
+                *x = hi.clone();
+            }
+        }
+        self
+    }
+}
+
+impl<T, const R: usize, const C: usize, S, S1> AddAssign<&MatrixBase<T, R, C, S1>>
+    for MatrixBase<T, R, C, S>
+where
+    T: AddAssign + Clone,
+    S: StorageMut<T>,
+    S1: Storage<T>,
+{
+    fn add_assign(&mut self, rhs: &MatrixBase<T, R, C, S1>) {
+        for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *a += b.clone();
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize, S, S1> AddAssign<MatrixBase<T, R, C, S1>>
+    for MatrixBase<T, R, C, S>
+where
+    T: AddAssign,
+    S: StorageMut<T>,
+    S1: OwnedStorage<T>,
+{
+    fn add_assign(&mut self, rhs: MatrixBase<T, R, C, S1>) {
+        for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.into_vec()) {
+            *a += b;
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize, S, S1> SubAssign<&MatrixBase<T, R, C, S1>>
+    for MatrixBase<T, R, C, S>
+where
+    T: SubAssign + Clone,
+    S: StorageMut<T>,
+    S1: Storage<T>,
+{
+    fn sub_assign(&mut self, rhs: &MatrixBase<T, R, C, S1>) {
+        for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *a -= b.clone();
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize, S, S1> SubAssign<MatrixBase<T, R, C, S1>>
+    for MatrixBase<T, R, C, S>
+where
+    T: SubAssign,
+    S: StorageMut<T>,
+    S1: OwnedStorage<T>,
+{
+    fn sub_assign(&mut self, rhs: MatrixBase<T, R, C, S1>) {
+        for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.into_vec()) {
+            *a -= b;
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> MulAssign<T> for MatrixBase<T, R, C, S>
+where
+    T: MulAssign + Clone,
+{
+    fn mul_assign(&mut self, scalar: T) {
+        for a in self.as_mut_slice() {
+            *a *= scalar.clone();
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> DivAssign<T> for MatrixBase<T, R, C, S>
+where
+    T: DivAssign + Clone,
+{
+    fn div_assign(&mut self, scalar: T) {
+        for a in self.as_mut_slice() {
+            *a /= scalar.clone();
+        }
+    }
+}
+
+// 整数除以零会 panic，需要报告错误时用 checked_div_scalar
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> Div<T> for MatrixBase<T, R, C, S>
+where
+    T: DivAssign + Clone,
+{
+    type Output = Self;
+
+    fn div(mut self, scalar: T) -> Self {
+        self /= scalar;
+        self
+    }
+}
+
+impl<T, const X: usize, const Y: usize, const Z: usize, S, S1> Mul<MatrixBase<T, Y, Z, S1>>
+    for MatrixBase<T, X, Y, S>
+where
+    T: Zero + Mul<Output = T> + Clone + GemmElement,
+    S: Storage<T>,
+    S1: Storage<T>,
+{
+    type Output = OwnedMatrix<S::Family, T, X, Z>;
+
+    fn mul(self, rhs: MatrixBase<T, Y, Z, S1>) -> Self::Output {
+        self.dot_product(&rhs)
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> Zero for MatrixBase<T, R, C, S>
+where
+    T: Zero + Clone,
+{
+    fn zero() -> Self {
+        MatrixBase::filled(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.as_slice().iter().all(T::is_zero)
+    }
+}
+
+// 只有方阵在乘法下有单位元；乘积要放回同一种存储，S 须是其所属族中 N×N 矩阵的存储
+impl<T, const N: usize, S> One for MatrixBase<T, N, N, S>
+where
+    T: Zero + One + Clone + GemmElement,
+    S: OwnedStorage<T>,
+    S::Family: StorageFamily<Storage<T, N, N> = S>,
+{
+    fn one() -> Self {
+        MatrixBase::from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+// 逐元素累加，空迭代器的和为零矩阵
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> Sum for MatrixBase<T, R, C, S>
+where
+    T: Zero + AddAssign + Clone,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |mut sum, m| {
+            sum += m;
+            sum
+        })
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize, S> Sum<&'a MatrixBase<T, R, C, S>>
+    for MatrixBase<T, R, C, S>
+where
+    T: Zero + AddAssign + Clone,
+    S: OwnedStorage<T>,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |mut sum, m| {
+            sum += m;
+            sum
+        })
+    }
+}
+
+// 按迭代顺序连乘 m0·m1·m2…，空迭代器的积为单位矩阵
+impl<T, const N: usize, S> Product for MatrixBase<T, N, N, S>
+where
+    T: Zero + One + Clone + GemmElement,
+    S: OwnedStorage<T>,
+    S::Family: StorageFamily<Storage<T, N, N> = S>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, m| product * m)
+    }
+}
+
+impl<'a, T, const N: usize, S> Product<&'a MatrixBase<T, N, N, S>> for MatrixBase<T, N, N, S>
+where
+    T: Zero + One + Clone + GemmElement,
+    S: OwnedStorage<T>,
+    S::Family: StorageFamily<Storage<T, N, N> = S>,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |product, m| product.dot_product(m))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_zero_one() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        assert_eq!(a.clone() + Matrix::zero(), a);
+        assert_eq!(a.clone() * Matrix::one(), a);
+        assert!(Matrix::<i32, 2, 3>::zero().is_zero());
+        assert!(!a.is_zero());
+        assert_eq!(a.clone() * a.clone(), a.dot_product(&a));
+
+        // 方阵可以作为 num_traits 泛型算法的元素
+        let b = DynMatrics::from([[1, 1], [1, 0]]);
+        assert_eq!(
+            num_traits::pow(b, 10),
+            DynMatrics::from([[89, 55], [55, 34]])
+        );
+    }
+
+    #[test]
+    fn test_compound_assign() {
+        let mut a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[0.5, 0.5], [1.0, 1.0]]);
+        let ptr = a.as_slice().as_ptr();
+        a += &b;
+        a -= Matrix::from([[1.0, 0.0], [0.0, 1.0]]);
+        a *= 2.0;
+        assert_eq!(a, Matrix::from([[1.0, 5.0], [8.0, 8.0]]));
+        // 原地更新，没有重新分配
+        assert_eq!(a.as_slice().as_ptr(), ptr);
+
+        let mut c = DynMatrics::from([[1, 2, 3]]);
+        c -= &DynMatrics::from([[1, 1, 1]]);
+        c += DynMatrics::from([[10, 10, 10]]);
+        c *= 3;
+        assert_eq!(c, DynMatrics::from([[30, 33, 36]]));
+        c /= 3;
+        assert_eq!(c / 2, DynMatrics::from([[5, 5, 6]]));
+
+        // 按样本数求平均
+        let total = Matrix::from([[3.0, 6.0]]);
+        assert_eq!(total / 3.0, Matrix::from([[1.0, 2.0]]));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let grads = [
+            Matrix::from([[1.0, 2.0], [3.0, 4.0]]),
+            Matrix::from([[3.0, 2.0], [1.0, 0.0]]),
+        ];
+        let total: Matrix<f64, 2, 2> = grads.iter().sum();
+        assert_eq!(total, Matrix::filled(4.0));
+        assert_eq!(grads.into_iter().sum::<Matrix<f64, 2, 2>>(), total);
+        assert!(core::iter::empty::<Matrix<i32, 2, 3>>()
+            .sum::<Matrix<i32, 2, 3>>()
+            .is_zero());
+
+        // 矩阵乘法不交换，按迭代顺序相乘
+        let shear = DynMatrics::from([[1, 1], [0, 1]]);
+        let swap = DynMatrics::from([[0, 1], [1, 0]]);
+        let product: DynMatrics<i32, 2, 2> = [&shear, &swap, &shear].into_iter().product();
+        assert_eq!(product, shear.dot_product(&swap).dot_product(&shear));
+        assert_eq!(product, DynMatrics::from([[1, 2], [1, 1]]));
+        assert_eq!(
+            Vec::<DynMatrics<i32, 2, 2>>::new()
+                .into_iter()
+                .product::<DynMatrics<i32, 2, 2>>(),
+            DynMatrics::one()
+        );
+    }
+
+    #[test]
+    fn test_neg_abs_clamp() {
+        let a = Matrix::from([[-3, 0], [2, -1]]);
+        assert_eq!(-a.clone(), Matrix::from([[3, 0], [-2, 1]]));
+        assert_eq!(a.clone().abs(), Matrix::from([[3, 0], [2, 1]]));
+        assert_eq!(a.clone().signum(), Matrix::from([[-1, 0], [1, -1]]));
+        assert_eq!(a.clamp(-1, 1), Matrix::from([[-1, 0], [1, -1]]));
+
+        let b = DynMatrics::from([[-0.5, 2.5, f64::NAN]]);
+        let clamped = b.clone().clamp(0.0, 1.0);
+        assert_eq!(clamped[0][..2], [0.0, 1.0]);
+        assert!(clamped[0][2].is_nan());
+        assert_eq!((-b).abs()[0][..2], [0.5, 2.5]);
+    }
+}
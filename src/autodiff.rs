@@ -0,0 +1,409 @@
+use alloc::{vec, vec::Vec};
+use core::cell::RefCell;
+use core::ops::{Add, Sub};
+
+use num_traits::Float;
+
+use crate::dynamic::DynMatrix;
+use crate::{GemmElement, Matrix};
+
+// 反向模式自动微分：前向计算时把每一步的结果与运算记录在 Tape 上，
+// backward 按记录的逆序传播梯度。形状由 Var 的类型参数在编译期检查，
+// 节点内部统一存为 DynMatrix，一条 Tape 上可以混合各种形状
+//
+// 训练时每一步新建一条 Tape，参数以 Matrix 的形式保存在 Tape 之外：
+//
+//     let tape = Tape::new();
+//     let w = tape.var(weights.clone());
+//     let loss = (tape.var(x).matmul(w) - tape.var(y)).square().mean();
+//     let mut step = loss.backward().wrt(&w);
+//     step *= rate;
+//     weights -= step;
+pub struct Tape<T> {
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+struct Node<T> {
+    value: DynMatrix<T>,
+    op: Op<T>,
+}
+
+// 字段为输入节点在 Tape 上的序号
+enum Op<T> {
+    Leaf,
+    MatMul(usize, usize),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Hadamard(usize, usize),
+    Scale(usize, T),
+    AddRow(usize, usize),
+    Relu(usize),
+    Sigmoid(usize),
+    Tanh(usize),
+    Square(usize),
+    Sum(usize),
+    Mean(usize),
+}
+
+#[derive(Clone, Copy)]
+pub struct Var<'t, T, const R: usize, const C: usize> {
+    tape: &'t Tape<T>,
+    index: usize,
+}
+
+fn map<T: Float>(m: &DynMatrix<T>, f: impl Fn(T) -> T) -> DynMatrix<T> {
+    let data = m.as_slice();
+    DynMatrix::from_fn(m.rows(), m.cols(), |i, j| f(data[i * m.cols() + j]))
+}
+
+fn zip<T: Float>(a: &DynMatrix<T>, b: &DynMatrix<T>, f: impl Fn(T, T) -> T) -> DynMatrix<T> {
+    a.broadcast_with(b, |&x, &y| f(x, y))
+        .expect("operand shapes are checked by Var")
+}
+
+impl<T: Float> Default for Tape<T> {
+    fn default() -> Self {
+        Tape {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Float + GemmElement> Tape<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 叶子节点：参数、输入与目标值都以此进入计算图
+    pub fn var<const R: usize, const C: usize>(&self, value: Matrix<T, R, C>) -> Var<'_, T, R, C> {
+        self.push(value.into(), Op::Leaf)
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn push<const R: usize, const C: usize>(
+        &self,
+        value: DynMatrix<T>,
+        op: Op<T>,
+    ) -> Var<'_, T, R, C> {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { value, op });
+        Var {
+            tape: self,
+            index: nodes.len() - 1,
+        }
+    }
+
+    fn value(&self, index: usize) -> DynMatrix<T> {
+        self.nodes.borrow()[index].value.clone()
+    }
+
+    // 从 output 出发按逆序传播，每个节点的梯度与其值同形状
+    fn backward(&self, output: usize) -> Gradients<T> {
+        let nodes = self.nodes.borrow();
+        let mut grads: Vec<Option<DynMatrix<T>>> = (0..nodes.len()).map(|_| None).collect();
+        grads[output] = Some(DynMatrix::filled(1, 1, T::one()));
+        let accumulate = |grads: &mut Vec<Option<DynMatrix<T>>>, index: usize, g: DynMatrix<T>| {
+            grads[index] = Some(match grads[index].take() {
+                Some(sum) => zip(&sum, &g, |a, b| a + b),
+                None => g,
+            });
+        };
+        for index in (0..=output).rev() {
+            let Some(g) = grads[index].take() else {
+                continue;
+            };
+            let node = &nodes[index];
+            let input = |i: usize| &nodes[i].value;
+            match node.op {
+                Op::Leaf => {}
+                Op::MatMul(a, b) => {
+                    let da = g.dot_product(&input(b).transpose());
+                    let db = input(a).transpose().dot_product(&g);
+                    accumulate(&mut grads, a, da.expect("shapes are checked by Var"));
+                    accumulate(&mut grads, b, db.expect("shapes are checked by Var"));
+                }
+                Op::Add(a, b) => {
+                    accumulate(&mut grads, a, g.clone());
+                    accumulate(&mut grads, b, g.clone());
+                }
+                Op::Sub(a, b) => {
+                    accumulate(&mut grads, a, g.clone());
+                    accumulate(&mut grads, b, map(&g, |x| -x));
+                }
+                Op::Hadamard(a, b) => {
+                    accumulate(&mut grads, a, zip(&g, input(b), |x, y| x * y));
+                    accumulate(&mut grads, b, zip(&g, input(a), |x, y| x * y));
+                }
+                Op::Scale(a, k) => accumulate(&mut grads, a, map(&g, |x| x * k)),
+                Op::AddRow(a, bias) => {
+                    // 偏置加到了每一行上，梯度按列求和
+                    let cols = g.cols();
+                    let sums = DynMatrix::from_fn(1, cols, |_, j| {
+                        (0..g.rows()).fold(T::zero(), |sum, i| sum + g[i][j])
+                    });
+                    accumulate(&mut grads, a, g.clone());
+                    accumulate(&mut grads, bias, sums);
+                }
+                Op::Relu(a) => {
+                    let mask = |x: T, y: T| if y > T::zero() { x } else { T::zero() };
+                    accumulate(&mut grads, a, zip(&g, input(a), mask));
+                }
+                Op::Sigmoid(a) => {
+                    let d = |x: T, y: T| x * y * (T::one() - y);
+                    accumulate(&mut grads, a, zip(&g, &node.value, d));
+                }
+                Op::Tanh(a) => {
+                    let d = |x: T, y: T| x * (T::one() - y * y);
+                    accumulate(&mut grads, a, zip(&g, &node.value, d));
+                }
+                Op::Square(a) => {
+                    let d = |x: T, y: T| x * (y + y);
+                    accumulate(&mut grads, a, zip(&g, input(a), d));
+                }
+                Op::Sum(a) | Op::Mean(a) => {
+                    let (rows, cols) = input(a).shape();
+                    let mut scale = g[0][0];
+                    if let Op::Mean(_) = node.op {
+                        scale = scale / T::from(rows * cols).expect("element count fits in T");
+                    }
+                    accumulate(&mut grads, a, DynMatrix::filled(rows, cols, scale));
+                }
+            }
+            grads[index] = Some(g);
+        }
+        Gradients { grads }
+    }
+}
+
+impl<'t, T: Float + GemmElement, const R: usize, const C: usize> Var<'t, T, R, C> {
+    pub fn value(&self) -> Matrix<T, R, C> {
+        Matrix::try_from(self.tape.value(self.index)).expect("node has shape R×C")
+    }
+
+    fn unary<const X: usize, const Y: usize>(
+        self,
+        f: impl Fn(&DynMatrix<T>) -> DynMatrix<T>,
+        op: Op<T>,
+    ) -> Var<'t, T, X, Y> {
+        let value = f(&self.tape.nodes.borrow()[self.index].value);
+        self.tape.push(value, op)
+    }
+
+    fn binary<const X: usize, const Y: usize, const Z: usize, const W: usize>(
+        self,
+        other: Var<'t, T, Z, W>,
+        f: impl Fn(&DynMatrix<T>, &DynMatrix<T>) -> DynMatrix<T>,
+        op: Op<T>,
+    ) -> Var<'t, T, X, Y> {
+        assert!(
+            core::ptr::eq(self.tape, other.tape),
+            "variables belong to different tapes"
+        );
+        let value = {
+            let nodes = self.tape.nodes.borrow();
+            f(&nodes[self.index].value, &nodes[other.index].value)
+        };
+        self.tape.push(value, op)
+    }
+
+    pub fn matmul<const Z: usize>(self, other: Var<'t, T, C, Z>) -> Var<'t, T, R, Z> {
+        let f = |a: &DynMatrix<T>, b: &DynMatrix<T>| {
+            a.dot_product(b).expect("shapes are checked by Var")
+        };
+        self.binary(other, f, Op::MatMul(self.index, other.index))
+    }
+
+    // 逐元素乘积
+    pub fn hadamard(self, other: Self) -> Self {
+        let f = |a: &DynMatrix<T>, b: &DynMatrix<T>| zip(a, b, |x, y| x * y);
+        self.binary(other, f, Op::Hadamard(self.index, other.index))
+    }
+
+    pub fn scale(self, k: T) -> Self {
+        self.unary(|a| map(a, |x| x * k), Op::Scale(self.index, k))
+    }
+
+    // 每一行加上 1×C 的 bias，即全连接层的偏置
+    pub fn add_row(self, bias: Var<'t, T, 1, C>) -> Self {
+        let f = |a: &DynMatrix<T>, b: &DynMatrix<T>| zip(a, b, |x, y| x + y);
+        self.binary(bias, f, Op::AddRow(self.index, bias.index))
+    }
+
+    pub fn relu(self) -> Self {
+        let f = |a: &DynMatrix<T>| map(a, |x| x.max(T::zero()));
+        self.unary(f, Op::Relu(self.index))
+    }
+
+    pub fn sigmoid(self) -> Self {
+        let f = |a: &DynMatrix<T>| map(a, |x| T::one() / (T::one() + (-x).exp()));
+        self.unary(f, Op::Sigmoid(self.index))
+    }
+
+    pub fn tanh(self) -> Self {
+        self.unary(|a| map(a, T::tanh), Op::Tanh(self.index))
+    }
+
+    pub fn square(self) -> Self {
+        self.unary(|a| map(a, |x| x * x), Op::Square(self.index))
+    }
+
+    pub fn sum(self) -> Var<'t, T, 1, 1> {
+        let f = |a: &DynMatrix<T>| {
+            let sum = a.as_slice().iter().fold(T::zero(), |sum, &x| sum + x);
+            DynMatrix::filled(1, 1, sum)
+        };
+        self.unary(f, Op::Sum(self.index))
+    }
+
+    pub fn mean(self) -> Var<'t, T, 1, 1> {
+        let n = T::from(R * C).expect("element count fits in T");
+        let f = |a: &DynMatrix<T>| {
+            let sum = a.as_slice().iter().fold(T::zero(), |sum, &x| sum + x);
+            DynMatrix::filled(1, 1, sum / n)
+        };
+        self.unary(f, Op::Mean(self.index))
+    }
+}
+
+impl<'t, T: Float + GemmElement> Var<'t, T, 1, 1> {
+    // 对标量输出求所有节点的梯度
+    pub fn backward(self) -> Gradients<T> {
+        self.tape.backward(self.index)
+    }
+}
+
+impl<'t, T: Float + GemmElement, const R: usize, const C: usize> Add for Var<'t, T, R, C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let f = |a: &DynMatrix<T>, b: &DynMatrix<T>| zip(a, b, |x, y| x + y);
+        self.binary(other, f, Op::Add(self.index, other.index))
+    }
+}
+
+impl<'t, T: Float + GemmElement, const R: usize, const C: usize> Sub for Var<'t, T, R, C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        let f = |a: &DynMatrix<T>, b: &DynMatrix<T>| zip(a, b, |x, y| x - y);
+        self.binary(other, f, Op::Sub(self.index, other.index))
+    }
+}
+
+// backward 的结果，按 Var 取对应的梯度
+pub struct Gradients<T> {
+    grads: Vec<Option<DynMatrix<T>>>,
+}
+
+impl<T: Float> Gradients<T> {
+    // 与输出无关的节点梯度为零
+    pub fn wrt<const R: usize, const C: usize>(&self, var: &Var<'_, T, R, C>) -> Matrix<T, R, C> {
+        match self.grads.get(var.index).and_then(Option::as_ref) {
+            Some(g) => Matrix::try_from(g.clone()).expect("gradient has shape R×C"),
+            None => Matrix::try_from(vec![T::zero(); R * C]).expect("zero has R * C elements"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 中心差分，用于核对解析梯度
+    fn numeric_gradient<const R: usize, const C: usize>(
+        x: &Matrix<f64, R, C>,
+        f: impl Fn(&Matrix<f64, R, C>) -> f64,
+    ) -> Matrix<f64, R, C> {
+        let h = 1e-6;
+        Matrix::from_fn(|i, j| {
+            let (mut plus, mut minus) = (x.clone(), x.clone());
+            plus[i][j] += h;
+            minus[i][j] -= h;
+            (f(&plus) - f(&minus)) / (2.0 * h)
+        })
+    }
+
+    #[test]
+    fn test_gradients_match_finite_differences() {
+        let x = Matrix::from([[0.5, -1.0, 2.0], [1.5, 0.25, -0.75]]);
+        let w = Matrix::from([[0.1, -0.2], [0.4, 0.3], [-0.5, 0.2]]);
+        let b = Matrix::from([[0.05, -0.1]]);
+        let target = Matrix::from([[0.2, 0.8], [0.6, 0.1]]);
+
+        // sigmoid(tanh(x)·w + b) 与目标的均方误差，再加上 relu 与逐元素乘积的项
+        let loss = |x: &Matrix<f64, 2, 3>, w: &Matrix<f64, 3, 2>, b: &Matrix<f64, 1, 2>| {
+            let tape = Tape::new();
+            let (xv, wv, bv) = (
+                tape.var(x.clone()),
+                tape.var(w.clone()),
+                tape.var(b.clone()),
+            );
+            let hidden = xv.tanh().matmul(wv).add_row(bv);
+            let error = hidden.sigmoid() - tape.var(target.clone());
+            let penalty = hidden.relu().hadamard(hidden).sum().scale(0.5);
+            (error.square().mean() + penalty).value()[0][0]
+        };
+
+        let tape = Tape::new();
+        let (xv, wv, bv) = (
+            tape.var(x.clone()),
+            tape.var(w.clone()),
+            tape.var(b.clone()),
+        );
+        let hidden = xv.tanh().matmul(wv).add_row(bv);
+        let error = hidden.sigmoid() - tape.var(target.clone());
+        let penalty = hidden.relu().hadamard(hidden).sum().scale(0.5);
+        let total = error.square().mean() + penalty;
+        assert_eq!(total.value()[0][0], loss(&x, &w, &b));
+
+        let grads = total.backward();
+        assert!(grads
+            .wrt(&xv)
+            .approx_eq(&numeric_gradient(&x, |x| loss(x, &w, &b)), 1e-6));
+        assert!(grads
+            .wrt(&wv)
+            .approx_eq(&numeric_gradient(&w, |w| loss(&x, w, &b)), 1e-6));
+        assert!(grads
+            .wrt(&bv)
+            .approx_eq(&numeric_gradient(&b, |b| loss(&x, &w, b)), 1e-6));
+    }
+
+    #[test]
+    fn test_linear_regression_converges() {
+        // y = 2·x₀ - 3·x₁ + 1
+        let x = Matrix::from([[0.0, 1.0], [1.0, 0.0], [1.0, 1.0], [2.0, -1.0]]);
+        let y = Matrix::from([[-2.0], [3.0], [0.0], [8.0]]);
+        let (mut w, mut b) = (
+            Matrix::<f64, 2, 1>::filled(0.0),
+            Matrix::<f64, 1, 1>::filled(0.0),
+        );
+        for _ in 0..2000 {
+            let tape = Tape::new();
+            let (wv, bv) = (tape.var(w.clone()), tape.var(b.clone()));
+            let prediction = tape.var(x.clone()).matmul(wv).add_row(bv);
+            let loss = (prediction - tape.var(y.clone())).square().mean();
+            let grads = loss.backward();
+            let (mut dw, mut db) = (grads.wrt(&wv), grads.wrt(&bv));
+            dw *= 0.1;
+            db *= 0.1;
+            w -= dw;
+            b -= db;
+        }
+        assert!(w.approx_eq(&Matrix::from([[2.0], [-3.0]]), 1e-6));
+        assert!(b.approx_eq(&Matrix::from([[1.0]]), 1e-6));
+
+        // 未参与计算的变量梯度为零
+        let tape = Tape::new();
+        let unused = tape.var(Matrix::<f64, 2, 2>::filled(1.0));
+        let grads = tape.var(y.clone()).sum().backward();
+        assert_eq!(grads.wrt(&unused), Matrix::filled(0.0));
+        assert_eq!(tape.len(), 3);
+    }
+}
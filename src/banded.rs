@@ -0,0 +1,245 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Div, Mul, Sub};
+
+use num_traits::Zero;
+
+use crate::{Matrix, MatrixError, Result};
+
+// 带状存储：第 i 行保存列 i - lower ..= i + upper，越界位置填零
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BandedMatrix<T> {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<T>,
+}
+
+impl<T> BandedMatrix<T> {
+    pub fn zeros(n: usize, lower: usize, upper: usize) -> Self
+    where
+        T: Zero + Clone,
+    {
+        BandedMatrix {
+            n,
+            lower,
+            upper,
+            data: vec![T::zero(); n * (lower + upper + 1)],
+        }
+    }
+
+    // sub/sup 长度为 n - 1，diag 长度为 n
+    pub fn tridiagonal(sub: &[T], diag: &[T], sup: &[T]) -> Result<Self>
+    where
+        T: Zero + Clone,
+    {
+        let n = diag.len();
+        for band in [sub, sup] {
+            if band.len() + 1 != n.max(1) {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: n.saturating_sub(1),
+                    got: band.len(),
+                });
+            }
+        }
+        let mut result = Self::zeros(n, 1, 1);
+        for i in 0..n {
+            result.data[i * 3 + 1] = diag[i].clone();
+            if i > 0 {
+                result.data[i * 3] = sub[i - 1].clone();
+            }
+            if i + 1 < n {
+                result.data[i * 3 + 2] = sup[i].clone();
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn from_dense<const N: usize>(matrix: &Matrix<T, N, N>, lower: usize, upper: usize) -> Self
+    where
+        T: Zero + Clone,
+    {
+        let mut result = Self::zeros(N, lower, upper);
+        for i in 0..N {
+            for j in result.row_range(i) {
+                let offset = result.offset(i, j);
+                result.data[offset] = matrix[i][j].clone();
+            }
+        }
+        result
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn lower_bandwidth(&self) -> usize {
+        self.lower
+    }
+
+    pub fn upper_bandwidth(&self) -> usize {
+        self.upper
+    }
+
+    fn width(&self) -> usize {
+        self.lower + self.upper + 1
+    }
+
+    fn offset(&self, i: usize, j: usize) -> usize {
+        i * self.width() + (j + self.lower - i)
+    }
+
+    fn row_range(&self, i: usize) -> core::ops::Range<usize> {
+        i.saturating_sub(self.lower)..(i + self.upper + 1).min(self.n)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        (i < self.n && self.row_range(i).contains(&j)).then(|| &self.data[self.offset(i, j)])
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i < self.n && self.row_range(i).contains(&j) {
+            let offset = self.offset(i, j);
+            Some(&mut self.data[offset])
+        } else {
+            None
+        }
+    }
+
+    pub fn to_dense<const N: usize>(&self) -> Result<Matrix<T, N, N>>
+    where
+        T: Zero + Clone,
+    {
+        if self.n != N {
+            return Err(MatrixError::DimensionMismatch {
+                expected: N,
+                got: self.n,
+            });
+        }
+        let mut result = Matrix::zero();
+        for i in 0..N {
+            for j in self.row_range(i) {
+                result[i][j] = self.data[self.offset(i, j)].clone();
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn dot_vector(&self, vector: &[T]) -> Result<Vec<T>>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        if vector.len() != self.n {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.n,
+                got: vector.len(),
+            });
+        }
+        Ok((0..self.n)
+            .map(|i| {
+                self.row_range(i).fold(T::zero(), |sum, j| {
+                    sum + self.data[self.offset(i, j)].clone() * vector[j].clone()
+                })
+            })
+            .collect())
+    }
+
+    // Thomas 算法，O(n)；不做主元选取，要求矩阵对角占优或对称正定
+    pub fn solve_tridiagonal(&self, rhs: &[T]) -> Result<Vec<T>>
+    where
+        T: Zero + PartialEq + Sub<Output = T> + Mul<Output = T> + Div<Output = T> + Clone,
+    {
+        if self.lower > 1 || self.upper > 1 {
+            return Err(MatrixError::NotTridiagonal {
+                lower: self.lower,
+                upper: self.upper,
+            });
+        }
+        if rhs.len() != self.n {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.n,
+                got: rhs.len(),
+            });
+        }
+        let zero = T::zero();
+        let band = |i: usize, j: usize| self.get(i, j).cloned().unwrap_or_else(T::zero);
+
+        let mut c_prime = vec![zero.clone(); self.n];
+        let mut d_prime = vec![zero.clone(); self.n];
+        for i in 0..self.n {
+            let a = if i > 0 { band(i, i - 1) } else { T::zero() };
+            let (c_prev, d_prev) = if i > 0 {
+                (c_prime[i - 1].clone(), d_prime[i - 1].clone())
+            } else {
+                (T::zero(), T::zero())
+            };
+            let pivot = band(i, i) - a.clone() * c_prev;
+            if pivot == zero {
+                return Err(MatrixError::Singular);
+            }
+            c_prime[i] = band(i, i + 1) / pivot.clone();
+            d_prime[i] = (rhs[i].clone() - a * d_prev) / pivot;
+        }
+
+        let mut result = d_prime;
+        for i in (0..self.n.saturating_sub(1)).rev() {
+            result[i] = result[i].clone() - c_prime[i].clone() * result[i + 1].clone();
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_storage() {
+        let m = Matrix::from([[1, 2, 0, 0], [3, 4, 5, 0], [0, 6, 7, 8], [0, 0, 9, 10]]);
+        let banded = BandedMatrix::from_dense(&m, 1, 1);
+        assert_eq!(banded.get(2, 1), Some(&6));
+        assert_eq!(banded.get(0, 2), None);
+        assert_eq!(banded.to_dense::<4>().unwrap(), m);
+        let tri = BandedMatrix::tridiagonal(&[3, 6, 9], &[1, 4, 7, 10], &[2, 5, 8]).unwrap();
+        assert_eq!(tri, banded);
+        assert_eq!(
+            banded.dot_vector(&[1, 1, 1, 1]).unwrap(),
+            vec![3, 12, 21, 19]
+        );
+    }
+
+    #[test]
+    fn test_wider_band_multiply() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let banded = BandedMatrix::from_dense(&m, 2, 0);
+        let lower = Matrix::from([[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+        let x = Matrix::from([[1], [2], [3]]);
+        let expected: Vec<i32> = lower.dot_product(&x).as_slice().to_vec();
+        assert_eq!(banded.dot_vector(&[1, 2, 3]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_thomas_solver() {
+        // 一维泊松方程离散：-u'' = f
+        let n = 5;
+        let tri = BandedMatrix::tridiagonal(&vec![-1.0; n - 1], &vec![2.0; n], &vec![-1.0; n - 1])
+            .unwrap();
+        let rhs = vec![1.0f64; n];
+        let solution = tri.solve_tridiagonal(&rhs).unwrap();
+        let residual = tri.dot_vector(&solution).unwrap();
+        for (r, b) in residual.iter().zip(&rhs) {
+            assert!((r - b).abs() < 1e-12);
+        }
+        assert_eq!(
+            BandedMatrix::<f64>::zeros(3, 2, 1)
+                .solve_tridiagonal(&[1.0; 3])
+                .unwrap_err(),
+            MatrixError::NotTridiagonal { lower: 2, upper: 1 }
+        );
+        assert_eq!(
+            BandedMatrix::<f64>::zeros(3, 1, 1)
+                .solve_tridiagonal(&[1.0; 3])
+                .unwrap_err(),
+            MatrixError::Singular
+        );
+    }
+}
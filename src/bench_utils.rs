@@ -0,0 +1,59 @@
+use rand::distributions::{Distribution, Standard};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dynamic::DynMatrics;
+use crate::Matrix;
+
+// 基准测试和示例用的数据生成器
+// 元素取 0..=255 的小整数再转换成 T，长度上万的 i32 点积也不会溢出，各元素类型的数据也一致
+
+// 固定种子，保证多次运行的输入相同，结果可以横向比较
+pub fn seeded_rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+pub fn random_matrix<T, const X: usize, const Y: usize>(rng: &mut impl Rng) -> Matrix<T, X, Y>
+where
+    T: From<u8>,
+{
+    Matrix::random_with(rng, Standard.map(T::from))
+}
+
+pub fn random_dyn_matrix<T, const X: usize, const Y: usize>(
+    rng: &mut impl Rng,
+) -> DynMatrics<T, X, Y>
+where
+    T: From<u8>,
+{
+    DynMatrics::random_with(rng, Standard.map(T::from))
+}
+
+// 运行时尺寸的数据，配合 dot_product_slices 使用
+pub fn random_vec<T>(rng: &mut impl Rng, len: usize) -> Vec<T>
+where
+    T: From<u8>,
+{
+    Standard.map(T::from).sample_iter(rng).take(len).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_generators() {
+        let a: Matrix<i32, 3, 4> = random_matrix(&mut seeded_rng(7));
+        let b: Matrix<i32, 3, 4> = random_matrix(&mut seeded_rng(7));
+        assert_eq!(a, b);
+        assert!(a.as_slice().iter().all(|&x| (0..=255).contains(&x)));
+
+        // 相同种子下不同元素类型得到相同的数值
+        let d: DynMatrics<f64, 3, 4> = random_dyn_matrix(&mut seeded_rng(7));
+        assert!(a
+            .as_slice()
+            .iter()
+            .zip(d.as_slice())
+            .all(|(&x, &y)| x as f64 == y));
+    }
+}
@@ -1,59 +1,388 @@
+use matrix::bench_utils::{random_dyn_matrix, random_matrix, random_vec, seeded_rng};
 use matrix::dynamic::DynMatrics;
-use matrix::Matrix;
+use matrix::{dot_product_slices, GemmElement, Matrix};
+use num_traits::Zero;
+use std::ops::Mul;
+use std::process::ExitCode;
 use std::time::Instant;
 
-fn main() {
-    let cpus = num_cpus::get();
-    println!("cpus: {}", cpus);
+const USAGE: &str = "\
+usage: main [options]
+  --size MxKxN[,...]     multiply M×K by K×N (default 16x10000x1000)
+  --dtype TYPE[,...]     i32, i64, f32 or f64 (default i32)
+  --threads N[,...]      thread counts, 1 is sequential (default 1,<cpus>)
+  --iterations N         timed runs per case (default 5)
+  --warmup N             untimed runs per case (default 1)
+  --format FORMAT        text, json or csv (default text)
+  --seed N               seed for the input data (default 42)
+  --storage KIND[,...]   slice, matrix or dyn (default slice,matrix,dyn);
+                         matrix and dyn are compiled for 16x10000x1000 only";
 
-    let a = generate_matrix::<16, 10000>();
-    let b = generate_matrix::<10000, 1000>();
+// Matrix 与 DynMatrics 的维度是编译期常量，只能比较这一种尺寸
+const FIXED_SIZE: (usize, usize, usize) = (16, 10000, 1000);
 
-    let start = Instant::now();
-    let _result_sequential = a.dot_product(&b);
-    let duration_sequential = start.elapsed();
+// slice 为运行时尺寸的 dot_product_slices，matrix、dyn 分别是 Box 数组与 Vec 存储的定长矩阵
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Storage {
+    Slice,
+    Matrix,
+    Dyn,
+}
+
+impl Storage {
+    fn name(self) -> &'static str {
+        match self {
+            Storage::Slice => "slice",
+            Storage::Matrix => "matrix",
+            Storage::Dyn => "dyn",
+        }
+    }
+}
 
-    let start = Instant::now();
-    let _result_parallel = a.dot_product_in_parallel(&b, cpus);
-    let duration_parallel = start.elapsed();
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
 
-    println!("Sequential dot product took: {:?}", duration_sequential);
-    println!("Parallel dot product took: {:?}", duration_parallel);
+#[derive(Debug)]
+struct Options {
+    sizes: Vec<(usize, usize, usize)>,
+    dtypes: Vec<String>,
+    threads: Vec<usize>,
+    iterations: usize,
+    warmup: usize,
+    format: Format,
+    seed: u64,
+    storages: Vec<Storage>,
+}
 
-    let a = generate_dynamic_matrix::<16, 10000>();
-    let b = generate_dynamic_matrix::<10000, 1000>();
+struct Record {
+    m: usize,
+    k: usize,
+    n: usize,
+    dtype: String,
+    storage: Storage,
+    threads: usize,
+    mean: f64,
+    stddev: f64,
+    gflops: f64,
+}
+
+fn parse_list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    value.split(',').map(|item| parse(item.trim())).collect()
+}
 
-    let start = Instant::now();
-    let _result_sequential = a.dot_product(&b);
-    let duration_sequential = start.elapsed();
+fn parse_size(value: &str) -> Option<(usize, usize, usize)> {
+    let dims: Vec<usize> = value
+        .split(['x', 'X'])
+        .map(|dim| dim.parse().ok())
+        .collect::<Option<_>>()?;
+    match dims[..] {
+        [m, k, n] => Some((m, k, n)),
+        _ => None,
+    }
+}
 
-    let start = Instant::now();
-    let _result_parallel = a.dot_product_in_parallel(&b, cpus);
-    let duration_parallel = start.elapsed();
+// 返回 Ok(None) 表示要求显示帮助
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        sizes: vec![(16, 10000, 1000)],
+        dtypes: vec!["i32".to_string()],
+        threads: vec![1, num_cpus::get()],
+        iterations: 5,
+        warmup: 1,
+        format: Format::Text,
+        seed: 42,
+        storages: vec![Storage::Slice, Storage::Matrix, Storage::Dyn],
+    };
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        if flag == "-h" || flag == "--help" {
+            return Ok(None);
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("missing value for {}", flag))?;
+        let invalid = || format!("invalid value for {}: {:?}", flag, value);
+        match flag.as_str() {
+            "--size" => options.sizes = parse_list(&value, parse_size).ok_or_else(invalid)?,
+            "--dtype" => {
+                options.dtypes = parse_list(&value, |dtype| {
+                    ["i32", "i64", "f32", "f64"]
+                        .contains(&dtype)
+                        .then(|| dtype.to_string())
+                })
+                .ok_or_else(invalid)?
+            }
+            "--threads" => {
+                options.threads =
+                    parse_list(&value, |n| n.parse().ok().filter(|&n| n > 0)).ok_or_else(invalid)?
+            }
+            "--iterations" => {
+                options.iterations = value.parse().ok().filter(|&n| n > 0).ok_or_else(invalid)?
+            }
+            "--warmup" => options.warmup = value.parse().map_err(|_| invalid())?,
+            "--seed" => options.seed = value.parse().map_err(|_| invalid())?,
+            "--format" => {
+                options.format = match value.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    _ => return Err(invalid()),
+                }
+            }
+            "--storage" => {
+                options.storages = parse_list(&value, |kind| match kind {
+                    "slice" => Some(Storage::Slice),
+                    "matrix" => Some(Storage::Matrix),
+                    "dyn" => Some(Storage::Dyn),
+                    _ => None,
+                })
+                .ok_or_else(invalid)?
+            }
+            _ => return Err(format!("unknown option {}\n{}", flag, USAGE)),
+        }
+    }
+    options.threads.dedup();
+    Ok(Some(options))
+}
 
-    println!(
-        "(dyn) Sequential dot product took: {:?}",
-        duration_sequential
-    );
-    println!("(dyn) Parallel dot product took: {:?}", duration_parallel);
+// 返回各线程数下的平均耗时与标准差（秒），run 接收线程数，1 表示串行
+fn measure<R>(options: &Options, run: impl Fn(usize) -> R) -> Vec<(usize, f64, f64)> {
+    options
+        .threads
+        .iter()
+        .map(|&threads| {
+            for _ in 0..options.warmup {
+                std::hint::black_box(run(threads));
+            }
+            let times: Vec<f64> = (0..options.iterations)
+                .map(|_| {
+                    let start = Instant::now();
+                    std::hint::black_box(run(threads));
+                    start.elapsed().as_secs_f64()
+                })
+                .collect();
+            let mean = times.iter().sum::<f64>() / times.len() as f64;
+            let variance =
+                times.iter().map(|t| (t - mean) * (t - mean)).sum::<f64>() / times.len() as f64;
+            (threads, mean, variance.sqrt())
+        })
+        .collect()
 }
 
-fn generate_matrix<const X: usize, const Y: usize>() -> Matrix<i32, X, Y> {
-    let mut matrix = Matrix::default();
-    for i in 0..X {
-        for j in 0..Y {
-            matrix[i][j] = rand::random::<u8>() as _;
+fn bench<T>(
+    options: &Options,
+    storage: Storage,
+    (m, k, n): (usize, usize, usize),
+) -> Vec<(usize, f64, f64)>
+where
+    T: From<u8> + Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+{
+    let mut rng = seeded_rng(options.seed);
+    match storage {
+        Storage::Slice => {
+            let a: Vec<T> = random_vec(&mut rng, m * k);
+            let b: Vec<T> = random_vec(&mut rng, k * n);
+            measure(options, |threads| {
+                dot_product_slices(&a, &b, m, k, n, threads).expect("inputs match sizes")
+            })
+        }
+        Storage::Matrix => {
+            let a: Matrix<T, 16, 10000> = random_matrix(&mut rng);
+            let b: Matrix<T, 10000, 1000> = random_matrix(&mut rng);
+            measure(options, |threads| match threads {
+                1 => a.dot_product(&b),
+                _ => a.dot_product_in_parallel(&b, threads),
+            })
+        }
+        Storage::Dyn => {
+            let a: DynMatrics<T, 16, 10000> = random_dyn_matrix(&mut rng);
+            let b: DynMatrics<T, 10000, 1000> = random_dyn_matrix(&mut rng);
+            measure(options, |threads| match threads {
+                1 => a.dot_product(&b),
+                _ => a.dot_product_in_parallel(&b, threads),
+            })
         }
     }
-    matrix
 }
 
-fn generate_dynamic_matrix<const X: usize, const Y: usize>() -> DynMatrics<i32, X, Y> {
-    let mut matrix = DynMatrics::default();
-    for i in 0..X {
-        for j in 0..Y {
-            matrix[i][j] = rand::random::<u8>() as _;
+fn print_records(records: &[Record], format: Format) {
+    match format {
+        Format::Text => {
+            for r in records {
+                println!(
+                    "{}x{}x{} {} {} threads={}: mean {:.3} ms, stddev {:.3} ms, {:.2} GFLOP/s",
+                    r.m,
+                    r.k,
+                    r.n,
+                    r.dtype,
+                    r.storage.name(),
+                    r.threads,
+                    r.mean * 1e3,
+                    r.stddev * 1e3,
+                    r.gflops
+                );
+            }
+        }
+        Format::Csv => {
+            println!("m,k,n,dtype,storage,threads,mean_ms,stddev_ms,gflops");
+            for r in records {
+                println!(
+                    "{},{},{},{},{},{},{:.6},{:.6},{:.6}",
+                    r.m,
+                    r.k,
+                    r.n,
+                    r.dtype,
+                    r.storage.name(),
+                    r.threads,
+                    r.mean * 1e3,
+                    r.stddev * 1e3,
+                    r.gflops
+                );
+            }
+        }
+        Format::Json => {
+            let items: Vec<String> = records
+                .iter()
+                .map(|r| {
+                    format!(
+                        "  {{\"m\": {}, \"k\": {}, \"n\": {}, \"dtype\": \"{}\", \"storage\": \"{}\", \
+                         \"threads\": {}, \"mean_ms\": {:.6}, \"stddev_ms\": {:.6}, \"gflops\": {:.6}}}",
+                        r.m,
+                        r.k,
+                        r.n,
+                        r.dtype,
+                        r.storage.name(),
+                        r.threads,
+                        r.mean * 1e3,
+                        r.stddev * 1e3,
+                        r.gflops
+                    )
+                })
+                .collect();
+            println!("[\n{}\n]", items.join(",\n"));
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    if options.format == Format::Text {
+        println!("cpus: {}", num_cpus::get());
+    }
+
+    let mut records = Vec::new();
+    for &size in &options.sizes {
+        for (dtype, &storage) in options
+            .dtypes
+            .iter()
+            .flat_map(|dtype| options.storages.iter().map(move |s| (dtype, s)))
+        {
+            if storage != Storage::Slice && size != FIXED_SIZE {
+                eprintln!(
+                    "skipping {} storage for {}x{}x{}: only {}x{}x{} is compiled in",
+                    storage.name(),
+                    size.0,
+                    size.1,
+                    size.2,
+                    FIXED_SIZE.0,
+                    FIXED_SIZE.1,
+                    FIXED_SIZE.2
+                );
+                continue;
+            }
+            let results = match dtype.as_str() {
+                "i32" => bench::<i32>(&options, storage, size),
+                "i64" => bench::<i64>(&options, storage, size),
+                "f32" => bench::<f32>(&options, storage, size),
+                _ => bench::<f64>(&options, storage, size),
+            };
+            let (m, k, n) = size;
+            // 每个输出元素 k 次乘法和 k 次加法
+            let flops = 2.0 * (m * k * n) as f64;
+            records.extend(results.into_iter().map(|(threads, mean, stddev)| Record {
+                m,
+                k,
+                n,
+                dtype: dtype.clone(),
+                storage,
+                threads,
+                mean,
+                stddev,
+                gflops: flops / mean / 1e9,
+            }));
+        }
+    }
+    print_records(&records, options.format);
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Options>, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let options = parse(&[]).unwrap().unwrap();
+        assert_eq!(options.sizes, vec![FIXED_SIZE]);
+        assert_eq!(options.storages.len(), 3);
+
+        let options = parse(&[
+            "--size",
+            "2x3x4, 5X6X7",
+            "--dtype",
+            "f32,i64",
+            "--threads",
+            "1,1,4",
+            "--format",
+            "csv",
+            "--storage",
+            "dyn",
+            "--warmup",
+            "0",
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(options.sizes, vec![(2, 3, 4), (5, 6, 7)]);
+        assert_eq!(options.dtypes, vec!["f32", "i64"]);
+        assert_eq!(options.threads, vec![1, 4]);
+        assert_eq!(options.format, Format::Csv);
+        assert_eq!(options.storages, vec![Storage::Dyn]);
+        assert_eq!(options.warmup, 0);
+
+        // --help 不是错误
+        assert!(parse(&["--size", "1x1x1", "--help"]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        for args in [
+            &["--size", "2x3"][..],
+            &["--dtype", "u8"],
+            &["--threads", "0"],
+            &["--iterations", "0"],
+            &["--storage", "array"],
+            &["--seed"],
+            &["--verbose", "1"],
+        ] {
+            assert!(parse(args).is_err(), "{:?}", args);
         }
     }
-    matrix
 }
@@ -0,0 +1,392 @@
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, Write};
+
+use matrix::dynamic::DynMatrix;
+use matrix::io::csv::CsvOptions;
+use matrix::ParallelConfig;
+
+const HELP: &str = "\
+  A = [1 2; 3 4]      define a matrix (elements separated by spaces or commas, rows by ;)
+  C = A * B' + 2 * A  evaluate an expression: + - * (matrix product), ' (transpose), ( )
+  A - [1 2] + 1       + and - broadcast rows, columns and scalars
+  A                   print a variable or expression
+  load A a.csv        read a matrix from a CSV file
+  save C c.csv        write a matrix to a CSV file
+  vars                list defined matrices
+  help                show this message
+  quit                exit";
+
+type Mat = DynMatrix<f64>;
+
+fn shape(m: &Mat) -> String {
+    format!("{}x{}", m.rows(), m.cols())
+}
+
+// 数字按标量处理，与矩阵相乘时逐元素缩放
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Scalar(f64),
+    Matrix(Mat),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Symbol(char),
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut text = String::new();
+            while let Some(&c) = chars.peek() {
+                // 允许 1e-3 这样的指数形式
+                let exponent_sign = (c == '-' || c == '+') && text.ends_with(['e', 'E']);
+                if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || exponent_sign {
+                    text.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let value = text
+                .parse()
+                .map_err(|_| format!("invalid number {:?}", text))?;
+            tokens.push(Token::Number(value));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut name = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_alphanumeric() || c == '_' {
+                    name.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Ident(name));
+        } else if "+-*'()[];,".contains(c) {
+            tokens.push(Token::Symbol(c));
+            chars.next();
+        } else {
+            return Err(format!("unexpected character {:?}", c));
+        }
+    }
+    Ok(tokens)
+}
+
+// 递归下降：expr = term (('+' | '-') term)*，term = unary ('*' unary)*，
+// unary = '-' unary | postfix，postfix = primary '\''*
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    vars: &'a BTreeMap<String, Mat>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), String> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(format!("expected {:?}", symbol))
+        }
+    }
+
+    fn expr(&mut self) -> Result<Value, String> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value = add(value, self.term()?, 1.0)?;
+            } else if self.eat('-') {
+                value = add(value, self.term()?, -1.0)?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Value, String> {
+        let mut value = self.unary()?;
+        while self.eat('*') {
+            value = multiply(value, self.unary()?)?;
+        }
+        Ok(value)
+    }
+
+    fn unary(&mut self) -> Result<Value, String> {
+        if self.eat('-') {
+            return multiply(Value::Scalar(-1.0), self.unary()?);
+        }
+        let mut value = self.primary()?;
+        while self.eat('\'') {
+            value = match value {
+                Value::Matrix(m) => Value::Matrix(m.transpose()),
+                scalar => scalar,
+            };
+        }
+        Ok(value)
+    }
+
+    fn primary(&mut self) -> Result<Value, String> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(Token::Number(value)) => {
+                self.pos += 1;
+                Ok(Value::Scalar(value))
+            }
+            Some(Token::Ident(name)) => {
+                self.pos += 1;
+                self.vars
+                    .get(&name)
+                    .cloned()
+                    .map(Value::Matrix)
+                    .ok_or_else(|| format!("undefined matrix {}", name))
+            }
+            Some(Token::Symbol('(')) => {
+                self.pos += 1;
+                let value = self.expr()?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some(Token::Symbol('[')) => {
+                self.pos += 1;
+                self.literal()
+            }
+            Some(token) => Err(format!("unexpected {:?}", token)),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    // `[` 之后的部分：元素是可带负号的数字
+    fn literal(&mut self) -> Result<Value, String> {
+        let mut rows: Vec<Vec<f64>> = vec![Vec::new()];
+        loop {
+            if self.eat(']') {
+                break;
+            } else if self.eat(';') {
+                rows.push(Vec::new());
+            } else if self.eat(',') {
+                continue;
+            } else {
+                let sign = if self.eat('-') { -1.0 } else { 1.0 };
+                match self.peek() {
+                    Some(&Token::Number(value)) => {
+                        self.pos += 1;
+                        rows.last_mut().unwrap().push(sign * value);
+                    }
+                    _ => return Err("expected a number in matrix literal".to_string()),
+                }
+            }
+        }
+        // 允许末尾多写一个分号
+        if rows.len() > 1 && rows.last().is_some_and(Vec::is_empty) {
+            rows.pop();
+        }
+        let cols = rows[0].len();
+        if let Some(row) = rows.iter().find(|row| row.len() != cols) {
+            return Err(format!(
+                "rows have different lengths: {} and {}",
+                cols,
+                row.len()
+            ));
+        }
+        let rows_count = if cols == 0 { 0 } else { rows.len() };
+        let m = Mat::new(rows_count, cols, rows.concat()).expect("all rows have cols elements");
+        Ok(Value::Matrix(m))
+    }
+}
+
+fn add(lhs: Value, rhs: Value, sign: f64) -> Result<Value, String> {
+    match (lhs, rhs) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a + sign * b)),
+        // 标量视作 1×1 矩阵，按 NumPy 规则广播
+        (lhs, rhs) => {
+            let as_matrix = |value| match value {
+                Value::Scalar(s) => Mat::filled(1, 1, s),
+                Value::Matrix(m) => m,
+            };
+            let (a, b) = (as_matrix(lhs), as_matrix(rhs));
+            let sum = if sign > 0.0 {
+                a.broadcast_add(&b)
+            } else {
+                a.broadcast_sub(&b)
+            };
+            sum.map(Value::Matrix)
+                .map_err(|_| format!("cannot add {} and {}", shape(&a), shape(&b)))
+        }
+    }
+}
+
+fn multiply(lhs: Value, rhs: Value) -> Result<Value, String> {
+    match (lhs, rhs) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a * b)),
+        (Value::Scalar(s), Value::Matrix(m)) | (Value::Matrix(m), Value::Scalar(s)) => {
+            Ok(Value::Matrix(Mat::from_fn(m.rows(), m.cols(), |i, j| {
+                s * m[i][j]
+            })))
+        }
+        (Value::Matrix(a), Value::Matrix(b)) => {
+            let product = match ParallelConfig::default().threads_for(a.rows(), a.cols(), b.cols())
+            {
+                1 => a.dot_product(&b),
+                threads => a.dot_product_in_parallel(&b, threads),
+            };
+            product
+                .map(Value::Matrix)
+                .map_err(|_| format!("cannot multiply {} by {}", shape(&a), shape(&b)))
+        }
+    }
+}
+
+fn evaluate(input: &str, vars: &BTreeMap<String, Mat>) -> Result<Value, String> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        vars,
+    };
+    let value = parser.expr()?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(token) => Err(format!("unexpected {:?}", token)),
+    }
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Scalar(x) => x.to_string(),
+        Value::Matrix(m) if m.as_slice().is_empty() => format!("{} matrix", shape(m)),
+        Value::Matrix(m) => format!("{} matrix\n{:#}", shape(m), m),
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+// 执行一行输入，返回需要打印的内容；Ok(None) 表示退出
+fn execute(line: &str, vars: &mut BTreeMap<String, Mat>) -> Result<Option<String>, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        [] => Ok(Some(String::new())),
+        ["quit" | "exit"] => Ok(None),
+        ["help"] => Ok(Some(HELP.to_string())),
+        ["vars"] => Ok(Some(
+            vars.iter()
+                .map(|(name, m)| format!("{}: {}", name, shape(m)))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )),
+        ["load", name, path] if is_name(name) => {
+            let m = Mat::load_csv(path, &CsvOptions::new())
+                .map_err(|err| format!("{}: {}", path, err))?;
+            let out = format!("{} = {} matrix", name, shape(&m));
+            vars.insert(name.to_string(), m);
+            Ok(Some(out))
+        }
+        ["save", name, path] => {
+            let m = vars
+                .get(*name)
+                .ok_or_else(|| format!("undefined matrix {}", name))?;
+            m.save_csv(path, &CsvOptions::new())
+                .map_err(|err| format!("{}: {}", path, err))?;
+            Ok(Some(format!("saved {} to {}", name, path)))
+        }
+        _ => match line.split_once('=') {
+            Some((name, expr)) if is_name(name.trim()) => {
+                let name = name.trim();
+                match evaluate(expr, vars)? {
+                    Value::Matrix(m) => {
+                        let out = format!("{} = {}", name, format_value(&Value::Matrix(m.clone())));
+                        vars.insert(name.to_string(), m);
+                        Ok(Some(out))
+                    }
+                    Value::Scalar(_) => Err("only matrices can be assigned".to_string()),
+                }
+            }
+            _ => evaluate(line, vars).map(|value| Some(format_value(&value))),
+        },
+    }
+}
+
+fn main() {
+    println!("matrix repl, type `help` for commands");
+    let mut vars = BTreeMap::new();
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!(">> ");
+        io::stdout().flush().expect("failed to flush stdout");
+        let Some(Ok(line)) = lines.next() else { break };
+        match execute(&line, &mut vars) {
+            Ok(Some(out)) if out.is_empty() => {}
+            Ok(Some(out)) => println!("{}", out),
+            Ok(None) => break,
+            Err(err) => println!("error: {}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(rows: usize, cols: usize, data: &[f64]) -> Mat {
+        Mat::new(rows, cols, data.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_define_and_evaluate() {
+        let mut vars = BTreeMap::new();
+        execute("A = [1 2; 3 4]", &mut vars).unwrap();
+        execute("B = [1, 0, -1; 0, 1, 2.5e0]", &mut vars).unwrap();
+        assert_eq!(vars["B"], matrix(2, 3, &[1.0, 0.0, -1.0, 0.0, 1.0, 2.5]));
+
+        execute("C = A * B'' - 2 * -B", &mut vars).unwrap();
+        assert_eq!(vars["C"], matrix(2, 3, &[3.0, 2.0, 2.0, 3.0, 6.0, 12.0]));
+        execute("D = (A + A') * [1; 1]", &mut vars).unwrap();
+        assert_eq!(vars["D"], matrix(2, 1, &[7.0, 13.0]));
+
+        assert_eq!(
+            evaluate("B * B'", &vars).unwrap(),
+            Value::Matrix(matrix(2, 2, &[2.0, -2.5, -2.5, 7.25]))
+        );
+        assert_eq!(evaluate("2 * (3 - 1)", &vars).unwrap(), Value::Scalar(4.0));
+        // 行向量与标量广播
+        assert_eq!(
+            evaluate("10 - A + [1 2]", &vars).unwrap(),
+            Value::Matrix(matrix(2, 2, &[10.0, 10.0, 8.0, 8.0]))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut vars = BTreeMap::new();
+        execute("A = [1 2; 3 4]", &mut vars).unwrap();
+        assert!(execute("A * [1 2 3]", &mut vars).is_err());
+        assert!(execute("A + [1 2 3]", &mut vars).is_err());
+        assert!(execute("A + X", &mut vars).is_err());
+        assert!(execute("B = [1 2; 3]", &mut vars).is_err());
+        assert!(execute("B = A +", &mut vars).is_err());
+        assert!(execute("B = 3", &mut vars).is_err());
+        assert_eq!(execute("quit", &mut vars), Ok(None));
+    }
+}
@@ -0,0 +1,156 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use matrix::dynamic::DynMatrix;
+use matrix::io::csv::CsvOptions;
+
+// 在命令行中对 CSV 文件做矩阵运算，路径为 `-` 时读标准输入，未指定 -o 时写标准输出
+#[derive(Parser)]
+#[command(name = "matrix", about = "Matrix operations on CSV files")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct Format {
+    /// Field delimiter
+    #[arg(short, long, default_value_t = ',')]
+    delimiter: char,
+    /// Skip the first line of every input
+    #[arg(long)]
+    header: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Multiply two matrices: A·B
+    Multiply {
+        a: PathBuf,
+        b: PathBuf,
+        /// Output file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Number of threads, defaults to the number of CPUs
+        #[arg(short, long)]
+        threads: Option<usize>,
+        #[command(flatten)]
+        format: Format,
+    },
+    /// Transpose a matrix
+    Transpose {
+        input: PathBuf,
+        /// Output file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        #[command(flatten)]
+        format: Format,
+    },
+    /// Print the shape and summary statistics of a matrix
+    Info {
+        input: PathBuf,
+        #[command(flatten)]
+        format: Format,
+    },
+}
+
+fn read(path: &PathBuf, options: &CsvOptions) -> Result<DynMatrix<f64>, String> {
+    let reader: Box<dyn Read> = if path.as_os_str() == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(File::open(path).map_err(|err| format!("{}: {}", path.display(), err))?)
+    };
+    DynMatrix::from_csv_reader(reader, options)
+        .map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+fn write(output: Option<&PathBuf>, format: &Format, matrix: &DynMatrix<f64>) -> Result<(), String> {
+    let writer: Box<dyn Write> = match output {
+        Some(path) => Box::new(BufWriter::new(
+            File::create(path).map_err(|err| format!("{}: {}", path.display(), err))?,
+        )),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    let options = CsvOptions::new().delimiter(format.delimiter);
+    matrix
+        .write_csv(writer, &options)
+        .map_err(|err| err.to_string())
+}
+
+fn options(format: &Format) -> CsvOptions {
+    CsvOptions::new()
+        .delimiter(format.delimiter)
+        .has_header(format.header)
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    match cli.command {
+        Command::Multiply {
+            a,
+            b,
+            output,
+            threads,
+            format,
+        } => {
+            let options = options(&format);
+            let (a, b) = (read(&a, &options)?, read(&b, &options)?);
+            if a.cols() != b.rows() {
+                return Err(format!(
+                    "cannot multiply {}x{} by {}x{}",
+                    a.rows(),
+                    a.cols(),
+                    b.rows(),
+                    b.cols()
+                ));
+            }
+            let threads = threads.unwrap_or_else(num_cpus::get);
+            if threads == 0 {
+                return Err("--threads must be at least 1".to_string());
+            }
+            let product = match threads {
+                1 => a.dot_product(&b),
+                threads => a.dot_product_in_parallel(&b, threads),
+            };
+            let product = product.map_err(|err| err.to_string())?;
+            write(output.as_ref(), &format, &product)
+        }
+        Command::Transpose {
+            input,
+            output,
+            format,
+        } => {
+            let matrix = read(&input, &options(&format))?;
+            write(output.as_ref(), &format, &matrix.transpose())
+        }
+        Command::Info { input, format } => {
+            let matrix = read(&input, &options(&format))?;
+            println!("rows: {}", matrix.rows());
+            println!("cols: {}", matrix.cols());
+            let data = matrix.as_slice();
+            if !data.is_empty() {
+                let min = data.iter().copied().fold(f64::INFINITY, f64::min);
+                let max = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                let sum: f64 = data.iter().sum();
+                let zeros = data.iter().filter(|&&x| x == 0.0).count();
+                println!("min: {}", min);
+                println!("max: {}", max);
+                println!("mean: {}", sum / data.len() as f64);
+                println!("zeros: {}", zeros);
+            }
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{BitAnd, BitOr, BitXor};
+
+use crate::Matrix;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+// 每个 u64 存 64 个布尔值，每行按字对齐，行尾多余的位始终为 0
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct BitMatrix<const R: usize, const C: usize> {
+    words: Vec<u64>,
+}
+
+impl<const R: usize, const C: usize> Default for BitMatrix<R, C> {
+    fn default() -> Self {
+        BitMatrix {
+            words: vec![0; R * Self::WORDS_PER_ROW],
+        }
+    }
+}
+
+impl<const R: usize, const C: usize> BitMatrix<R, C> {
+    const WORDS_PER_ROW: usize = C.div_ceil(WORD_BITS);
+
+    pub fn identity() -> Self {
+        let mut result = Self::default();
+        for i in 0..R.min(C) {
+            result.set(i, i, true);
+        }
+        result
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(i < R && j < C, "index ({}, {}) out of bounds", i, j);
+        self.row_words(i)[j / WORD_BITS] >> (j % WORD_BITS) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, value: bool) {
+        assert!(i < R && j < C, "index ({}, {}) out of bounds", i, j);
+        let word = &mut self.words[i * Self::WORDS_PER_ROW + j / WORD_BITS];
+        let mask = 1u64 << (j % WORD_BITS);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    pub fn row_words(&self, i: usize) -> &[u64] {
+        &self.words[i * Self::WORDS_PER_ROW..(i + 1) * Self::WORDS_PER_ROW]
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // 布尔半环上的乘法：result[i][j] = OR_k (self[i][k] AND matrix1[k][j])
+    // 对 self[i][k] 为真的 k，直接按字把 matrix1 的第 k 行 OR 进结果
+    pub fn dot_product<const Z: usize>(&self, matrix1: &BitMatrix<C, Z>) -> BitMatrix<R, Z> {
+        let mut result = BitMatrix::<R, Z>::default();
+        let words_per_row = BitMatrix::<R, Z>::WORDS_PER_ROW;
+        for (i, out) in result.words.chunks_mut(words_per_row.max(1)).enumerate() {
+            for (word_index, &word) in self.row_words(i).iter().enumerate() {
+                let mut bits = word;
+                while bits != 0 {
+                    let k = word_index * WORD_BITS + bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    for (target, &source) in out.iter_mut().zip(matrix1.row_words(k)) {
+                        *target |= source;
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn zip_words(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        BitMatrix {
+            words: self
+                .words
+                .iter()
+                .zip(&other.words)
+                .map(|(&a, &b)| op(a, b))
+                .collect(),
+        }
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<const R: usize, const C: usize> $trait for &BitMatrix<R, C> {
+            type Output = BitMatrix<R, C>;
+
+            fn $method(self, rhs: Self) -> BitMatrix<R, C> {
+                self.zip_words(rhs, |a, b| a $op b)
+            }
+        }
+
+        impl<const R: usize, const C: usize> $trait for BitMatrix<R, C> {
+            type Output = BitMatrix<R, C>;
+
+            fn $method(self, rhs: Self) -> BitMatrix<R, C> {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, &);
+impl_bit_op!(BitOr, bitor, |);
+impl_bit_op!(BitXor, bitxor, ^);
+
+impl<const R: usize, const C: usize> From<&Matrix<bool, R, C>> for BitMatrix<R, C> {
+    fn from(matrix: &Matrix<bool, R, C>) -> Self {
+        let mut result = Self::default();
+        for i in 0..R {
+            for j in 0..C {
+                if matrix[i][j] {
+                    result.set(i, j, true);
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<const R: usize, const C: usize> From<&BitMatrix<R, C>> for Matrix<bool, R, C> {
+    fn from(matrix: &BitMatrix<R, C>) -> Self {
+        let mut result = Matrix::default();
+        for i in 0..R {
+            for j in 0..C {
+                result[i][j] = matrix.get(i, j);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion_and_access() {
+        let m = Matrix::from([[true, false, true], [false, false, true]]);
+        let bits = BitMatrix::from(&m);
+        assert!(bits.get(0, 2));
+        assert!(!bits.get(1, 0));
+        assert_eq!(bits.count_ones(), 3);
+        assert_eq!(Matrix::from(&bits), m);
+    }
+
+    #[test]
+    fn test_boolean_ops() {
+        let a = BitMatrix::from(&Matrix::from([[true, true], [false, false]]));
+        let b = BitMatrix::from(&Matrix::from([[true, false], [true, false]]));
+        assert_eq!(
+            Matrix::from(&(&a & &b)),
+            Matrix::from([[true, false], [false, false]])
+        );
+        assert_eq!(
+            Matrix::from(&(&a | &b)),
+            Matrix::from([[true, true], [true, false]])
+        );
+        assert_eq!(
+            Matrix::from(&(a ^ b)),
+            Matrix::from([[false, true], [true, false]])
+        );
+    }
+
+    #[test]
+    fn test_boolean_product() {
+        // 跨越多个字的列数
+        let mut a = BitMatrix::<3, 130>::default();
+        let mut b = BitMatrix::<130, 70>::default();
+        a.set(0, 0, true);
+        a.set(0, 129, true);
+        a.set(2, 64, true);
+        b.set(0, 1, true);
+        b.set(129, 69, true);
+        b.set(64, 3, true);
+        b.set(5, 7, true);
+        let product = a.dot_product(&b);
+        assert!(product.get(0, 1));
+        assert!(product.get(0, 69));
+        assert!(product.get(2, 3));
+        assert!(!product.get(0, 7));
+        assert_eq!(product.count_ones(), 3);
+
+        assert_eq!(a.dot_product(&BitMatrix::identity()), a);
+    }
+}
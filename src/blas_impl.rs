@@ -0,0 +1,102 @@
+use std::any::TypeId;
+use std::mem::size_of;
+use std::os::raw::c_int;
+
+use cblas_sys::{cblas_dgemm, cblas_sgemm, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+
+use crate::gemm::Output;
+
+// T 为 f32/f64 时调用 cblas 计算 C = A·B 并返回 true；其他类型或无法交给 BLAS 的尺寸返回 false
+// 本 crate 只声明 cblas 接口，具体实现（OpenBLAS、Accelerate 等）由使用者链接，例如通过 blas-src
+pub(crate) fn gemm<T, S>(a: &[T], b: &[T], m: usize, k: usize, n: usize, c: &mut [S]) -> bool
+where
+    T: 'static,
+    S: Output<T>,
+{
+    // 空矩阵与 k == 0 交给纯 Rust 实现写零，不依赖各家 BLAS 对退化尺寸的处理
+    if m == 0 || k == 0 || n == 0 {
+        return false;
+    }
+    let (Ok(mi), Ok(ki), Ok(ni)) = (c_int::try_from(m), c_int::try_from(k), c_int::try_from(n))
+    else {
+        return false;
+    };
+    assert!(a.len() >= m * k && b.len() >= k * n && c.len() >= m * n);
+    // S 是 T 或 MaybeUninit<T>，与 T 布局相同；beta 为 0 时 BLAS 不读取 C 的原有内容
+    debug_assert_eq!(size_of::<S>(), size_of::<T>());
+    let (layout, no_trans) = (CBLAS_LAYOUT::CblasRowMajor, CBLAS_TRANSPOSE::CblasNoTrans);
+    if TypeId::of::<T>() == TypeId::of::<f32>() {
+        // SAFETY: T 就是 f32，上面已检查三个缓冲区的长度
+        unsafe {
+            cblas_sgemm(
+                layout,
+                no_trans,
+                no_trans,
+                mi,
+                ni,
+                ki,
+                1.0,
+                a.as_ptr().cast(),
+                ki,
+                b.as_ptr().cast(),
+                ni,
+                0.0,
+                c.as_mut_ptr().cast(),
+                ni,
+            );
+        }
+        true
+    } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+        // SAFETY: 同上，T 就是 f64
+        unsafe {
+            cblas_dgemm(
+                layout,
+                no_trans,
+                no_trans,
+                mi,
+                ni,
+                ki,
+                1.0,
+                a.as_ptr().cast(),
+                ki,
+                b.as_ptr().cast(),
+                ni,
+                0.0,
+                c.as_mut_ptr().cast(),
+                ni,
+            );
+        }
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_blas_matches_pure_rust() {
+        let a = Matrix::<f64, 5, 7>::from_fn(|i, j| (i * 7 + j) as f64 * 0.25 - 3.0);
+        let b = Matrix::<f64, 7, 3>::from_fn(|i, j| (i + j * 2) as f64 - 4.0);
+        // 这些值的乘积累加在 f64 中是精确的，BLAS 的求和顺序不影响结果
+        assert_eq!(a.dot_product(&b), a.dot_product_naive(&b));
+
+        let a = DynMatrics::<f32, 4, 6>::from_fn(|i, j| (i + j) as f32 * 0.5);
+        let b = DynMatrics::<f32, 6, 2>::from_fn(|i, j| (i * j) as f32 - 1.0);
+        let mut out = DynMatrics::filled(f32::NAN);
+        a.dot_product_into(&b, &mut out);
+        assert_eq!(out, a.dot_product_naive(&b));
+
+        // 非浮点类型与退化尺寸仍走纯 Rust 实现
+        let m = Matrix::from([[1, 2], [3, 4]]);
+        assert_eq!(m.dot_product(&m), Matrix::from([[7, 10], [15, 22]]));
+        let empty = Matrix::<f32, 2, 0>::default();
+        assert_eq!(
+            empty.dot_product(&Matrix::<f32, 0, 3>::default()),
+            Matrix::filled(0.0)
+        );
+    }
+}
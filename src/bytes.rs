@@ -0,0 +1,64 @@
+use bytemuck::Pod;
+
+use crate::{MatrixBase, MatrixError, OwnedStorage, Result, Storage};
+
+fn pod_vec_from_bytes<T: Pod>(bytes: &[u8], len: usize) -> Result<Vec<T>> {
+    let expected = len * std::mem::size_of::<T>();
+    if bytes.len() != expected {
+        return Err(MatrixError::DimensionMismatch {
+            expected,
+            got: bytes.len(),
+        });
+    }
+    // 输入不保证对齐，整体拷贝一次
+    Ok(bytemuck::pod_collect_to_vec(bytes))
+}
+
+impl<T: Pod, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.as_slice())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self>
+    where
+        S: OwnedStorage<T>,
+    {
+        Self::try_from(pod_vec_from_bytes::<T>(bytes, R * C)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::{Matrix, MatrixError};
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let m = Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]);
+        let bytes = m.as_bytes();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(Matrix::<f32, 2, 2>::from_bytes(bytes).unwrap(), m);
+    }
+
+    #[test]
+    fn test_bytes_unaligned() {
+        let m = DynMatrics::from([[1u32, 2, 3]]);
+        let mut buffer = vec![0u8];
+        buffer.extend_from_slice(m.as_bytes());
+        assert_eq!(
+            DynMatrics::<u32, 1, 3>::from_bytes(&buffer[1..]).unwrap(),
+            m
+        );
+    }
+
+    #[test]
+    fn test_bytes_wrong_length() {
+        assert_eq!(
+            Matrix::<u16, 2, 2>::from_bytes(&[0; 6]).unwrap_err(),
+            MatrixError::DimensionMismatch {
+                expected: 8,
+                got: 6
+            }
+        );
+    }
+}
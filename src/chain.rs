@@ -0,0 +1,159 @@
+use alloc::borrow::Cow;
+use alloc::{vec, vec::Vec};
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::dynamic::DynMatrix;
+use crate::{GemmElement, MatrixError, Result};
+
+// 连乘 A0·A1·…·An：先用动态规划求出标量乘法次数最少的加括号方式，再按该顺序相乘
+// 例如 A(10×100)·B(100×5)·C(5×50) 从左往右需 7500 次乘法，从右往左需 75000 次
+#[derive(Debug, Clone)]
+pub struct MatrixChain<'a, T> {
+    operands: Vec<&'a DynMatrix<T>>,
+}
+
+// 区间 [i, j] 的最优分割点 split[i][j]：先算 [i, k] 与 [k + 1, j] 再相乘
+struct Plan {
+    cost: usize,
+    split: Vec<Vec<usize>>,
+}
+
+impl<'a, T: Clone> MatrixChain<'a, T> {
+    pub fn new(first: &'a DynMatrix<T>) -> Self {
+        MatrixChain {
+            operands: vec![first],
+        }
+    }
+
+    // 在右侧追加一个因子，形状在 cost、evaluate 时检查
+    pub fn then(mut self, next: &'a DynMatrix<T>) -> Self {
+        self.operands.push(next);
+        self
+    }
+
+    // 相邻因子的列数与行数不符时报告第一处
+    fn check(&self) -> Result<()> {
+        for pair in self.operands.windows(2) {
+            if pair[0].cols() != pair[1].rows() {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: pair[0].cols(),
+                    got: pair[1].rows(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // 经典的 O(n³) 区间动态规划，dims[i]×dims[i + 1] 为第 i 个因子的形状
+    fn plan(&self) -> Plan {
+        let n = self.operands.len();
+        let dims: Vec<usize> = core::iter::once(self.operands[0].rows())
+            .chain(self.operands.iter().map(|m| m.cols()))
+            .collect();
+        let mut cost = vec![vec![0usize; n]; n];
+        let mut split = vec![vec![0; n]; n];
+        for len in 2..=n {
+            for i in 0..=n - len {
+                let j = i + len - 1;
+                cost[i][j] = usize::MAX;
+                for k in i..j {
+                    // 含空因子时形状可以很大，乘法次数饱和到 usize::MAX
+                    let c = cost[i][k].saturating_add(cost[k + 1][j]).saturating_add(
+                        dims[i]
+                            .saturating_mul(dims[k + 1])
+                            .saturating_mul(dims[j + 1]),
+                    );
+                    if c < cost[i][j] {
+                        cost[i][j] = c;
+                        split[i][j] = k;
+                    }
+                }
+            }
+        }
+        Plan {
+            cost: cost[0][n - 1],
+            split,
+        }
+    }
+
+    // 最优顺序下的标量乘法次数
+    pub fn cost(&self) -> Result<usize> {
+        self.check()?;
+        Ok(self.plan().cost)
+    }
+
+    pub fn evaluate(&self) -> Result<DynMatrix<T>>
+    where
+        T: Zero + Mul<Output = T> + GemmElement,
+    {
+        self.check()?;
+        let plan = self.plan();
+        let product = self.multiply(&plan.split, 0, self.operands.len() - 1)?;
+        Ok(product.into_owned())
+    }
+
+    fn multiply(&self, split: &[Vec<usize>], i: usize, j: usize) -> Result<Cow<'a, DynMatrix<T>>>
+    where
+        T: Zero + Mul<Output = T> + GemmElement,
+    {
+        if i == j {
+            return Ok(Cow::Borrowed(self.operands[i]));
+        }
+        let k = split[i][j];
+        let left = self.multiply(split, i, k)?;
+        let right = self.multiply(split, k + 1, j)?;
+        Ok(Cow::Owned(left.dot_product(&right)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operand(rows: usize, cols: usize) -> DynMatrix<i64> {
+        DynMatrix::from_fn(rows, cols, |i, j| ((i * 7 + j * 3) % 5) as i64 - 2)
+    }
+
+    #[test]
+    fn test_optimal_order() {
+        // 《算法导论》15.2 节的例子
+        let shapes = [30, 35, 15, 5, 10, 20, 25];
+        let operands: Vec<_> = shapes.windows(2).map(|s| operand(s[0], s[1])).collect();
+        let chain = operands[1..]
+            .iter()
+            .fold(MatrixChain::new(&operands[0]), |chain, m| chain.then(m));
+        assert_eq!(chain.cost(), Ok(15125));
+
+        let expected = operands[1..]
+            .iter()
+            .fold(operands[0].clone(), |acc, m| acc.dot_product(m).unwrap());
+        assert_eq!(chain.evaluate().unwrap(), expected);
+
+        let single = MatrixChain::new(&operands[2]);
+        assert_eq!(single.cost(), Ok(0));
+        assert_eq!(single.evaluate().unwrap(), operands[2]);
+    }
+
+    #[test]
+    fn test_shape_mismatch() {
+        let (a, b) = (operand(2, 3), operand(4, 2));
+        let chain = MatrixChain::new(&a).then(&a).then(&b);
+        let mismatch = MatrixError::DimensionMismatch {
+            expected: 3,
+            got: 2,
+        };
+        assert_eq!(chain.cost(), Err(mismatch.clone()));
+        assert_eq!(chain.evaluate(), Err(mismatch));
+    }
+
+    #[test]
+    fn test_huge_empty_operands() {
+        // 没有元素的因子形状可以任意大，(A·B)·(C·D) 的 2^30 × 2^30 × 2^30 次乘法超出 usize
+        let big = 1 << 30;
+        let (wide, tall) = (operand(0, big), operand(big, 0));
+        let chain = MatrixChain::new(&tall).then(&wide).then(&tall).then(&wide);
+        assert_eq!(chain.cost(), Ok(0));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{MatrixBase, OwnedMatrix, Storage};
+use alloc::vec::Vec;
+
+// 实数的共轭是自身；启用 `complex` 特性后为 num_complex::Complex 取共轭
+pub trait Conjugate {
+    fn conjugate(&self) -> Self;
+}
+
+macro_rules! impl_real_conjugate {
+    ($($ty:ty),*) => {
+        $(
+            impl Conjugate for $ty {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(feature = "complex")]
+impl<T: Clone + core::ops::Neg<Output = T>> Conjugate for num_complex::Complex<T> {
+    fn conjugate(&self) -> Self {
+        num_complex::Complex::new(self.re.clone(), -self.im.clone())
+    }
+}
+
+impl<T: Conjugate, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn conjugate(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        let data: Vec<T> = self.as_slice().iter().map(T::conjugate).collect();
+        MatrixBase::try_from(data).expect("conjugate has exactly R * C elements")
+    }
+
+    // 共轭转置 Aᴴ
+    pub fn hermitian_transpose(&self) -> OwnedMatrix<S::Family, T, C, R> {
+        let data: Vec<T> = (0..C)
+            .flat_map(|j| (0..R).map(move |i| self[i][j].conjugate()))
+            .collect();
+        MatrixBase::try_from(data).expect("transpose has exactly R * C elements")
+    }
+
+    pub fn is_hermitian(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        R == C && (0..R).all(|i| (i..C).all(|j| self[i][j] == self[j][i].conjugate()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_real_hermitian_is_symmetric() {
+        let m = Matrix::from([[1.0, 2.0], [2.0, 3.0]]);
+        assert!(m.is_hermitian());
+        assert_eq!(m.hermitian_transpose(), m.transpose());
+        assert!(!DynMatrics::from([[1, 2], [3, 4]]).is_hermitian());
+        assert!(!Matrix::from([[1, 2, 3]]).is_hermitian());
+    }
+
+    #[cfg(feature = "complex")]
+    #[test]
+    fn test_complex_matrices() {
+        use num_complex::Complex;
+
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let m = Matrix::from([[one, i], [-i, one * 2.0]]);
+        assert!(m.is_hermitian());
+        assert_eq!(m.conjugate(), Matrix::from([[one, -i], [i, one * 2.0]]));
+
+        let a = Matrix::from([[one, i, one + i]]);
+        let gram = a.dot_product(&a.hermitian_transpose());
+        assert_eq!(gram, Matrix::from([[Complex::new(4.0, 0.0)]]));
+        assert_eq!(a.dot_product_in_parallel(&a.hermitian_transpose(), 2), gram);
+        assert_eq!(format!("{}", Matrix::from([[i]])), "[0+1i]");
+    }
+}
@@ -0,0 +1,212 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::dynamic::DynMatrix;
+#[cfg(feature = "std")]
+use crate::parallel::{for_each_chunk, validate};
+use crate::{MatrixBase, Storage};
+
+// 一次二维互相关的参数；图像四周补 padding 圈零，窗口每次移动 stride
+struct Window<'a, T> {
+    image: &'a [T],
+    rows: usize,
+    cols: usize,
+    kernel: &'a [T],
+    kernel_rows: usize,
+    kernel_cols: usize,
+    padding: usize,
+    stride: usize,
+}
+
+impl<T> Window<'_, T>
+where
+    T: Zero + Mul<Output = T> + Clone,
+{
+    // 输出的行数、列数；核比补零后的图像还大时为 0
+    fn output_shape(&self) -> (usize, usize) {
+        let dim = |len: usize, kernel: usize| {
+            (len + 2 * self.padding)
+                .checked_sub(kernel)
+                .map_or(0, |span| span / self.stride + 1)
+        };
+        (
+            dim(self.rows, self.kernel_rows),
+            dim(self.cols, self.kernel_cols),
+        )
+    }
+
+    // 计算输出的第 row 行，越出图像的位置视为零，直接跳过
+    fn fill_row(&self, row: usize, out: &mut [T]) {
+        for (col, target) in out.iter_mut().enumerate() {
+            let mut sum = T::zero();
+            for ki in 0..self.kernel_rows {
+                let i = (row * self.stride + ki).wrapping_sub(self.padding);
+                if i >= self.rows {
+                    continue;
+                }
+                for kj in 0..self.kernel_cols {
+                    let j = (col * self.stride + kj).wrapping_sub(self.padding);
+                    if j < self.cols {
+                        sum = sum
+                            + self.image[i * self.cols + j].clone()
+                                * self.kernel[ki * self.kernel_cols + kj].clone();
+                    }
+                }
+            }
+            *target = sum;
+        }
+    }
+
+    fn run(&self) -> DynMatrix<T> {
+        let (rows, cols) = self.output_shape();
+        let mut data = vec![T::zero(); rows * cols];
+        for (row, out) in data.chunks_mut(cols.max(1)).enumerate() {
+            self.fill_row(row, out);
+        }
+        DynMatrix::new(rows, cols, data).expect("output has rows * cols elements")
+    }
+
+    // 按输出行分块并行
+    #[cfg(feature = "std")]
+    fn run_in_parallel(&self, parallel: usize) -> DynMatrix<T>
+    where
+        T: Send + Sync,
+    {
+        let (rows, cols) = self.output_shape();
+        let mut data = vec![T::zero(); rows * cols];
+        let rows_per_chunk = rows.div_ceil(validate(parallel, rows));
+        for_each_chunk(&mut data, rows_per_chunk * cols, |i, chunk| {
+            for (offset, out) in chunk.chunks_mut(cols).enumerate() {
+                self.fill_row(i * rows_per_chunk + offset, out);
+            }
+        });
+        DynMatrix::new(rows, cols, data).expect("output has rows * cols elements")
+    }
+}
+
+// 把矩阵当作图像/网格做二维卷积，输出尺寸取决于 padding 与 stride，因此返回 DynMatrix
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S>
+where
+    T: Zero + Mul<Output = T> + Clone,
+{
+    fn window<'a>(
+        &'a self,
+        kernel: &'a [T],
+        (kernel_rows, kernel_cols): (usize, usize),
+        padding: usize,
+        stride: usize,
+    ) -> Window<'a, T> {
+        assert!(stride > 0, "stride must be at least 1");
+        Window {
+            image: self.as_slice(),
+            rows: R,
+            cols: C,
+            kernel,
+            kernel_rows,
+            kernel_cols,
+            padding,
+            stride,
+        }
+    }
+
+    // 互相关：核不翻转，即多数图像库里的“卷积”
+    pub fn correlate2d<const KR: usize, const KC: usize, S1: Storage<T>>(
+        &self,
+        kernel: &MatrixBase<T, KR, KC, S1>,
+        padding: usize,
+        stride: usize,
+    ) -> DynMatrix<T> {
+        self.window(kernel.as_slice(), (KR, KC), padding, stride)
+            .run()
+    }
+
+    // 卷积：核旋转 180° 后做互相关
+    pub fn convolve2d<const KR: usize, const KC: usize, S1: Storage<T>>(
+        &self,
+        kernel: &MatrixBase<T, KR, KC, S1>,
+        padding: usize,
+        stride: usize,
+    ) -> DynMatrix<T> {
+        let flipped: Vec<T> = kernel.as_slice().iter().rev().cloned().collect();
+        self.window(&flipped, (KR, KC), padding, stride).run()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn correlate2d_in_parallel<const KR: usize, const KC: usize, S1: Storage<T>>(
+        &self,
+        kernel: &MatrixBase<T, KR, KC, S1>,
+        padding: usize,
+        stride: usize,
+        parallel: usize,
+    ) -> DynMatrix<T>
+    where
+        T: Send + Sync,
+    {
+        self.window(kernel.as_slice(), (KR, KC), padding, stride)
+            .run_in_parallel(parallel)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn convolve2d_in_parallel<const KR: usize, const KC: usize, S1: Storage<T>>(
+        &self,
+        kernel: &MatrixBase<T, KR, KC, S1>,
+        padding: usize,
+        stride: usize,
+        parallel: usize,
+    ) -> DynMatrix<T>
+    where
+        T: Send + Sync,
+    {
+        let flipped: Vec<T> = kernel.as_slice().iter().rev().cloned().collect();
+        self.window(&flipped, (KR, KC), padding, stride)
+            .run_in_parallel(parallel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+
+    #[test]
+    fn test_convolve_and_correlate() {
+        let image = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let kernel = Matrix::from([[1, 0], [0, -1]]);
+        assert_eq!(
+            image.correlate2d(&kernel, 0, 1),
+            DynMatrix::from([[-4, -4], [-4, -4]])
+        );
+        assert_eq!(
+            image.convolve2d(&kernel, 0, 1),
+            DynMatrix::from([[4, 4], [4, 4]])
+        );
+
+        // 补零后 3×3 盒式滤波，stride 2 只取角上与中心
+        let ones = Matrix::filled(1);
+        let blurred: DynMatrix<i32> = image.correlate2d::<3, 3, _>(&ones, 1, 2);
+        assert_eq!(blurred, DynMatrix::from([[12, 16], [24, 28]]));
+        #[cfg(feature = "std")]
+        assert_eq!(image.correlate2d_in_parallel(&ones, 1, 2, 3), blurred);
+
+        // 核比图像大时输出为空
+        let big = Matrix::<i32, 4, 4>::filled(1);
+        assert_eq!(image.convolve2d(&big, 0, 1).shape(), (0, 0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let image = Matrix::<i64, 37, 23>::from_fn(|i, j| (i * 31 + j * 7) as i64 % 11 - 5);
+        let kernel = Matrix::from([[1, 2, 1], [0, 0, 0], [-1, -2, -1]]);
+        let expected = image.convolve2d(&kernel, 1, 1);
+        assert_eq!(expected.shape(), (37, 23));
+        for threads in [1, 2, 5, 64] {
+            assert_eq!(
+                image.convolve2d_in_parallel(&kernel, 1, 1, threads),
+                expected
+            );
+        }
+    }
+}
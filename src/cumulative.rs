@@ -0,0 +1,111 @@
+use core::ops::{Add, Sub};
+
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// 方向约定与 numpy 的 axis 相同：*_rows 沿行号方向（逐行向下），*_cols 沿列号方向（逐列向右）
+// 都在行优先的数据上原地进行，逐行处理时内层循环是连续内存
+
+// 第 i 行加上第 i - 1 行的结果
+fn cumsum_down<T: Add<Output = T> + Clone>(data: &mut [T], cols: usize) {
+    for i in 1..data.len() / cols.max(1) {
+        let (done, rest) = data.split_at_mut(i * cols);
+        for (x, prev) in rest[..cols].iter_mut().zip(&done[(i - 1) * cols..]) {
+            *x = prev.clone() + x.clone();
+        }
+    }
+}
+
+fn cumsum_right<T: Add<Output = T> + Clone>(data: &mut [T], cols: usize) {
+    for row in data.chunks_mut(cols.max(1)) {
+        for j in 1..row.len() {
+            row[j] = row[j - 1].clone() + row[j].clone();
+        }
+    }
+}
+
+// 从最后一行往前减，避免覆盖尚未使用的上一行
+fn diff_down<T: Sub<Output = T> + Clone>(data: &mut [T], cols: usize) {
+    for i in (1..data.len() / cols.max(1)).rev() {
+        let (done, rest) = data.split_at_mut(i * cols);
+        for (x, prev) in rest[..cols].iter_mut().zip(&done[(i - 1) * cols..]) {
+            *x = x.clone() - prev.clone();
+        }
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    // result[i][j] = Σ_{k ≤ i} self[k][j]
+    pub fn cumsum_rows(&self) -> OwnedMatrix<S::Family, T, R, C>
+    where
+        T: Add<Output = T>,
+    {
+        let mut result = self.to_owned_matrix();
+        cumsum_down(result.as_mut_slice(), C);
+        result
+    }
+
+    // result[i][j] = Σ_{k ≤ j} self[i][k]
+    pub fn cumsum_cols(&self) -> OwnedMatrix<S::Family, T, R, C>
+    where
+        T: Add<Output = T>,
+    {
+        let mut result = self.to_owned_matrix();
+        cumsum_right(result.as_mut_slice(), C);
+        result
+    }
+
+    // 相邻行之差，第 0 行保持不变，因此是 cumsum_rows 的逆运算
+    pub fn diff_rows(&self) -> OwnedMatrix<S::Family, T, R, C>
+    where
+        T: Sub<Output = T>,
+    {
+        let mut result = self.to_owned_matrix();
+        diff_down(result.as_mut_slice(), C);
+        result
+    }
+
+    // 积分图：result[i][j] 为左上角 (0, 0) 到 (i, j) 矩形内的元素和，
+    // 即 cumsum_rows().cumsum_cols()，只复制一次
+    pub fn summed_area_table(&self) -> OwnedMatrix<S::Family, T, R, C>
+    where
+        T: Add<Output = T>,
+    {
+        let mut result = self.to_owned_matrix();
+        cumsum_right(result.as_mut_slice(), C);
+        cumsum_down(result.as_mut_slice(), C);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_cumsum_and_diff() {
+        let a = Matrix::<i32, 3, 3>::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(
+            a.cumsum_rows(),
+            Matrix::from([[1, 2, 3], [5, 7, 9], [12, 15, 18]])
+        );
+        assert_eq!(
+            a.cumsum_cols(),
+            Matrix::from([[1, 3, 6], [4, 9, 15], [7, 15, 24]])
+        );
+        assert_eq!(a.cumsum_rows().diff_rows(), a);
+        assert_eq!(
+            a.diff_rows(),
+            Matrix::from([[1, 2, 3], [3, 3, 3], [3, 3, 3]])
+        );
+
+        let table = a.summed_area_table();
+        assert_eq!(table, a.cumsum_rows().cumsum_cols());
+        // 矩形 [1, 2] × [1, 2] 的和：5 + 6 + 8 + 9
+        assert_eq!(table[2][2] - table[0][2] - table[2][0] + table[0][0], 28);
+
+        let d = DynMatrics::<f64, 2, 1>::from([[0.5], [0.25]]);
+        assert_eq!(d.cumsum_rows(), DynMatrics::from([[0.5], [0.75]]));
+        assert_eq!(d.cumsum_cols(), d);
+    }
+}
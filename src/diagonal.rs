@@ -0,0 +1,149 @@
+use core::ops::{Div, Mul};
+
+use num_traits::Zero;
+
+use crate::{Matrix, MatrixError, Result};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DiagonalMatrix<T, const N: usize> {
+    diagonal: [T; N],
+}
+
+impl<T, const N: usize> From<[T; N]> for DiagonalMatrix<T, N> {
+    fn from(diagonal: [T; N]) -> Self {
+        DiagonalMatrix { diagonal }
+    }
+}
+
+impl<T: Clone, const N: usize> From<&Matrix<T, N, N>> for DiagonalMatrix<T, N> {
+    fn from(matrix: &Matrix<T, N, N>) -> Self {
+        DiagonalMatrix {
+            diagonal: core::array::from_fn(|i| matrix[i][i].clone()),
+        }
+    }
+}
+
+impl<T, const N: usize> DiagonalMatrix<T, N> {
+    pub fn diagonal(&self) -> &[T; N] {
+        &self.diagonal
+    }
+
+    pub fn diagonal_mut(&mut self) -> &mut [T; N] {
+        &mut self.diagonal
+    }
+
+    pub fn to_dense(&self) -> Matrix<T, N, N>
+    where
+        T: Zero + Clone,
+    {
+        let mut result = Matrix::zero();
+        for (i, value) in self.diagonal.iter().enumerate() {
+            result[i][i] = value.clone();
+        }
+        result
+    }
+
+    // D·M：按行缩放，O(N·Z)
+    pub fn dot_product<const Z: usize>(&self, matrix1: &Matrix<T, N, Z>) -> Matrix<T, N, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let mut result = matrix1.clone();
+        for (i, scale) in self.diagonal.iter().enumerate() {
+            for value in result[i].iter_mut() {
+                *value = scale.clone() * value.clone();
+            }
+        }
+        result
+    }
+
+    // 求解 D·X = B
+    pub fn solve<const Z: usize>(&self, b: &Matrix<T, N, Z>) -> Result<Matrix<T, N, Z>>
+    where
+        T: Zero + PartialEq + Div<Output = T> + Clone,
+    {
+        if self.diagonal.contains(&T::zero()) {
+            return Err(MatrixError::Singular);
+        }
+        let mut result = b.clone();
+        for (i, pivot) in self.diagonal.iter().enumerate() {
+            for value in result[i].iter_mut() {
+                *value = value.clone() / pivot.clone();
+            }
+        }
+        Ok(result)
+    }
+
+    pub fn dot_product_diagonal(&self, matrix1: &DiagonalMatrix<T, N>) -> DiagonalMatrix<T, N>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        DiagonalMatrix {
+            diagonal: core::array::from_fn(|i| {
+                self.diagonal[i].clone() * matrix1.diagonal[i].clone()
+            }),
+        }
+    }
+
+    pub fn trace(&self) -> T
+    where
+        T: Zero + Clone,
+    {
+        self.diagonal
+            .iter()
+            .fold(T::zero(), |sum, value| sum + value.clone())
+    }
+}
+
+impl<T, const X: usize, const Y: usize> Matrix<T, X, Y> {
+    // M·D：按列缩放，O(X·Y)
+    pub fn dot_product_diagonal(&self, matrix1: &DiagonalMatrix<T, Y>) -> Matrix<T, X, Y>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        let mut result = self.clone();
+        for i in 0..X {
+            for (value, scale) in result[i].iter_mut().zip(matrix1.diagonal()) {
+                *value = value.clone() * scale.clone();
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaling_matches_dense() {
+        let d = DiagonalMatrix::from([2, 3]);
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(d.dot_product(&m), d.to_dense().dot_product(&m));
+
+        let m = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(m.dot_product_diagonal(&d), m.dot_product(&d.to_dense()));
+    }
+
+    #[test]
+    fn test_solve() {
+        let d = DiagonalMatrix::from([2.0, 4.0]);
+        let b = Matrix::from([[1.0], [2.0]]);
+        let x = d.solve(&b).unwrap();
+        assert_eq!(x, Matrix::from([[0.5], [0.5]]));
+        assert_eq!(d.dot_product(&x), b);
+        assert_eq!(
+            DiagonalMatrix::from([1.0, 0.0]).solve(&b).unwrap_err(),
+            MatrixError::Singular
+        );
+    }
+
+    #[test]
+    fn test_extract_and_trace() {
+        let m = Matrix::from([[1, 9], [9, 5]]);
+        let d = DiagonalMatrix::from(&m);
+        assert_eq!(d.diagonal(), &[1, 5]);
+        assert_eq!(d.trace(), 6);
+        assert_eq!(d.dot_product_diagonal(&d).diagonal(), &[1, 25]);
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display, Formatter};
+
+use crate::dynamic::DynMatrix;
+use crate::{MatrixBase, Storage};
+
+// 超过该行数/列数时省略中间部分，只显示首尾各 EDGE_ITEMS 个
+const ELIDE_THRESHOLD: usize = 10;
+const EDGE_ITEMS: usize = 3;
+const ELLIPSIS: &str = "...";
+
+// 返回需要显示的索引，None 表示省略号
+fn visible_indices(len: usize, elide: bool) -> Vec<Option<usize>> {
+    if elide && len > ELIDE_THRESHOLD {
+        (0..EDGE_ITEMS)
+            .map(Some)
+            .chain(core::iter::once(None))
+            .chain((len - EDGE_ITEMS..len).map(Some))
+            .collect()
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+// `{:.N}` 控制浮点精度，`{:#}` 关闭省略
+pub(crate) fn fmt_grid<'a, T, F>(
+    f: &mut Formatter<'_>,
+    rows: usize,
+    cols: usize,
+    cell: F,
+) -> fmt::Result
+where
+    T: Display + 'a,
+    F: Fn(usize, usize) -> &'a T,
+{
+    let elide = !f.alternate();
+    let row_indices = visible_indices(rows, elide);
+    let col_indices = visible_indices(cols, elide);
+
+    let cells: Vec<Vec<String>> = row_indices
+        .iter()
+        .map(|row| {
+            col_indices
+                .iter()
+                .map(|col| match (row, col) {
+                    (Some(i), Some(j)) => match f.precision() {
+                        Some(precision) => format!("{:.*}", precision, cell(*i, *j)),
+                        None => cell(*i, *j).to_string(),
+                    },
+                    _ => ELLIPSIS.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..col_indices.len())
+        .map(|j| {
+            cells
+                .iter()
+                .map(|row| row[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for (i, row) in cells.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "[")?;
+        for (j, text) in row.iter().enumerate() {
+            if j > 0 {
+                write!(f, "  ")?;
+            }
+            write!(f, "{:>width$}", text, width = widths[j])?;
+        }
+        write!(f, "]")?;
+    }
+    Ok(())
+}
+
+impl<T: Display, const R: usize, const C: usize, S: Storage<T>> Display for MatrixBase<T, R, C, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_grid(f, R, C, |i, j| &self[i][j])
+    }
+}
+
+impl<T: Display> Display for DynMatrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_grid(f, self.rows(), self.cols(), |i, j| &self[i][j])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::dynamic::{DynMatrics, DynMatrix};
+    use crate::Matrix;
+
+    #[test]
+    fn test_display_aligned() {
+        let m = Matrix::from([[1, 200], [30, 4]]);
+        assert_eq!(m.to_string(), "[ 1  200]\n[30    4]");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let m = DynMatrics::from([[1.0, 2.5], [-3.25, 4.0]]);
+        assert_eq!(format!("{:.1}", m), "[ 1.0  2.5]\n[-3.2  4.0]");
+    }
+
+    #[test]
+    fn test_display_elision() {
+        let m = DynMatrics::<u8, 1, 12>::default();
+        assert_eq!(m.to_string(), "[0  0  0  ...  0  0  0]");
+        assert_eq!(format!("{:#}", m).matches('0').count(), 12);
+    }
+
+    #[test]
+    fn test_display_runtime_shape() {
+        let m = DynMatrix::from([[1, 200], [30, 4]]);
+        assert_eq!(m.to_string(), "[ 1  200]\n[30    4]");
+    }
+}
@@ -1,115 +1,374 @@
-use std::ops::{Add, Index, IndexMut, Mul};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
-pub struct DynMatrics<T, const R: usize, const C: usize> {
+use num_traits::Zero;
+
+#[cfg(feature = "std")]
+use crate::parallel;
+use crate::{gemm, GemmElement, MatrixBase, MatrixError, OwnedStorage, Result};
+
+// 元素放在 Vec 中的定长矩阵，运算结果仍是 DynMatrics
+pub type DynMatrics<T, const R: usize, const C: usize> = MatrixBase<T, R, C, Vec<T>>;
+
+// 行列数是运行时字段的矩阵，尺寸只能从文件或输入得知时使用
+// 与 DynMatrics 相同按行优先存放在 Vec 中，形状不匹配时返回 MatrixError 而不是编译错误
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct DynMatrix<T> {
+    rows: usize,
+    cols: usize,
     data: Vec<T>,
 }
-impl<T, const R: usize, const C: usize> Index<usize> for DynMatrics<T, R, C> {
+
+impl<T> Index<usize> for DynMatrix<T> {
     type Output = [T];
 
     fn index(&self, index: usize) -> &Self::Output {
-        let start = index * C;
-        let end = start + C;
-        &self.data[start..end]
+        assert!(index < self.rows, "row {} out of bounds", index);
+        &self.data[index * self.cols..(index + 1) * self.cols]
     }
 }
 
-impl<T, const R: usize, const C: usize> IndexMut<usize> for DynMatrics<T, R, C> {
+impl<T> IndexMut<usize> for DynMatrix<T> {
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        let start = index * C;
-        let end = start + C;
-        &mut self.data[start..end]
+        assert!(index < self.rows, "row {} out of bounds", index);
+        &mut self.data[index * self.cols..(index + 1) * self.cols]
     }
 }
 
-impl<T, const X: usize, const Y: usize> Default for DynMatrics<T, X, Y>
-where
-    T: Default + Copy,
-{
-    fn default() -> Self {
-        DynMatrics {
-            data: vec![T::default(); X * Y],
+// 运行时尺寸的元素个数；乘积溢出时与 Vec 的容量溢出一样 panic
+fn element_count(rows: usize, cols: usize) -> usize {
+    rows.checked_mul(cols)
+        .unwrap_or_else(|| panic!("{}x{} matrix is too large", rows, cols))
+}
+
+impl<T> DynMatrix<T> {
+    // 尺寸可能来自不可信的输入（如反序列化），乘积溢出时按 usize::MAX 报告
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Result<Self> {
+        match rows.checked_mul(cols) {
+            Some(len) if len == data.len() => Ok(DynMatrix { rows, cols, data }),
+            len => Err(MatrixError::DimensionMismatch {
+                expected: len.unwrap_or(usize::MAX),
+                got: data.len(),
+            }),
         }
     }
-}
 
-impl<T, const X: usize, const Y: usize> DynMatrics<T, X, Y> {
-    pub fn dot_product<const Z: usize>(&self, matrix1: &DynMatrics<T, Y, Z>) -> DynMatrics<T, X, Z>
+    pub fn zeros(rows: usize, cols: usize) -> Self
     where
-        T: Default + Add<Output = T> + Mul<Output = T> + Copy,
+        T: Zero,
     {
-        let mut result = DynMatrics::<T, X, Z>::default();
-        for i in 0..X {
-            for j in 0..Z {
-                let mut sum = T::default();
-                for k in 0..Y {
-                    sum = sum + self.data[i * Y + k] * matrix1.data[k * Z + j];
-                }
-                result.data[i * Z + j] = sum;
-            }
+        Self::from_fn(rows, cols, |_, _| T::zero())
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        DynMatrix {
+            rows,
+            cols,
+            data: (0..element_count(rows, cols))
+                .map(|index| f(index / cols, index % cols))
+                .collect(),
+        }
+    }
+
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        DynMatrix {
+            rows,
+            cols,
+            data: vec![value; element_count(rows, cols)],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // (行数, 列数)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn transpose(&self) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        DynMatrix::from_fn(self.cols, self.rows, |i, j| {
+            self.data[j * self.cols + i].clone()
+        })
+    }
+
+    fn check_product(&self, matrix1: &DynMatrix<T>) -> Result<()> {
+        if self.cols != matrix1.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.cols,
+                got: matrix1.rows,
+            });
         }
-        result
+        Ok(())
     }
 
-    pub fn dot_product_in_parallel<const Z: usize>(
+    // self 的列数必须等于 matrix1 的行数
+    pub fn dot_product(&self, matrix1: &DynMatrix<T>) -> Result<DynMatrix<T>>
+    where
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        self.check_product(matrix1)?;
+        let (m, k, n) = (self.rows, self.cols, matrix1.cols);
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_dispatch 会写满全部 m * n 个元素
+        let data = unsafe { gemm::uninit_vec(m * n, |c| gemm::gemm_dispatch(a, b, m, k, n, c)) };
+        DynMatrix::new(m, n, data)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn dot_product_in_parallel(
         &self,
-        matrix1: &DynMatrics<T, Y, Z>,
+        matrix1: &DynMatrix<T>,
         parallel: usize,
-    ) -> DynMatrics<T, X, Z>
+    ) -> Result<DynMatrix<T>>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        self.check_product(matrix1)?;
+        let (m, k, n) = (self.rows, self.cols, matrix1.cols);
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: parallel::gemm 会写满全部 m * n 个元素
+        let data =
+            unsafe { gemm::uninit_vec(m * n, |c| parallel::gemm(a, b, m, k, n, c, parallel)) };
+        DynMatrix::new(m, n, data)
+    }
+
+    fn zip_with(&self, other: &DynMatrix<T>, f: impl Fn(&T, &T) -> T) -> Result<DynMatrix<T>> {
+        if self.shape() != other.shape() {
+            let (expected, got) = if self.rows != other.rows {
+                (self.rows, other.rows)
+            } else {
+                (self.cols, other.cols)
+            };
+            return Err(MatrixError::DimensionMismatch { expected, got });
+        }
+        let data = self.data.iter().zip(&other.data).map(|(a, b)| f(a, b));
+        DynMatrix::new(self.rows, self.cols, data.collect())
+    }
+
+    // NumPy 风格的广播：每一维要么相等，要么其中一方为 1
+    pub fn broadcast_with<U>(
+        &self,
+        other: &DynMatrix<U>,
+        f: impl Fn(&T, &U) -> T,
+    ) -> Result<DynMatrix<T>> {
+        let dim = |a: usize, b: usize| match (a, b) {
+            _ if a == b => Ok(a),
+            (1, _) => Ok(b),
+            (_, 1) => Ok(a),
+            _ => Err(MatrixError::DimensionMismatch {
+                expected: a,
+                got: b,
+            }),
+        };
+        let (rows, cols) = (dim(self.rows, other.rows)?, dim(self.cols, other.cols)?);
+        // 长度为 1 的维度下标固定取 0
+        let at =
+            |m_rows: usize, m_cols: usize, i: usize, j: usize| (i % m_rows) * m_cols + j % m_cols;
+        Ok(DynMatrix::from_fn(rows, cols, |i, j| {
+            f(
+                &self.data[at(self.rows, self.cols, i, j)],
+                &other.data[at(other.rows, other.cols, i, j)],
+            )
+        }))
+    }
+
+    pub fn broadcast_add(&self, other: &DynMatrix<T>) -> Result<DynMatrix<T>>
     where
-        T: Default + Add<Output = T> + Mul<Output = T> + Copy + Send + Sync,
+        T: Add<Output = T> + Clone,
     {
-        let mut result = DynMatrics::<T, X, Z>::default();
-        let matrix0 = &self.data;
-        let matrix1_data = &matrix1.data;
-
-        std::thread::scope(|scope| {
-            let chunk_size = (X + parallel - 1) / parallel; // 计算每个线程应处理的行数
-
-            let mut start_index = 0;
-            let mut result_slices = &mut result.data[..];
-
-            for _ in 0..parallel {
-                if start_index >= X {
-                    break;
-                }
-                let chunk_end = std::cmp::min(start_index + chunk_size, X);
-                let (local_result, rest) =
-                    result_slices.split_at_mut((chunk_end - start_index) * Z);
-                result_slices = rest;
-
-                let local_matrix0_start = start_index * Y;
-                let local_matrix0_end = chunk_end * Y;
-                let local_matrix0 = &matrix0[local_matrix0_start..local_matrix0_end];
-
-                scope.spawn(move || {
-                    for i in 0..(chunk_end - start_index) {
-                        for j in 0..Z {
-                            let mut sum = T::default();
-                            for k in 0..Y {
-                                sum = sum + local_matrix0[i * Y + k] * matrix1_data[k * Z + j];
-                            }
-                            local_result[i * Z + j] = sum;
-                        }
-                    }
-                });
-
-                start_index = chunk_end;
+        self.broadcast_with(other, |a, b| a.clone() + b.clone())
+    }
+
+    pub fn broadcast_sub(&self, other: &DynMatrix<T>) -> Result<DynMatrix<T>>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        self.broadcast_with(other, |a, b| a.clone() - b.clone())
+    }
+
+    pub fn broadcast_mul(&self, other: &DynMatrix<T>) -> Result<DynMatrix<T>>
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        self.broadcast_with(other, |a, b| a.clone() * b.clone())
+    }
+
+    pub fn add(&self, other: &DynMatrix<T>) -> Result<DynMatrix<T>>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        self.zip_with(other, |a, b| a.clone() + b.clone())
+    }
+
+    pub fn sub(&self, other: &DynMatrix<T>) -> Result<DynMatrix<T>>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        self.zip_with(other, |a, b| a.clone() - b.clone())
+    }
+
+    // 在末尾追加一行；没有行时由这一行决定列数
+    pub fn push_row(&mut self, row: &[T]) -> Result<()>
+    where
+        T: Clone,
+    {
+        if self.rows == 0 {
+            self.cols = row.len();
+        }
+        if row.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.cols,
+                got: row.len(),
+            });
+        }
+        self.data.extend_from_slice(row);
+        self.rows += 1;
+        Ok(())
+    }
+
+    // 删除第 index 行并返回它
+    pub fn remove_row(&mut self, index: usize) -> Vec<T> {
+        assert!(index < self.rows, "row {} out of bounds", index);
+        let start = index * self.cols;
+        let row = self.data.drain(start..start + self.cols).collect();
+        self.rows -= 1;
+        row
+    }
+
+    // 在第 index 列之前插入一列，index 等于列数时追加在最后；没有列时由这一列决定行数
+    pub fn insert_col(&mut self, index: usize, col: &[T]) -> Result<()>
+    where
+        T: Clone,
+    {
+        assert!(index <= self.cols, "column {} out of bounds", index);
+        if self.cols == 0 {
+            self.rows = col.len();
+        }
+        if col.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.rows,
+                got: col.len(),
+            });
+        }
+        let cols = self.cols + 1;
+        let mut old = core::mem::take(&mut self.data).into_iter();
+        self.data = Vec::with_capacity(self.rows * cols);
+        for value in col {
+            self.data.extend(old.by_ref().take(index));
+            self.data.push(value.clone());
+            self.data.extend(old.by_ref().take(self.cols - index));
+        }
+        self.cols = cols;
+        Ok(())
+    }
+
+    // 保留左上角的元素，新增的位置填 fill
+    pub fn resize(&mut self, rows: usize, cols: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let len = element_count(rows, cols);
+        if cols == self.cols {
+            self.data.resize(len, fill);
+        } else {
+            let mut old = core::mem::take(&mut self.data).into_iter();
+            let kept = cols.min(self.cols);
+            self.data = Vec::with_capacity(len);
+            for _ in 0..rows.min(self.rows) {
+                self.data.extend(old.by_ref().take(kept));
+                old.by_ref().take(self.cols - kept).for_each(drop);
+                self.data
+                    .resize(self.data.len() + cols - kept, fill.clone());
             }
-        });
+            self.data.resize(len, fill);
+        }
+        self.rows = rows;
+        self.cols = cols;
+    }
+}
 
-        result
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> From<MatrixBase<T, R, C, S>>
+    for DynMatrix<T>
+{
+    fn from(matrix: MatrixBase<T, R, C, S>) -> Self {
+        DynMatrix {
+            rows: R,
+            cols: C,
+            data: matrix.into_vec(),
+        }
     }
 }
 
-impl<T, const X: usize, const Y: usize> TryFrom<Vec<T>> for DynMatrics<T, X, Y> {
-    type Error = ();
+// 行数不符时报告行数，否则报告列数
+fn check_shape<T>(matrix: &DynMatrix<T>, rows: usize, cols: usize) -> Result<()> {
+    if matrix.rows != rows {
+        return Err(MatrixError::DimensionMismatch {
+            expected: rows,
+            got: matrix.rows,
+        });
+    }
+    if matrix.cols != cols {
+        return Err(MatrixError::DimensionMismatch {
+            expected: cols,
+            got: matrix.cols,
+        });
+    }
+    Ok(())
+}
 
-    fn try_from(data: Vec<T>) -> Result<Self, ()> {
-        if data.len() == X * Y {
-            Ok(Self { data })
-        } else {
-            return Err(());
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> TryFrom<DynMatrix<T>>
+    for MatrixBase<T, R, C, S>
+{
+    type Error = MatrixError;
+
+    fn try_from(matrix: DynMatrix<T>) -> Result<Self> {
+        check_shape(&matrix, R, C)?;
+        MatrixBase::try_from(matrix.data)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for DynMatrix<T> {
+    fn from(data: [[T; C]; R]) -> Self {
+        DynMatrix {
+            rows: R,
+            cols: C,
+            data: data.into_iter().flatten().collect(),
         }
     }
 }
@@ -117,6 +376,7 @@ impl<T, const X: usize, const Y: usize> TryFrom<Vec<T>> for DynMatrics<T, X, Y>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Matrix;
 
     #[test]
     fn test_dot_product_2x2() {
@@ -136,6 +396,61 @@ mod tests {
         assert_eq!(result.data, expected.data);
     }
 
+    #[test]
+    fn test_try_from_wrong_length() {
+        let err = DynMatrics::<i32, 2, 2>::try_from(vec![1, 2, 3]).unwrap_err();
+        assert_eq!(
+            err,
+            MatrixError::DimensionMismatch {
+                expected: 4,
+                got: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_dot_product_into() {
+        let a = DynMatrics::from([[1, 2], [3, 4]]);
+        let b = DynMatrics::from([[2, 0], [1, 2]]);
+        let mut out = DynMatrics::from([[9, 9], [9, 9]]);
+        a.dot_product_into(&b, &mut out);
+        assert_eq!(out, a.dot_product(&b));
+        #[cfg(feature = "std")]
+        {
+            a.dot_product_into_in_parallel(&a, &mut out, 2);
+            assert_eq!(out, a.dot_product(&a));
+        }
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let m = DynMatrics::<_, 2, 3>::from_fn(|i, j| i * 10 + j);
+        assert_eq!(m, DynMatrics::from([[0, 1, 2], [10, 11, 12]]));
+        assert_eq!(
+            DynMatrics::<_, 1, 2>::filled(0.5),
+            DynMatrics::from([[0.5, 0.5]])
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = DynMatrics::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.transpose(), DynMatrics::from([[1, 4], [2, 5], [3, 6]]));
+    }
+
+    #[test]
+    fn test_non_copy_elements() {
+        use num_bigint::BigInt;
+
+        let a = DynMatrics::from([[BigInt::from(i64::MAX), BigInt::from(i64::MAX)]]);
+        let b = a.transpose();
+        let expected = BigInt::from(i64::MAX) * i64::MAX * 2;
+        assert_eq!(a.dot_product(&b)[0][0], expected);
+        #[cfg(feature = "std")]
+        assert_eq!(a.dot_product_in_parallel(&b, 4)[0][0], expected);
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_dot_product_2x2_par() {
         let cpus = num_cpus::get();
@@ -146,6 +461,7 @@ mod tests {
         assert_eq!(result.data, expected.data);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_dot_product_2x3_and_3x2_par() {
         let cpus = num_cpus::get();
@@ -155,4 +471,122 @@ mod tests {
         let expected = DynMatrics::<_, 2, 2>::try_from(vec![58, 64, 139, 154]).unwrap();
         assert_eq!(result.data, expected.data);
     }
+
+    #[test]
+    fn test_runtime_dimensions() {
+        let a = DynMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let b = DynMatrix::from([[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(a.shape(), (2, 3));
+        assert_eq!(a[1], [4, 5, 6]);
+        assert_eq!(a.get(1, 2), Some(&6));
+        assert_eq!(a.get(2, 0), None);
+
+        let product = a.dot_product(&b).unwrap();
+        assert_eq!(product, DynMatrix::from([[4, 5], [10, 11]]));
+        #[cfg(feature = "std")]
+        assert_eq!(a.dot_product_in_parallel(&b, 4).unwrap(), product);
+        assert_eq!(
+            a.dot_product(&a),
+            Err(MatrixError::DimensionMismatch {
+                expected: 3,
+                got: 2
+            })
+        );
+        assert_eq!(a.transpose(), DynMatrix::from([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(
+            a.add(&a).unwrap(),
+            DynMatrix::from([[2, 4, 6], [8, 10, 12]])
+        );
+        assert!(a.sub(&b).is_err());
+        assert!(DynMatrix::new(2, 2, vec![1, 2, 3]).is_err());
+        // 1 << 32 的平方在 64 位上回绕为 0，不能被当作合法的空矩阵
+        let huge = 1 << (usize::BITS / 2);
+        assert_eq!(
+            DynMatrix::<i32>::new(huge, huge, vec![]),
+            Err(MatrixError::DimensionMismatch {
+                expected: usize::MAX,
+                got: 0
+            })
+        );
+
+        // 空矩阵与 k == 0 的乘积
+        let empty = DynMatrix::<f64>::zeros(3, 0);
+        assert_eq!(
+            empty.dot_product(&DynMatrix::zeros(0, 2)).unwrap(),
+            DynMatrix::zeros(3, 2)
+        );
+    }
+
+    #[test]
+    fn test_runtime_broadcasting() {
+        let a = DynMatrix::from([[1, 2, 3], [4, 5, 6]]);
+        let row = DynMatrix::from([[10, 20, 30]]);
+        let col = DynMatrix::from([[1], [2]]);
+        assert_eq!(
+            a.broadcast_add(&row).unwrap(),
+            DynMatrix::from([[11, 22, 33], [14, 25, 36]])
+        );
+        assert_eq!(
+            a.broadcast_mul(&col).unwrap(),
+            DynMatrix::from([[1, 2, 3], [8, 10, 12]])
+        );
+        assert_eq!(
+            col.broadcast_sub(&row).unwrap(),
+            DynMatrix::from([[-9, -19, -29], [-8, -18, -28]])
+        );
+        assert_eq!(
+            a.broadcast_mul(&DynMatrix::filled(1, 1, 2)).unwrap(),
+            a.add(&a).unwrap()
+        );
+        assert_eq!(
+            a.broadcast_add(&DynMatrix::from([[1, 2]])),
+            Err(MatrixError::DimensionMismatch {
+                expected: 3,
+                got: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_runtime_conversions() {
+        let m = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let dynamic = DynMatrix::from(m.clone());
+        assert_eq!(dynamic.shape(), (3, 2));
+        assert_eq!(Matrix::<f64, 3, 2>::try_from(dynamic.clone()).unwrap(), m);
+        assert_eq!(
+            DynMatrics::<f64, 2, 3>::try_from(dynamic.clone()),
+            Err(MatrixError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        );
+        let d = DynMatrics::<f64, 3, 2>::try_from(dynamic).unwrap();
+        assert_eq!(DynMatrix::from(d).as_slice(), m.as_slice());
+    }
+
+    #[test]
+    fn test_runtime_reshaping() {
+        let mut m = DynMatrix::<i32>::zeros(0, 0);
+        m.push_row(&[1, 2]).unwrap();
+        m.push_row(&[3, 4]).unwrap();
+        assert_eq!(
+            m.push_row(&[5]),
+            Err(MatrixError::DimensionMismatch {
+                expected: 2,
+                got: 1
+            })
+        );
+        m.insert_col(1, &[7, 8]).unwrap();
+        assert_eq!((m.shape(), m.as_slice()), ((2, 3), &[1, 7, 2, 3, 8, 4][..]));
+        m.insert_col(3, &[0, 0]).unwrap();
+        assert_eq!(m.remove_row(0), [1, 7, 2, 0]);
+        assert_eq!((m.shape(), m.as_slice()), ((1, 4), &[3, 8, 4, 0][..]));
+
+        m.resize(3, 2, 9);
+        assert_eq!(m.as_slice(), [3, 8, 9, 9, 9, 9]);
+        m.resize(2, 3, -1);
+        assert_eq!(m.as_slice(), [3, 8, -1, 9, 9, -1]);
+        m.resize(1, 3, 0);
+        assert_eq!(m.as_slice(), [3, 8, -1]);
+    }
 }
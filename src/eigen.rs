@@ -0,0 +1,134 @@
+use num_traits::Float;
+
+use crate::{GemmElement, MatrixBase, OwnedMatrix, Storage};
+
+// PageRank 迭代的上限；damping < 1 时每轮误差至少缩小为原来的 damping 倍，远用不到这么多轮
+const PAGERANK_MAX_ITERATIONS: usize = 1000;
+
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S>
+where
+    T: Float + GemmElement,
+{
+    // 幂迭代求模最大的特征值及其单位特征向量
+    // 每步 x ← A·x / ‖A·x‖，特征值取 Rayleigh 商 xᵀ·A·x；
+    // 为避免负特征值导致符号来回翻转，令绝对值最大的分量为正
+    // 相邻两次的向量之差的 2-范数小于 tolerance 时返回，否则在 max_iterations 次后返回 None
+    pub fn dominant_eigenpair(
+        &self,
+        max_iterations: usize,
+        tolerance: T,
+    ) -> Option<(T, OwnedMatrix<S::Family, T, N, 1>)> {
+        let start = T::one() / T::from(N).expect("dimension fits in a float").sqrt();
+        let mut x = OwnedMatrix::<S::Family, T, N, 1>::filled(start);
+        for _ in 0..max_iterations {
+            let y = self.dot_product(&x);
+            let eigenvalue = (0..N).fold(T::zero(), |sum, i| sum + x[i][0] * y[i][0]);
+            let norm = (0..N)
+                .fold(T::zero(), |sum, i| sum + y[i][0] * y[i][0])
+                .sqrt();
+            if norm == T::zero() {
+                // A·x = 0，x 本身就是特征值 0 的特征向量
+                return Some((T::zero(), x));
+            }
+            let pivot =
+                (0..N).map(|i| y[i][0]).fold(
+                    T::zero(),
+                    |p, v| {
+                        if v.abs() > p.abs() {
+                            v
+                        } else {
+                            p
+                        }
+                    },
+                );
+            let scale = norm * pivot.signum();
+            let next = OwnedMatrix::<S::Family, T, N, 1>::from_fn(|i, _| y[i][0] / scale);
+            let delta = (0..N)
+                .fold(T::zero(), |sum, i| sum + (next[i][0] - x[i][0]).powi(2))
+                .sqrt();
+            x = next;
+            if delta < tolerance {
+                return Some((eigenvalue, x));
+            }
+        }
+        None
+    }
+
+    // 把 self 视为邻接矩阵（self[i][j] 为 i 指向 j 的链接权重），返回各节点的 PageRank 行向量
+    // r ← (1 - damping) / N + damping · r·P，P 为按行归一化后的转移矩阵；
+    // 没有出链的节点视为均匀地链接到所有节点
+    pub fn pagerank(&self, damping: T) -> OwnedMatrix<S::Family, T, 1, N> {
+        assert!(
+            damping >= T::zero() && damping <= T::one(),
+            "damping must be in [0, 1]"
+        );
+        let n = T::from(N).expect("dimension fits in a float");
+        let transition = self.normalize_rows_to_one();
+        let dangling: [bool; N] =
+            core::array::from_fn(|i| transition[i].iter().all(|&p| p == T::zero()));
+        let teleport = (T::one() - damping) / n;
+        let mut rank = OwnedMatrix::<S::Family, T, 1, N>::filled(T::one() / n);
+        for _ in 0..PAGERANK_MAX_ITERATIONS {
+            // 悬挂节点的得分均匀分给所有节点
+            let leaked = (0..N)
+                .filter(|&i| dangling[i])
+                .fold(T::zero(), |sum, i| sum + rank[0][i]);
+            let flow = rank.dot_product(&transition);
+            let next = OwnedMatrix::<S::Family, T, 1, N>::from_fn(|_, j| {
+                teleport + damping * (flow[0][j] + leaked / n)
+            });
+            let delta = (0..N).fold(T::zero(), |d, j| d + (next[0][j] - rank[0][j]).abs());
+            rank = next;
+            if delta <= T::epsilon() * n {
+                break;
+            }
+        }
+        rank
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_dominant_eigenpair() {
+        // 特征值为 5 和 2，5 对应的特征向量为 (1, 1)/√2
+        let a = Matrix::from([[4.0, 1.0], [2.0, 3.0]]);
+        let (value, vector) = a.dominant_eigenpair(1000, 1e-12).unwrap();
+        assert!((value - 5.0).abs() < 1e-9);
+        let half = 0.5f64.sqrt();
+        assert!(vector.approx_eq(&Matrix::from([[half], [half]]), 1e-9));
+
+        // 模最大的特征值为负时符号不来回翻转
+        let b = DynMatrics::from([[-3.0, 0.0], [0.0, 1.0]]);
+        let (value, vector) = b.dominant_eigenpair(1000, 1e-12).unwrap();
+        assert!((value + 3.0).abs() < 1e-9);
+        assert!((vector[0][0] - 1.0).abs() < 1e-9);
+
+        // 两个模相同、符号相反的特征值，幂迭代不收敛
+        let c = Matrix::from([[0.0, 1.0], [1.0, 0.0]])
+            .dot_product(&Matrix::from([[2.0, 0.0], [0.0, 1.0]]));
+        assert!(c.dominant_eigenpair(50, 1e-12).is_none());
+    }
+
+    #[test]
+    fn test_pagerank() {
+        // 0 ⇄ 1，2 → 0，3 没有出链
+        let links = Matrix::from([
+            [0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ]);
+        let rank = links.pagerank(0.85);
+        let total = rank.as_slice().iter().sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-12);
+        assert!(rank[0][0] > rank[0][1] && rank[0][1] > rank[0][2]);
+        assert!((rank[0][2] - rank[0][3]).abs() < 1e-12);
+        // damping 为 0 时只剩随机跳转，得分均匀
+        assert_eq!(links.pagerank(0.0), Matrix::filled(0.25));
+    }
+}
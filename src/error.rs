@@ -0,0 +1,110 @@
+use alloc::string::String;
+use core::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    DimensionMismatch {
+        expected: usize,
+        got: usize,
+    },
+    NotSquare {
+        rows: usize,
+        cols: usize,
+    },
+    IndexOutOfBounds {
+        row: usize,
+        col: usize,
+    },
+    Singular,
+    // 要求对称正定的算法遇到了非正的对角元或主元
+    NotPositiveDefinite,
+    // 置换中第 position 个位置的下标 index 在前面已经出现过
+    InvalidPermutation {
+        position: usize,
+        index: usize,
+    },
+    // 三对角求解要求上下带宽都不超过 1
+    NotTridiagonal {
+        lower: usize,
+        upper: usize,
+    },
+    // 要求旋转矩阵（正交且 det = 1）的输入不满足条件
+    NotRotation,
+    Cancelled,
+    // 无法创建执行计算的线程，message 为系统返回的错误
+    SpawnFailed {
+        message: String,
+    },
+    // 并行运算中某个工作线程 panic，start_row..end_row 为它负责的结果行
+    WorkerPanicked {
+        start_row: usize,
+        end_row: usize,
+        message: String,
+    },
+}
+
+pub type Result<T> = core::result::Result<T, MatrixError>;
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::DimensionMismatch { expected, got } => {
+                write!(f, "dimension mismatch: expected {}, got {}", expected, got)
+            }
+            MatrixError::NotSquare { rows, cols } => {
+                write!(f, "matrix is not square: {}x{}", rows, cols)
+            }
+            MatrixError::IndexOutOfBounds { row, col } => {
+                write!(f, "index ({}, {}) out of bounds", row, col)
+            }
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::NotPositiveDefinite => write!(f, "matrix is not positive definite"),
+            MatrixError::InvalidPermutation { position, index } => write!(
+                f,
+                "invalid permutation: index {} at position {} appears twice",
+                index, position
+            ),
+            MatrixError::NotTridiagonal { lower, upper } => write!(
+                f,
+                "matrix is not tridiagonal: lower bandwidth {}, upper bandwidth {}",
+                lower, upper
+            ),
+            MatrixError::NotRotation => write!(f, "matrix is not a rotation"),
+            MatrixError::Cancelled => write!(f, "operation was cancelled"),
+            MatrixError::SpawnFailed { message } => {
+                write!(f, "failed to spawn worker thread: {}", message)
+            }
+            MatrixError::WorkerPanicked {
+                start_row,
+                end_row,
+                message,
+            } => write!(
+                f,
+                "worker panicked while computing rows {}..{}: {}",
+                start_row, end_row, message
+            ),
+        }
+    }
+}
+
+impl core::error::Error for MatrixError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_error_display() {
+        let err = MatrixError::DimensionMismatch {
+            expected: 4,
+            got: 3,
+        };
+        assert_eq!(err.to_string(), "dimension mismatch: expected 4, got 3");
+        assert_eq!(
+            MatrixError::NotSquare { rows: 2, cols: 3 }.to_string(),
+            "matrix is not square: 2x3"
+        );
+    }
+}
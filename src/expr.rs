@@ -0,0 +1,299 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use crate::{Matrix, MatrixBase, Storage, StorageMut};
+
+// 表达式的形状，只在类型层面使用，形状不同的表达式无法组合
+pub struct Shape<const R: usize, const C: usize>;
+
+// 惰性的逐元素表达式：`&a + &b`、`.map(f)`、`* k` 只构造表达式树，
+// 在 eval/assign 时按行优先一次遍历求值，中间不分配临时矩阵
+pub trait Expr: Sized {
+    type Elem;
+    type Shape;
+
+    // 行优先下标 index 处的元素
+    fn at(&self, index: usize) -> Self::Elem;
+
+    fn map<F, U>(self, f: F) -> MapExpr<Self, F>
+    where
+        F: Fn(Self::Elem) -> U,
+    {
+        MapExpr { expr: self, f }
+    }
+
+    // 与另一个同形状表达式逐元素组合
+    fn zip_with<B, F, U>(self, other: B, f: F) -> ZipExpr<Self, B, F>
+    where
+        B: Expr<Shape = Self::Shape>,
+        F: Fn(Self::Elem, B::Elem) -> U,
+    {
+        ZipExpr(self, other, f)
+    }
+
+    // 把 1×C 的行向量沿行方向重复 R 次，R 通常由参与运算的另一侧推断
+    fn broadcast_rows<const R: usize>(self) -> BroadcastRows<Self, R> {
+        BroadcastRows(self)
+    }
+
+    // 把 R×1 的列向量沿列方向重复 C 次
+    fn broadcast_cols<const C: usize>(self) -> BroadcastCols<Self, C> {
+        BroadcastCols(self)
+    }
+
+    fn eval<const R: usize, const C: usize>(&self) -> Matrix<Self::Elem, R, C>
+    where
+        Self: Expr<Shape = Shape<R, C>>,
+    {
+        let data: Vec<Self::Elem> = (0..R * C).map(|index| self.at(index)).collect();
+        Matrix::try_from(data).expect("expression has exactly R * C elements")
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize, S: Storage<T>> Expr for &MatrixBase<T, R, C, S> {
+    type Elem = T;
+    type Shape = Shape<R, C>;
+
+    fn at(&self, index: usize) -> T {
+        self.as_slice()[index].clone()
+    }
+}
+
+pub struct AddExpr<A, B>(A, B);
+
+pub struct SubExpr<A, B>(A, B);
+
+pub struct ScaleExpr<A, T>(A, T);
+
+pub struct MapExpr<A, F> {
+    expr: A,
+    f: F,
+}
+
+impl<A, B> Expr for AddExpr<A, B>
+where
+    A: Expr,
+    B: Expr<Elem = A::Elem, Shape = A::Shape>,
+    A::Elem: Add<Output = A::Elem>,
+{
+    type Elem = A::Elem;
+    type Shape = A::Shape;
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index) + self.1.at(index)
+    }
+}
+
+impl<A, B> Expr for SubExpr<A, B>
+where
+    A: Expr,
+    B: Expr<Elem = A::Elem, Shape = A::Shape>,
+    A::Elem: Sub<Output = A::Elem>,
+{
+    type Elem = A::Elem;
+    type Shape = A::Shape;
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index) - self.1.at(index)
+    }
+}
+
+impl<A, T> Expr for ScaleExpr<A, T>
+where
+    A: Expr<Elem = T>,
+    T: Mul<Output = T> + Clone,
+{
+    type Elem = T;
+    type Shape = A::Shape;
+
+    fn at(&self, index: usize) -> T {
+        self.0.at(index) * self.1.clone()
+    }
+}
+
+impl<A, F, U> Expr for MapExpr<A, F>
+where
+    A: Expr,
+    F: Fn(A::Elem) -> U,
+{
+    type Elem = U;
+    type Shape = A::Shape;
+
+    fn at(&self, index: usize) -> U {
+        (self.f)(self.expr.at(index))
+    }
+}
+
+pub struct ZipExpr<A, B, F>(A, B, F);
+
+pub struct BroadcastRows<A, const R: usize>(A);
+
+pub struct BroadcastCols<A, const C: usize>(A);
+
+// 标量广播为 R×C 的常量表达式
+pub struct Splat<T, const R: usize, const C: usize>(T);
+
+pub fn splat<T, const R: usize, const C: usize>(value: T) -> Splat<T, R, C> {
+    Splat(value)
+}
+
+impl<A, B, F, U> Expr for ZipExpr<A, B, F>
+where
+    A: Expr,
+    B: Expr<Shape = A::Shape>,
+    F: Fn(A::Elem, B::Elem) -> U,
+{
+    type Elem = U;
+    type Shape = A::Shape;
+
+    fn at(&self, index: usize) -> U {
+        (self.2)(self.0.at(index), self.1.at(index))
+    }
+}
+
+impl<A, const R: usize, const C: usize> Expr for BroadcastRows<A, R>
+where
+    A: Expr<Shape = Shape<1, C>>,
+{
+    type Elem = A::Elem;
+    type Shape = Shape<R, C>;
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index % C)
+    }
+}
+
+impl<A, const R: usize, const C: usize> Expr for BroadcastCols<A, C>
+where
+    A: Expr<Shape = Shape<R, 1>>,
+{
+    type Elem = A::Elem;
+    type Shape = Shape<R, C>;
+
+    fn at(&self, index: usize) -> A::Elem {
+        self.0.at(index / C)
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> Expr for Splat<T, R, C> {
+    type Elem = T;
+    type Shape = Shape<R, C>;
+
+    fn at(&self, _index: usize) -> T {
+        self.0.clone()
+    }
+}
+
+// 为表达式节点实现 `+`、`-`（与同形状表达式）和 `*`（与标量）
+macro_rules! impl_expr_ops {
+    ([$($gen:tt)*] $ty:ty) => {
+        impl<$($gen)*, Rhs> Add<Rhs> for $ty
+        where
+            $ty: Expr,
+            Rhs: Expr<Elem = <$ty as Expr>::Elem, Shape = <$ty as Expr>::Shape>,
+            <$ty as Expr>::Elem: Add<Output = <$ty as Expr>::Elem>,
+        {
+            type Output = AddExpr<Self, Rhs>;
+
+            fn add(self, rhs: Rhs) -> AddExpr<Self, Rhs> {
+                AddExpr(self, rhs)
+            }
+        }
+
+        impl<$($gen)*, Rhs> Sub<Rhs> for $ty
+        where
+            $ty: Expr,
+            Rhs: Expr<Elem = <$ty as Expr>::Elem, Shape = <$ty as Expr>::Shape>,
+            <$ty as Expr>::Elem: Sub<Output = <$ty as Expr>::Elem>,
+        {
+            type Output = SubExpr<Self, Rhs>;
+
+            fn sub(self, rhs: Rhs) -> SubExpr<Self, Rhs> {
+                SubExpr(self, rhs)
+            }
+        }
+
+        impl<$($gen)*, X> Mul<X> for $ty
+        where
+            $ty: Expr<Elem = X>,
+            X: Mul<Output = X> + Clone,
+        {
+            type Output = ScaleExpr<Self, X>;
+
+            fn mul(self, scalar: X) -> ScaleExpr<Self, X> {
+                ScaleExpr(self, scalar)
+            }
+        }
+    };
+}
+
+impl_expr_ops!(['a, T, const R: usize, const C: usize, S] &'a MatrixBase<T, R, C, S>);
+impl_expr_ops!([A, B] AddExpr<A, B>);
+impl_expr_ops!([A, B] SubExpr<A, B>);
+impl_expr_ops!([A, T] ScaleExpr<A, T>);
+impl_expr_ops!([A, F] MapExpr<A, F>);
+impl_expr_ops!([A, B, F] ZipExpr<A, B, F>);
+impl_expr_ops!([A, const R: usize] BroadcastRows<A, R>);
+impl_expr_ops!([A, const C: usize] BroadcastCols<A, C>);
+impl_expr_ops!([T, const R: usize, const C: usize] Splat<T, R, C>);
+
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> MatrixBase<T, R, C, S> {
+    // 把表达式的结果写入已有矩阵，不分配内存
+    pub fn assign<E>(&mut self, expr: E)
+    where
+        E: Expr<Elem = T, Shape = Shape<R, C>>,
+    {
+        for (index, slot) in self.as_mut_slice().iter_mut().enumerate() {
+            *slot = expr.at(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fused_expression() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[4.0, 3.0], [2.0, 1.0]]);
+        let expr = (&a + &b).map(|x: f64| x.sqrt()) * 2.0;
+        assert_eq!(expr.eval(), Matrix::from([[2.0 * 5f64.sqrt(); 2]; 2]));
+
+        let c = (&a - &b + &a) * 3.0;
+        assert_eq!(c.eval(), Matrix::from([[-6.0, 3.0], [12.0, 21.0]]));
+    }
+
+    #[test]
+    fn test_broadcasting() {
+        let x = Matrix::from([[1.0, 10.0], [3.0, 30.0], [5.0, 20.0]]);
+        let mean = Matrix::from([[3.0, 20.0]]);
+        let scale = Matrix::from([[2.0, 10.0]]);
+        // 按列中心化并缩放，行数由 x 推断
+        let standardized = (&x - (&mean).broadcast_rows())
+            .zip_with((&scale).broadcast_rows(), |a: f64, s| a / s)
+            .eval();
+        assert_eq!(
+            standardized,
+            Matrix::from([[-1.0, -1.0], [0.0, 1.0], [1.0, 0.0]])
+        );
+
+        let offsets = Matrix::from([[1], [2]]);
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let shifted = (&a + (&offsets).broadcast_cols() + splat(10)).eval();
+        assert_eq!(shifted, Matrix::from([[12, 13, 14], [16, 17, 18]]));
+    }
+
+    #[test]
+    fn test_assign_in_place() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let mut out = Matrix::<i32, 2, 3>::default();
+        out.assign(&a * 2 - &a);
+        assert_eq!(out, a);
+        // 视图与其它存储可以混合
+        let data = [1; 6];
+        let ones = crate::MatrixView::<_, 2, 3>::from_slice(&data).unwrap();
+        out.view_mut().assign((&a + &ones).map(|x| x % 2));
+        assert_eq!(out, Matrix::from([[0, 1, 0], [1, 0, 1]]));
+    }
+}
@@ -0,0 +1,131 @@
+// C 接口：运行时尺寸的 f64 矩阵，数据按行优先存放。
+// 头文件 include/matrix.h 由 scripts/gen-header.sh 重新生成，build.rs 只写到 OUT_DIR。
+// crate-type 不写进 Cargo.toml（cdylib 会让 no_std 构建失败），需要时单独指定：
+//   cargo rustc --release --lib --features ffi --crate-type staticlib
+use core::ptr;
+use core::slice;
+
+use crate::dynamic::DynMatrix;
+use crate::ParallelConfig;
+
+/// 不透明的矩阵句柄，只能通过 `matrix_*` 函数访问，用完后交给 `matrix_free` 释放。
+pub struct MatrixF64 {
+    inner: DynMatrix<f64>,
+}
+
+fn into_handle(inner: DynMatrix<f64>) -> *mut MatrixF64 {
+    Box::into_raw(Box::new(MatrixF64 { inner }))
+}
+
+/// 创建 rows×cols 的矩阵，从 `data` 复制 rows * cols 个元素；`data` 为空指针时全部为 0。
+/// rows * cols 溢出时返回空指针。
+///
+/// # Safety
+///
+/// `data` 为空指针，或指向至少 rows * cols 个可读的 double。
+#[no_mangle]
+pub unsafe extern "C" fn matrix_new(rows: usize, cols: usize, data: *const f64) -> *mut MatrixF64 {
+    let Some(len) = rows.checked_mul(cols) else {
+        return ptr::null_mut();
+    };
+    let data = if data.is_null() {
+        vec![0.0; len]
+    } else {
+        slice::from_raw_parts(data, len).to_vec()
+    };
+    DynMatrix::new(rows, cols, data).map_or(ptr::null_mut(), into_handle)
+}
+
+/// 释放矩阵，传入空指针时什么也不做。
+///
+/// # Safety
+///
+/// `matrix` 为空指针，或是由本库返回且尚未释放的句柄。
+#[no_mangle]
+pub unsafe extern "C" fn matrix_free(matrix: *mut MatrixF64) {
+    if !matrix.is_null() {
+        drop(Box::from_raw(matrix));
+    }
+}
+
+/// # Safety
+///
+/// `matrix` 是有效的句柄。
+#[no_mangle]
+pub unsafe extern "C" fn matrix_rows(matrix: *const MatrixF64) -> usize {
+    (*matrix).inner.rows()
+}
+
+/// # Safety
+///
+/// `matrix` 是有效的句柄。
+#[no_mangle]
+pub unsafe extern "C" fn matrix_cols(matrix: *const MatrixF64) -> usize {
+    (*matrix).inner.cols()
+}
+
+/// 返回行优先的 rows * cols 个元素，可以原地读写；指针在矩阵释放前有效。
+///
+/// # Safety
+///
+/// `matrix` 是有效的句柄。
+#[no_mangle]
+pub unsafe extern "C" fn matrix_data(matrix: *mut MatrixF64) -> *mut f64 {
+    (*matrix).inner.as_mut_slice().as_mut_ptr()
+}
+
+/// 计算 a·b 并返回新矩阵；a 的列数与 b 的行数不一致时返回空指针。
+/// `threads` 为 1 时串行，为 0 时按矩阵规模和 CPU 核数自动选择。
+///
+/// # Safety
+///
+/// `a` 与 `b` 是有效的句柄。
+#[no_mangle]
+pub unsafe extern "C" fn matrix_multiply(
+    a: *const MatrixF64,
+    b: *const MatrixF64,
+    threads: usize,
+) -> *mut MatrixF64 {
+    let (a, b) = (&(*a).inner, &(*b).inner);
+    let threads = match threads {
+        0 => ParallelConfig::default().threads_for(a.rows(), a.cols(), b.cols()),
+        threads => threads,
+    };
+    let product = match threads {
+        1 => a.dot_product(b),
+        threads => a.dot_product_in_parallel(b, threads),
+    };
+    product.map_or(ptr::null_mut(), into_handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_api_round_trip() {
+        let a_data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        unsafe {
+            let a = matrix_new(2, 3, a_data.as_ptr());
+            let b = matrix_new(3, 2, ptr::null());
+            assert_eq!((matrix_rows(b), matrix_cols(b)), (3, 2));
+            // b 初始为零矩阵，写成 [[1, 0], [0, 1], [1, 1]]
+            let b_data = slice::from_raw_parts_mut(matrix_data(b), 6);
+            b_data.copy_from_slice(&[1.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+
+            for threads in [0, 1, 4] {
+                let c = matrix_multiply(a, b, threads);
+                assert_eq!(
+                    slice::from_raw_parts(matrix_data(c), 4),
+                    &[4.0, 5.0, 10.0, 11.0]
+                );
+                matrix_free(c);
+            }
+            assert!(matrix_multiply(a, a, 1).is_null());
+            assert!(matrix_new(usize::MAX, 2, ptr::null()).is_null());
+            matrix_free(a);
+            matrix_free(b);
+            matrix_free(ptr::null_mut());
+        }
+    }
+}
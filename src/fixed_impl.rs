@@ -0,0 +1,120 @@
+use alloc::{vec, vec::Vec};
+
+use fixed::traits::{Fixed, FixedBits};
+
+use crate::{gemm, MatrixBase, OwnedMatrix, Storage};
+
+// fixed 的定点数实现了 num-traits 的 Zero、One 与 Checked*/Saturating*/Wrapping* 运算，
+// dot_product 以及 overflow 中的各种乘法都可以直接使用；
+// 这里另外提供先在宽整数中累加、最后统一移位的乘法，中间结果不会溢出也不会逐项舍入
+// 只支持不超过 32 位的定点数：两个原始整数位的乘积不超过 2^64，i128 中能精确累加 2^63 项；
+// 64 位的乘积本身就可能超出 i128，会在编译期报错
+
+fn widen<B: FixedBits>(bits: B) -> i128 {
+    bits.try_into()
+        .unwrap_or_else(|_| unreachable!("fixed bits wider than 32 bits"))
+}
+
+// 右移 shift 位并四舍五入，超出 T 的范围时饱和
+fn narrow<T: Fixed>(sum: i128, shift: u32) -> T {
+    let rounded = match shift {
+        0 => sum,
+        _ => sum.saturating_add(1 << (shift - 1)) >> shift,
+    };
+    T::Bits::try_from(rounded)
+        .map(T::from_bits)
+        .unwrap_or(if rounded < 0 { T::MIN } else { T::MAX })
+}
+
+// 原始整数位的乘积在 i128 中精确累加，shift 为累加后右移的位数
+fn dot_fixed<T: Fixed>(a: &[T], b: &[T], m: usize, k: usize, n: usize, shift: u32) -> Vec<T> {
+    const {
+        assert!(
+            T::Bits::BITS <= 32,
+            "dot_product_fixed supports fixed-point types of at most 32 bits"
+        )
+    };
+    assert!(shift < 128, "shift {} out of range", shift);
+    let mut acc = vec![0i128; n];
+    // SAFETY: 每一行都写满 n 个元素，共 m * n 个
+    unsafe {
+        gemm::uninit_vec(m * n, |c| {
+            for (i, row) in c.chunks_mut(n.max(1)).take(m).enumerate() {
+                acc.fill(0);
+                for p in 0..k {
+                    let scale = widen(a[i * k + p].to_bits());
+                    for (sum, value) in acc.iter_mut().zip(&b[p * n..(p + 1) * n]) {
+                        *sum += scale * widen(value.to_bits());
+                    }
+                }
+                for (slot, &sum) in row.iter_mut().zip(&acc) {
+                    slot.write(narrow(sum, shift));
+                }
+            }
+        })
+    }
+}
+
+impl<T: Fixed, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 两个 T 的乘积有 2 * FRAC_NBITS 位小数，shift 取 T::FRAC_NBITS 即为普通的定点乘法；
+    // 取其它值可以顺带完成缩放，例如多右移 n 位相当于再除以 2^n；T 不能超过 32 位
+    pub fn dot_product_fixed<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+        shift: u32,
+    ) -> OwnedMatrix<S::Family, T, X, Z> {
+        let data = dot_fixed(self.as_slice(), matrix1.as_slice(), X, Y, Z, shift);
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fixed::types::{I16F16, I8F8, U0F32, U8F8};
+
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_fixed_elements() {
+        let a = Matrix::<I16F16, 2, 2>::from_fn(|i, j| I16F16::from_num(i as f32 + 0.5 * j as f32));
+        let b = Matrix::<I16F16, 2, 2>::from_fn(|i, j| I16F16::from_num(1.25 - (i * j) as f32));
+        let expected = a.dot_product(&b);
+        assert_eq!(a.dot_product_fixed(&b, I16F16::FRAC_NBITS), expected);
+        assert_eq!(a.checked_dot_product(&b).as_ref(), Some(&expected));
+        // 多右移一位，结果减半
+        assert_eq!(
+            a.dot_product_fixed(&b, I16F16::FRAC_NBITS + 1),
+            Matrix::from_fn(|i, j| expected[i][j] / 2)
+        );
+    }
+
+    #[test]
+    fn test_fixed_accumulation_overflow() {
+        // 100 * 1 + 100 * 1 - 100 * 1：中间和 200 超出 I8F8 的范围（< 128），最终结果不超出
+        let a = DynMatrics::<I8F8, 1, 3>::from([[I8F8::from_num(100); 3]]);
+        let b = DynMatrics::<I8F8, 3, 1>::from([[I8F8::ONE], [I8F8::ONE], [-I8F8::ONE]]);
+        assert_eq!(a.checked_dot_product(&b), None);
+        assert_eq!(
+            a.dot_product_fixed(&b, I8F8::FRAC_NBITS).as_slice(),
+            [I8F8::from_num(100)]
+        );
+        // 最终结果超出范围时饱和
+        let b = DynMatrics::<I8F8, 3, 1>::from([[I8F8::ONE]; 3]);
+        assert_eq!(a.dot_product_fixed(&b, 8).as_slice(), [I8F8::MAX]);
+        let c = Matrix::<U8F8, 1, 1>::from([[U8F8::from_num(0.5)]]);
+        assert_eq!(
+            c.dot_product_fixed(&c, 8),
+            Matrix::from([[U8F8::from_num(0.25)]])
+        );
+
+        // 32 位无符号的乘积接近 2^64，多项相加仍精确：4 * (2^32 - 1)^2 / 2^34 = 2^32 - 2 + 2^-32
+        let a = DynMatrics::<U0F32, 1, 4>::from([[U0F32::MAX; 4]]);
+        let b = DynMatrics::<U0F32, 4, 1>::from([[U0F32::MAX]; 4]);
+        assert_eq!(
+            a.dot_product_fixed(&b, 34).as_slice(),
+            [U0F32::from_bits(0xffff_fffe)]
+        );
+        assert_eq!(a.dot_product_fixed(&b, 33).as_slice(), [U0F32::MAX]);
+    }
+}
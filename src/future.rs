@@ -0,0 +1,223 @@
+use std::any::Any;
+use std::future::Future;
+use std::ops::Mul;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+
+use num_traits::Zero;
+
+use crate::{
+    gemm, parallel, GemmElement, MatrixBase, MatrixError, MatrixThreadPool, OwnedMatrix,
+    ParallelConfig, Result, Storage,
+};
+
+// 每次乘法最多切成这么多个行面板，面板之间检查一次是否已取消
+const CANCEL_CHECKS: usize = 64;
+
+// 计算的结果、无法启动时的错误，或计算中 panic 的载荷
+type Outcome<T> = std::result::Result<Result<T>, Box<dyn Any + Send>>;
+
+struct State<T> {
+    outcome: Option<Outcome<T>>,
+    waker: Option<Waker>,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    cancelled: AtomicBool,
+}
+
+// 在后台线程上执行的计算，完成后唤醒等待者；不依赖具体的异步运行时，Tokio 等均可直接 await
+// 丢弃 future 即请求取消，计算在下一个检查点放弃，结果不再产生
+// 无法创建后台线程或计算用的工作线程时得到 SpawnFailed；计算 panic 时，panic 在 poll 中重新抛出
+pub struct MatrixFuture<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Future for MatrixFuture<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<T>> {
+        let mut state = self.shared.state.lock().unwrap();
+        match state.outcome.take() {
+            Some(Ok(value)) => Poll::Ready(value),
+            Some(Err(payload)) => {
+                drop(state);
+                resume_unwind(payload)
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for MatrixFuture<T> {
+    fn drop(&mut self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+// 所有后台计算共用的常驻线程池，第一次使用时创建；线程数与 CPU 核数相同，
+// 同时进行的计算更多时在池中排队。创建失败时下次使用会重新尝试
+static ASYNC_POOL: OnceLock<MatrixThreadPool> = OnceLock::new();
+
+fn async_pool() -> std::io::Result<&'static MatrixThreadPool> {
+    if let Some(pool) = ASYNC_POOL.get() {
+        return Ok(pool);
+    }
+    let pool = MatrixThreadPool::try_new(num_cpus::get())?;
+    // 并发初始化时落选的池在这里被丢弃，其线程随之退出
+    Ok(ASYNC_POOL.get_or_init(|| pool))
+}
+
+// 在后台线程池上运行 job，job 应定期检查传入的取消标志，已取消时可以返回 None
+fn spawn_cancellable<T, F>(job: F) -> MatrixFuture<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&AtomicBool) -> Option<Result<T>> + Send + 'static,
+{
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            outcome: None,
+            waker: None,
+        }),
+        cancelled: AtomicBool::new(false),
+    });
+    let worker = Arc::clone(&shared);
+    let task = move || {
+        let outcome = match catch_unwind(AssertUnwindSafe(|| job(&worker.cancelled))) {
+            Ok(Some(result)) => Ok(result),
+            Ok(None) => return,
+            Err(payload) => Err(payload),
+        };
+        let mut state = worker.state.lock().unwrap();
+        state.outcome = Some(outcome);
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    };
+    let submitted = match async_pool() {
+        Ok(pool) if pool.execute(task) => Ok(()),
+        Ok(_) => Err("matrix async pool is shut down".to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+    if let Err(message) = submitted {
+        shared.state.lock().unwrap().outcome = Some(Ok(Err(MatrixError::SpawnFailed { message })));
+    }
+    MatrixFuture { shared }
+}
+
+// 结果切成至多 CANCEL_CHECKS 个行面板，由 threads 个线程动态领取，线程只创建一次；
+// 每个面板开始前检查取消标志，取消后剩余的面板直接跳过；已取消时返回 None
+fn gemm_cancellable<T>(
+    a: &[T],
+    b: &[T],
+    (x, y, z): (usize, usize, usize),
+    cancelled: &AtomicBool,
+) -> Option<Result<Vec<T>>>
+where
+    T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+{
+    let threads = ParallelConfig::default().threads_for(x, y, z);
+    let mut c = vec![T::zero(); x * z];
+    let panel_rows = x.div_ceil(CANCEL_CHECKS).max(1);
+    let panels = c.chunks_mut(panel_rows * z.max(1)).collect();
+    let computed = parallel::try_for_each_part_dynamic(panels, threads, |i, panel| {
+        if cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+        let start = i * panel_rows;
+        let rows = panel.len() / z.max(1);
+        let local_a = &a[start * y..(start + rows) * y];
+        gemm::gemm_dispatch(local_a, b, rows, y, z, panel);
+    });
+    (!cancelled.load(Ordering::Relaxed)).then_some(computed.map(|()| c))
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 后台线程不能借用 self，两个操作数会先各复制一份
+    pub fn dot_product_async<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> MatrixFuture<OwnedMatrix<S::Family, T, X, Z>>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + 'static,
+        OwnedMatrix<S::Family, T, X, Z>: Send + 'static,
+    {
+        let a = self.as_slice().to_vec();
+        let b = matrix1.as_slice().to_vec();
+        spawn_cancellable(move |cancelled| {
+            let data = gemm_cancellable(&a, &b, (X, Y, Z), cancelled)?;
+            Some(data.map(|data| {
+                MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::task::Wake;
+    use std::thread::Thread;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    // 测试用的最小执行器
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = std::pin::pin!(future);
+        let waker = Waker::from(Arc::new(Unpark(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(value) => return value,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_dot_product_async() {
+        let a = Matrix::<i64, 70, 30>::from_fn(|i, j| (i + j) as i64 % 5);
+        let b = Matrix::<i64, 30, 20>::from_fn(|i, j| (i * j) as i64 % 7);
+        assert_eq!(block_on(a.dot_product_async(&b)), Ok(a.dot_product(&b)));
+        let c = DynMatrics::from([[1, 2], [3, 4]]);
+        assert_eq!(block_on(c.dot_product_async(&c)), Ok(c.dot_product(&c)));
+
+        // 多个计算共用同一个线程池
+        let futures: Vec<_> = (0..32).map(|_| a.dot_product_async(&b)).collect();
+        for future in futures {
+            assert_eq!(block_on(future), Ok(a.dot_product(&b)));
+        }
+    }
+
+    #[test]
+    fn test_drop_cancels() {
+        let (sender, receiver) = mpsc::channel();
+        let future = spawn_cancellable(move |cancelled: &AtomicBool| {
+            while !cancelled.load(Ordering::Relaxed) {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            sender.send(()).unwrap();
+            None::<Result<()>>
+        });
+        drop(future);
+        receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+}
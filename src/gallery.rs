@@ -0,0 +1,150 @@
+// 经典测试矩阵，用于检验求解器的正确性与条件数相关的行为
+use num_traits::{Float, One, Zero};
+use rand::distributions::{Distribution, Open01};
+use rand::Rng;
+
+use crate::linalg::Field;
+use crate::permutation::Permutation;
+use crate::{Matrix, Result};
+
+// Field 里只有 0 和 1，整数 n 由 n 个 1 相加得到，有理数类型也能精确表示
+fn field_from<T: Field>(n: usize) -> T {
+    (0..n).fold(T::zero(), |sum, _| sum + T::one())
+}
+
+// H[i][j] = 1 / (i + j + 1)，条件数随阶数指数增长
+pub fn hilbert<T: Field, const N: usize>() -> Matrix<T, N, N> {
+    Matrix::from_fn(|i, j| T::one() / field_from(i + j + 1))
+}
+
+// V[i][j] = points[i]^j
+pub fn vandermonde<T, const N: usize, const M: usize>(points: &[T; N]) -> Matrix<T, N, M>
+where
+    T: One + Clone,
+{
+    Matrix::from_fn(|i, j| (0..j).fold(T::one(), |power, _| power * points[i].clone()))
+}
+
+// 每条对角线上元素相同：T[i][j] 在 i >= j 时取 first_col[i - j]，否则取 first_row[j - i]
+// first_row[0] 与 first_col[0] 重合，以 first_col 为准
+pub fn toeplitz<T: Clone, const N: usize, const M: usize>(
+    first_col: &[T; N],
+    first_row: &[T; M],
+) -> Matrix<T, N, M> {
+    Matrix::from_fn(|i, j| {
+        if i >= j {
+            first_col[i - j].clone()
+        } else {
+            first_row[j - i].clone()
+        }
+    })
+}
+
+// P[i][perm[i]] = 1，即 P·A 的第 i 行是 A 的第 perm[i] 行
+pub fn permutation<T, const N: usize>(perm: &[usize; N]) -> Result<Matrix<T, N, N>>
+where
+    T: Zero + One,
+{
+    Ok(Permutation::try_from(*perm)?.to_dense())
+}
+
+// Box-Muller 变换得到标准正态分布
+fn standard_normal<T: Float>(rng: &mut impl Rng) -> T {
+    let (u, v): (f64, f64) = (Open01.sample(rng), Open01.sample(rng));
+    let z = (-2.0 * u.ln()).sqrt() * (core::f64::consts::TAU * v).cos();
+    T::from(z).expect("float conversion")
+}
+
+// 对高斯随机矩阵做修正 Gram-Schmidt 正交化，得到按 Haar 测度分布的正交矩阵
+pub fn random_orthogonal<T: Float, const N: usize>(rng: &mut impl Rng) -> Matrix<T, N, N> {
+    let mut q = Matrix::<T, N, N>::from_fn(|_, _| standard_normal(rng));
+    // 逐列处理，列向量为 q[..][j]
+    for j in 0..N {
+        for k in 0..j {
+            let dot = (0..N).fold(T::zero(), |sum, i| sum + q[i][k] * q[i][j]);
+            for i in 0..N {
+                q[i][j] = q[i][j] - dot * q[i][k];
+            }
+        }
+        let norm = (0..N)
+            .fold(T::zero(), |sum, i| sum + q[i][j] * q[i][j])
+            .sqrt();
+        for i in 0..N {
+            q[i][j] = q[i][j] / norm;
+        }
+    }
+    q
+}
+
+// Q·diag(λ)·Qᵀ，特征值在 1 与 condition 之间按几何级数分布，2-范数条件数恰为 condition
+pub fn random_spd<T: Float, const N: usize>(rng: &mut impl Rng, condition: T) -> Matrix<T, N, N> {
+    let q = random_orthogonal::<T, N>(rng);
+    let eigenvalue = |k: usize| {
+        if N > 1 {
+            condition.powf(T::from(k).unwrap() / T::from(N - 1).unwrap())
+        } else {
+            T::one()
+        }
+    };
+    let entry = |i: usize, j: usize| {
+        (0..N).fold(T::zero(), |sum, k| sum + q[i][k] * eigenvalue(k) * q[j][k])
+    };
+    // 只算上三角再镜像，保证结果严格对称
+    Matrix::from_fn(|i, j| entry(i.min(j), i.max(j)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::bench_utils::seeded_rng;
+    use crate::MatrixError;
+
+    #[test]
+    fn test_structured_matrices() {
+        let h = hilbert::<f64, 3>();
+        assert_eq!(h[0], [1.0, 0.5, 1.0 / 3.0]);
+        assert_eq!(h[2][2], 0.2);
+
+        let v = vandermonde::<_, 3, 4>(&[1, 2, 3]);
+        assert_eq!(v, Matrix::from([[1, 1, 1, 1], [1, 2, 4, 8], [1, 3, 9, 27]]));
+
+        let t = toeplitz(&[1, 2, 3], &[0, 4]);
+        assert_eq!(t, Matrix::from([[1, 4], [2, 1], [3, 2]]));
+
+        let p = permutation::<i32, 3>(&[2, 0, 1]).unwrap();
+        let a = Matrix::from([[1, 1], [2, 2], [3, 3]]);
+        assert_eq!(p.dot_product(&a), Matrix::from([[3, 3], [1, 1], [2, 2]]));
+        assert_eq!(
+            permutation::<i32, 3>(&[0, 0, 1]),
+            Err(MatrixError::InvalidPermutation {
+                position: 1,
+                index: 0
+            })
+        );
+        assert_eq!(
+            permutation::<i32, 2>(&[0, 2]),
+            Err(MatrixError::IndexOutOfBounds { row: 1, col: 2 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_random_orthogonal_and_spd() {
+        let mut rng = seeded_rng(11);
+        let q = random_orthogonal::<f64, 6>(&mut rng);
+        let qtq = q.transpose().dot_product(&q);
+        for i in 0..6 {
+            for j in 0..6 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((qtq[i][j] - expected).abs() < 1e-12);
+            }
+        }
+
+        let spd = random_spd::<f64, 5>(&mut rng, 100.0);
+        assert_eq!(spd, spd.transpose());
+        // 对称正定：行列式为全部特征值之积 1·√10·10·√1000·100
+        let det = spd.determinant();
+        assert!((det - 1e5).abs() / 1e5 < 1e-9);
+    }
+}
@@ -0,0 +1,599 @@
+use alloc::{vec, vec::Vec};
+use core::convert::Infallible;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Mul, Range, Sub};
+
+use num_traits::Zero;
+
+use crate::layout::Layout;
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// a 为 x×y 行优先，packed 为 z×y 行优先（即 B 的列优先拷贝），两者都按行连续读取
+fn dot_packed<T>(a: &[T], packed: &[T], x: usize, y: usize, z: usize) -> Vec<T>
+where
+    T: Zero + Mul<Output = T> + Clone,
+{
+    let mut result = Vec::with_capacity(x * z);
+    for row in a.chunks(y.max(1)).take(x) {
+        for column in packed.chunks(y.max(1)).take(z) {
+            let sum = row
+                .iter()
+                .zip(column)
+                .fold(T::zero(), |sum, (a, b)| sum + a.clone() * b.clone());
+            result.push(sum);
+        }
+    }
+    // y == 0 时 chunks 不产生任何块，结果全为零
+    result.resize_with(x * z, T::zero);
+    result
+}
+
+// 寄存器分块 MR×NR，以及 A/B 面板在缓存中的分块大小
+const MR: usize = 4;
+const NR: usize = 4;
+const MC: usize = 64;
+const KC: usize = 256;
+const NC: usize = 512;
+
+// 微内核负责的 C 子块：左上角坐标与实际行列数（边缘块小于 MR×NR）
+struct Tile {
+    row: usize,
+    col: usize,
+    rows: usize,
+    cols: usize,
+}
+
+// 把 A[ic.., pc..] 的 mc×kc 子块按 MR 行一组打包，每组内按 k 优先排列，不足 MR 行补零
+// 打包时按 layout 取元素，之后的微内核与存储顺序无关
+fn pack_a<T: Zero + Clone>(
+    (a, layout): (&[T], Layout),
+    m: usize,
+    k: usize,
+    ic: usize,
+    mc: usize,
+    pc: usize,
+    kc: usize,
+) -> Vec<T> {
+    let panels = mc.div_ceil(MR);
+    let mut packed = Vec::with_capacity(panels * kc * MR);
+    for panel in 0..panels {
+        for p in 0..kc {
+            for ir in 0..MR {
+                let i = panel * MR + ir;
+                packed.push(if i < mc {
+                    a[layout.index(ic + i, pc + p, m, k)].clone()
+                } else {
+                    T::zero()
+                });
+            }
+        }
+    }
+    packed
+}
+
+// 把 B[pc.., jc..] 的 kc×nc 子块按 NR 列一组打包，不足 NR 列补零
+fn pack_b<T: Zero + Clone>(
+    (b, layout): (&[T], Layout),
+    k: usize,
+    n: usize,
+    pc: usize,
+    kc: usize,
+    jc: usize,
+    nc: usize,
+) -> Vec<T> {
+    let panels = nc.div_ceil(NR);
+    let mut packed = Vec::with_capacity(panels * kc * NR);
+    for panel in 0..panels {
+        for p in 0..kc {
+            for jr in 0..NR {
+                let j = panel * NR + jr;
+                packed.push(if j < nc {
+                    b[layout.index(pc + p, jc + j, k, n)].clone()
+                } else {
+                    T::zero()
+                });
+            }
+        }
+    }
+    packed
+}
+
+// 结果缓冲区的元素：已初始化的 T，或尚未初始化的 MaybeUninit<T>
+pub(crate) trait Output<T> {
+    // 第一次写入时槽位内容无意义（可能未初始化），直接覆盖
+    fn store(&mut self, value: T, first: bool);
+
+    // 只会在第一次写入之后调用
+    fn load(&self) -> T;
+}
+
+impl<T: Clone> Output<T> for T {
+    fn store(&mut self, value: T, _first: bool) {
+        *self = value;
+    }
+
+    fn load(&self) -> T {
+        self.clone()
+    }
+}
+
+impl<T: Clone> Output<T> for MaybeUninit<T> {
+    fn store(&mut self, value: T, first: bool) {
+        if first {
+            self.write(value);
+        } else {
+            // SAFETY: 第一次写入之后槽位已初始化
+            *unsafe { self.assume_init_mut() } = value;
+        }
+    }
+
+    fn load(&self) -> T {
+        // SAFETY: 同上
+        unsafe { self.assume_init_ref() }.clone()
+    }
+}
+
+// 分配 len 个未初始化元素交给 fill，省去先清零再覆盖的开销
+// SAFETY: 调用方保证 fill 正常返回时已写满所有元素；fill panic 时已写入的元素只会泄漏
+pub(crate) unsafe fn uninit_vec<T>(len: usize, fill: impl FnOnce(&mut [MaybeUninit<T>])) -> Vec<T> {
+    let Ok(data) = unsafe {
+        try_uninit_vec::<T, Infallible>(len, |buffer| {
+            fill(buffer);
+            Ok(())
+        })
+    };
+    data
+}
+
+// 与 uninit_vec 相同，但 fill 可以中途放弃：返回 Err 时不假定已写满，已写入的元素只会泄漏
+// SAFETY: 调用方保证 fill 返回 Ok 时已写满所有元素
+pub(crate) unsafe fn try_uninit_vec<T, E>(
+    len: usize,
+    fill: impl FnOnce(&mut [MaybeUninit<T>]) -> Result<(), E>,
+) -> Result<Vec<T>, E> {
+    let mut buffer: Vec<MaybeUninit<T>> = Vec::with_capacity(len);
+    // SAFETY: MaybeUninit 不要求初始化
+    unsafe { buffer.set_len(len) };
+    fill(&mut buffer)?;
+    let mut buffer = ManuallyDrop::new(buffer);
+    // SAFETY: 所有元素均已初始化，MaybeUninit<T> 与 T 内存布局相同
+    Ok(unsafe { Vec::from_raw_parts(buffer.as_mut_ptr() as *mut T, len, buffer.capacity()) })
+}
+
+// 计算一个 MR×NR 子块；first 表示第一个深度分块，此时累加器从零开始并直接覆盖 C，
+// 之后的分块从 C 读出继续累加。每个元素的求和顺序与朴素三重循环一致
+fn micro_kernel<T, S>(
+    kc: usize,
+    pa: &[T],
+    pb: &[T],
+    c: &mut [S],
+    n: usize,
+    tile: &Tile,
+    first: bool,
+) where
+    T: Zero + Mul<Output = T> + Clone,
+    S: Output<T>,
+{
+    let mut acc: [[T; NR]; MR] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            if !first && i < tile.rows && j < tile.cols {
+                c[(tile.row + i) * n + tile.col + j].load()
+            } else {
+                T::zero()
+            }
+        })
+    });
+    for p in 0..kc {
+        let a = &pa[p * MR..(p + 1) * MR];
+        let b = &pb[p * NR..(p + 1) * NR];
+        for (row, a) in acc.iter_mut().zip(a) {
+            for (value, b) in row.iter_mut().zip(b) {
+                *value = value.clone() + a.clone() * b.clone();
+            }
+        }
+    }
+    for (i, row) in acc.into_iter().enumerate().take(tile.rows) {
+        for (j, value) in row.into_iter().enumerate().take(tile.cols) {
+            c[(tile.row + i) * n + tile.col + j].store(value, first);
+        }
+    }
+}
+
+// 乘法入口对元素类型的额外要求：启用 `blas` 或 `half` 特性时要用 TypeId 识别
+// f32/f64/f16/bf16，因此必须是 'static；两者都未启用时对所有类型成立
+#[cfg(any(feature = "blas", feature = "half"))]
+pub trait GemmElement: 'static {}
+#[cfg(any(feature = "blas", feature = "half"))]
+impl<T: 'static> GemmElement for T {}
+
+#[cfg(not(any(feature = "blas", feature = "half")))]
+pub trait GemmElement {}
+#[cfg(not(any(feature = "blas", feature = "half")))]
+impl<T> GemmElement for T {}
+
+// 串行乘法的入口：启用 `blas` 特性时 f32/f64 交给 cblas 的 sgemm/dgemm，
+// 启用 `half` 特性时 f16/bf16 在 f32 中累加，其余类型走下面的分块实现
+pub(crate) fn gemm_dispatch<T, S>(a: &[T], b: &[T], m: usize, k: usize, n: usize, c: &mut [S])
+where
+    T: Zero + Mul<Output = T> + Clone + GemmElement,
+    S: Output<T>,
+{
+    #[cfg(feature = "blas")]
+    if crate::blas_impl::gemm(a, b, m, k, n, c) {
+        return;
+    }
+    #[cfg(feature = "half")]
+    if crate::half_impl::gemm(a, b, m, k, n, 0..n, c) {
+        return;
+    }
+    gemm_into(a, b, m, k, n, c);
+}
+
+// 只计算 B 的 cols 列的 gemm_dispatch，C 为 m×cols.len() 行优先，供按列切分的并行乘法使用
+// BLAS 只处理完整的乘法，f16/bf16 同样在 f32 中累加
+#[cfg(feature = "std")]
+pub(crate) fn gemm_cols_dispatch<T, S>(
+    a: &[T],
+    b: &[T],
+    m: usize,
+    k: usize,
+    n: usize,
+    cols: Range<usize>,
+    c: &mut [S],
+) where
+    T: Zero + Mul<Output = T> + Clone + GemmElement,
+    S: Output<T>,
+{
+    if cols == (0..n) {
+        return gemm_dispatch(a, b, m, k, n, c);
+    }
+    #[cfg(feature = "half")]
+    if crate::half_impl::gemm(a, b, m, k, n, cols.clone(), c) {
+        return;
+    }
+    gemm_cols_into(a, b, m, k, n, cols, c);
+}
+
+// C = A·B，A 为 m×k、B 为 k×n、C 为 m×n，均为行优先；C 的原有内容被覆盖
+pub(crate) fn gemm_into<T, S>(a: &[T], b: &[T], m: usize, k: usize, n: usize, c: &mut [S])
+where
+    T: Zero + Mul<Output = T> + Clone,
+    S: Output<T>,
+{
+    gemm_cols_into(a, b, m, k, n, 0..n, c);
+}
+
+// 只计算 B 的 cols 列对应的结果，C 为 m×cols.len() 行优先
+pub(crate) fn gemm_cols_into<T, S>(
+    a: &[T],
+    b: &[T],
+    m: usize,
+    k: usize,
+    n: usize,
+    cols: Range<usize>,
+    c: &mut [S],
+) where
+    T: Zero + Mul<Output = T> + Clone,
+    S: Output<T>,
+{
+    gemm_layout_cols_into(
+        (a, Layout::RowMajor),
+        (b, Layout::RowMajor),
+        m,
+        k,
+        n,
+        cols,
+        c,
+    );
+}
+
+// 同 gemm_into，但 A、B 可以各自按行优先或列优先存放，C 总是行优先
+pub(crate) fn gemm_layout_into<T, S>(
+    a: (&[T], Layout),
+    b: (&[T], Layout),
+    m: usize,
+    k: usize,
+    n: usize,
+    c: &mut [S],
+) where
+    T: Zero + Mul<Output = T> + Clone,
+    S: Output<T>,
+{
+    gemm_layout_cols_into(a, b, m, k, n, 0..n, c);
+}
+
+// 三层分块：NC 列 → KC 深度（打包 B）→ MC 行（打包 A）→ MR×NR 微内核
+fn gemm_layout_cols_into<T, S>(
+    (a, a_layout): (&[T], Layout),
+    (b, b_layout): (&[T], Layout),
+    m: usize,
+    k: usize,
+    n: usize,
+    cols: Range<usize>,
+    c: &mut [S],
+) where
+    T: Zero + Mul<Output = T> + Clone,
+    S: Output<T>,
+{
+    let width = cols.len();
+    if k == 0 {
+        for slot in c.iter_mut().take(m * width) {
+            slot.store(T::zero(), true);
+        }
+        return;
+    }
+    for jc in cols.clone().step_by(NC) {
+        let nc = NC.min(cols.end - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            let packed_b = pack_b((b, b_layout), k, n, pc, kc, jc, nc);
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+                let packed_a = pack_a((a, a_layout), m, k, ic, mc, pc, kc);
+                for (jr, pb) in packed_b.chunks(kc * NR).enumerate() {
+                    for (ir, pa) in packed_a.chunks(kc * MR).enumerate() {
+                        let tile = Tile {
+                            row: ic + ir * MR,
+                            col: jc - cols.start + jr * NR,
+                            rows: MR.min(mc - ir * MR),
+                            cols: NR.min(nc - jr * NR),
+                        };
+                        micro_kernel(kc, pa, pb, c, width, &tile, pc == 0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// 操作数、累加器与结果类型各不相同的 C = A·B：A、B 的元素转换为 Acc 后相乘累加，
+// 每个元素的和再经 finish 写入 C。只额外占用 A 的一行与 C 的一行 Acc 作为临时缓冲
+// 只计算 B 的 cols 列，C 为 m×cols.len() 行优先
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gemm_mixed<A, B, Acc, O, S>(
+    a: &[A],
+    b: &[B],
+    m: usize,
+    k: usize,
+    n: usize,
+    cols: Range<usize>,
+    finish: impl Fn(Acc) -> O,
+    c: &mut [S],
+) where
+    A: Clone + Into<Acc>,
+    B: Clone + Into<Acc>,
+    Acc: Zero + Mul<Output = Acc> + Clone,
+    S: Output<O>,
+{
+    let mut a_row: Vec<Acc> = Vec::with_capacity(k);
+    let width = cols.len();
+    let mut acc = vec![Acc::zero(); width];
+    for i in 0..m {
+        a_row.clear();
+        a_row.extend(a[i * k..(i + 1) * k].iter().map(|x| x.clone().into()));
+        acc.fill(Acc::zero());
+        // 按 B 的行顺序读取，内层循环连续访问 B 与 acc
+        for (p, scale) in a_row.iter().enumerate() {
+            for (sum, value) in acc.iter_mut().zip(&b[p * n + cols.start..p * n + cols.end]) {
+                *sum = sum.clone() + scale.clone() * value.clone().into();
+            }
+        }
+        for (slot, sum) in c[i * width..(i + 1) * width].iter_mut().zip(&acc) {
+            slot.store(finish(sum.clone()), true);
+        }
+    }
+}
+
+fn zip_map<T: Clone>(a: &[T], b: &[T], op: impl Fn(T, T) -> T) -> Vec<T> {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| op(x.clone(), y.clone()))
+        .collect()
+}
+
+// 取 n×n 矩阵的 (r, c) 象限，边长 h = ceil(n / 2)，越界部分补零
+fn quadrant<T: Zero + Clone>(m: &[T], n: usize, h: usize, r: usize, c: usize) -> Vec<T> {
+    let mut result = Vec::with_capacity(h * h);
+    for i in r * h..(r + 1) * h {
+        for j in c * h..(c + 1) * h {
+            result.push(if i < n && j < n {
+                m[i * n + j].clone()
+            } else {
+                T::zero()
+            });
+        }
+    }
+    result
+}
+
+// 7 次子矩阵乘法代替 8 次，规模不超过 cutoff 时改用分块内核
+fn strassen<T>(a: &[T], b: &[T], n: usize, cutoff: usize) -> Vec<T>
+where
+    T: Zero + Sub<Output = T> + Mul<Output = T> + Clone,
+{
+    if n <= cutoff.max(1) {
+        let mut c = vec![T::zero(); n * n];
+        gemm_into(a, b, n, n, n, &mut c);
+        return c;
+    }
+    let h = n.div_ceil(2);
+    let [a11, a12, a21, a22] =
+        [(0, 0), (0, 1), (1, 0), (1, 1)].map(|(r, c)| quadrant(a, n, h, r, c));
+    let [b11, b12, b21, b22] =
+        [(0, 0), (0, 1), (1, 0), (1, 1)].map(|(r, c)| quadrant(b, n, h, r, c));
+    let add = |x: &[T], y: &[T]| zip_map(x, y, |p, q| p + q);
+    let sub = |x: &[T], y: &[T]| zip_map(x, y, |p, q| p - q);
+
+    let m1 = strassen(&add(&a11, &a22), &add(&b11, &b22), h, cutoff);
+    let m2 = strassen(&add(&a21, &a22), &b11, h, cutoff);
+    let m3 = strassen(&a11, &sub(&b12, &b22), h, cutoff);
+    let m4 = strassen(&a22, &sub(&b21, &b11), h, cutoff);
+    let m5 = strassen(&add(&a11, &a12), &b22, h, cutoff);
+    let m6 = strassen(&sub(&a21, &a11), &add(&b11, &b12), h, cutoff);
+    let m7 = strassen(&sub(&a12, &a22), &add(&b21, &b22), h, cutoff);
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+
+    let mut c = Vec::with_capacity(n * n);
+    for i in 0..n {
+        let (left, right) = if i < h { (&c11, &c12) } else { (&c21, &c22) };
+        let row = i % h;
+        for j in 0..n {
+            let quadrant = if j < h { left } else { right };
+            c.push(quadrant[row * h + j % h].clone());
+        }
+    }
+    c
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 朴素三重循环，作为分块实现的对照
+    pub fn dot_product_naive<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let a = self.as_slice();
+        let b = matrix1.as_slice();
+        let data: Vec<T> = (0..X)
+            .flat_map(|i| {
+                (0..Z).map(move |j| {
+                    (0..Y).fold(T::zero(), |sum, k| {
+                        sum + a[i * Y + k].clone() * b[k * Z + j].clone()
+                    })
+                })
+            })
+            .collect();
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+
+    // 先把 matrix1 转置成列优先，避免内层循环按 Z 跨步访问
+    pub fn dot_product_packed<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let packed = matrix1.transpose();
+        let data = dot_packed(self.as_slice(), packed.as_slice(), X, Y, Z);
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+}
+
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S> {
+    // 规模大于 cutoff 时递归使用 Strassen 算法；浮点结果与普通乘法存在舍入差异
+    pub fn dot_product_strassen(
+        &self,
+        matrix1: &MatrixBase<T, N, N, impl Storage<T>>,
+        cutoff: usize,
+    ) -> OwnedMatrix<S::Family, T, N, N>
+    where
+        T: Zero + Sub<Output = T> + Mul<Output = T> + Clone,
+    {
+        let data = strassen(self.as_slice(), matrix1.as_slice(), N, cutoff);
+        MatrixBase::try_from(data).expect("product has exactly N * N elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_packed_matches_naive() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[7, 8, 1, 0], [9, 10, 0, 1], [11, 12, 1, 1]]);
+        assert_eq!(a.dot_product_packed(&b), a.dot_product(&b));
+
+        let a =
+            DynMatrics::<f64, 3, 7>::try_from((0..21).map(|i| i as f64 * 0.3).collect::<Vec<_>>())
+                .unwrap();
+        let b = DynMatrics::<f64, 7, 5>::try_from(
+            (0..35).map(|i| 1.0 / (i as f64 + 1.0)).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        assert_eq!(a.dot_product_packed(&b), a.dot_product(&b));
+    }
+
+    #[test]
+    fn test_packed_empty_inner_dimension() {
+        let a = Matrix::<i32, 2, 0>::default();
+        let b = Matrix::<i32, 0, 3>::default();
+        assert_eq!(a.dot_product_packed(&b), Matrix::<i32, 2, 3>::default());
+    }
+
+    #[test]
+    fn test_gemm_matches_naive_across_blocks() {
+        // 各维度都跨越分块边界并留有不整齐的尾部
+        let a: Vec<i64> = (0..67 * 300).map(|i| i % 13 - 6).collect();
+        let b: Vec<i64> = (0..300 * 517).map(|i| i % 7 - 3).collect();
+        let a = DynMatrics::<i64, 67, 300>::try_from(a).unwrap();
+        let b = DynMatrics::<i64, 300, 517>::try_from(b).unwrap();
+        assert_eq!(a.dot_product(&b), a.dot_product_naive(&b));
+        #[cfg(feature = "std")]
+        assert_eq!(a.dot_product_in_parallel(&b, 3), a.dot_product_naive(&b));
+    }
+
+    #[test]
+    fn test_gemm_float_bitwise() {
+        let a: Vec<f32> = (0..9 * 270).map(|i| (i as f32 * 0.1).sin()).collect();
+        let b: Vec<f32> = (0..270 * 6).map(|i| (i as f32 * 0.7).cos()).collect();
+        let a = Matrix::<f32, 9, 270>::try_from(a).unwrap();
+        let b = Matrix::<f32, 270, 6>::try_from(b).unwrap();
+        assert_eq!(a.dot_product(&b), a.dot_product_naive(&b));
+        #[cfg(feature = "std")]
+        assert_eq!(a.dot_product_in_parallel(&b, 4), a.dot_product_naive(&b));
+    }
+
+    #[test]
+    fn test_strassen() {
+        // 奇数边长，递归过程中需要补零
+        let a: Vec<i64> = (0..37 * 37).map(|i| i % 11 - 5).collect();
+        let b: Vec<i64> = (0..37 * 37).map(|i| i % 9 - 4).collect();
+        let a = Matrix::<i64, 37, 37>::try_from(a).unwrap();
+        let b = Matrix::<i64, 37, 37>::try_from(b).unwrap();
+        for cutoff in [0, 4, 16, 64] {
+            assert_eq!(a.dot_product_strassen(&b, cutoff), a.dot_product(&b));
+        }
+
+        let a = DynMatrics::from([[1.5, 2.0, 0.5], [0.0, 1.0, 3.0], [2.0, 2.0, 1.0]]);
+        let b = DynMatrics::from([[1.0, 0.0, 2.0], [4.0, 1.0, 0.5], [0.0, 3.0, 1.0]]);
+        assert!(a
+            .dot_product_strassen(&b, 1)
+            .approx_eq(&a.dot_product(&b), 1e-12));
+    }
+
+    #[test]
+    fn test_uninitialized_output() {
+        // 内维为 0 时结果全部写为零
+        let a = Matrix::<i32, 2, 0>::default();
+        let b = Matrix::<i32, 0, 3>::default();
+        assert_eq!(a.dot_product(&b), Matrix::<i32, 2, 3>::default());
+        #[cfg(feature = "std")]
+        assert_eq!(
+            a.dot_product_in_parallel(&b, 4),
+            Matrix::<i32, 2, 3>::default()
+        );
+
+        // 需要析构的元素类型，跨越多个深度分块
+        use num_bigint::BigInt;
+        let a: Vec<BigInt> = (0..3 * 300).map(|i| BigInt::from(i % 5 - 2)).collect();
+        let b: Vec<BigInt> = (0..300 * 2).map(|i| BigInt::from(i % 3)).collect();
+        let a = DynMatrics::<BigInt, 3, 300>::try_from(a).unwrap();
+        let b = DynMatrics::<BigInt, 300, 2>::try_from(b).unwrap();
+        assert_eq!(a.dot_product(&b), a.dot_product_naive(&b));
+        #[cfg(feature = "std")]
+        assert_eq!(a.dot_product_in_parallel(&b, 5), a.dot_product_naive(&b));
+        let mut out = DynMatrics::<BigInt, 3, 2>::default();
+        a.dot_product_into(&b, &mut out);
+        assert_eq!(out, a.dot_product_naive(&b));
+    }
+}
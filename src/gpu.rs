@@ -0,0 +1,402 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+use std::sync::mpsc;
+
+use wgpu::util::DeviceExt;
+
+use crate::dynamic::DynMatrics;
+
+#[derive(Debug)]
+pub enum Error {
+    // 没有可用的 GPU 适配器（包括软件实现）
+    NoAdapter(wgpu::RequestAdapterError),
+    Device(wgpu::RequestDeviceError),
+    Map(wgpu::BufferAsyncError),
+    Poll(wgpu::PollError),
+    // 矩阵超出单次 dispatch 能处理的规模
+    TooLarge { rows: usize, cols: usize },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NoAdapter(err) => write!(f, "no gpu adapter: {}", err),
+            Error::Device(err) => write!(f, "failed to open gpu device: {}", err),
+            Error::Map(err) => write!(f, "failed to read back gpu buffer: {}", err),
+            Error::Poll(err) => write!(f, "failed to wait for gpu: {}", err),
+            Error::TooLarge { rows, cols } => write!(
+                f,
+                "{}x{} matrix is too large for a single gpu dispatch",
+                rows, cols
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::NoAdapter(err) => Some(err),
+            Error::Device(err) => Some(err),
+            Error::Map(err) => Some(err),
+            Error::Poll(err) => Some(err),
+            Error::TooLarge { .. } => None,
+        }
+    }
+}
+
+// 每个工作组 16×16 个线程，每个线程计算 C 的一个元素
+const MATMUL_SHADER: &str = r#"
+struct Dims {
+    m: u32,
+    k: u32,
+    n: u32,
+    op: u32,
+}
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> c: array<f32>;
+@group(0) @binding(3) var<uniform> dims: Dims;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let row = id.y;
+    let col = id.x;
+    if (row >= dims.m || col >= dims.n) {
+        return;
+    }
+    var sum = 0.0;
+    for (var k = 0u; k < dims.k; k = k + 1u) {
+        sum = sum + a[row * dims.k + k] * b[k * dims.n + col];
+    }
+    c[row * dims.n + col] = sum;
+}
+"#;
+
+// 一维下标按 x + y * 行宽 展开，避免单个维度超过工作组数量上限
+const ELEMENTWISE_SHADER: &str = r#"
+struct Dims {
+    m: u32,
+    k: u32,
+    n: u32,
+    op: u32,
+}
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> c: array<f32>;
+@group(0) @binding(3) var<uniform> dims: Dims;
+
+@compute @workgroup_size(256)
+fn main(
+    @builtin(global_invocation_id) id: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let i = id.x + id.y * groups.x * 256u;
+    if (i >= dims.m * dims.n) {
+        return;
+    }
+    switch dims.op {
+        case 0u: { c[i] = a[i] + b[i]; }
+        case 1u: { c[i] = a[i] - b[i]; }
+        default: { c[i] = a[i] * b[i]; }
+    }
+}
+"#;
+
+const MAX_GROUPS: u32 = 65535;
+
+// 着色器用 u32 计算下标，任何一个参与运算的矩阵元素个数都不能超过 u32::MAX
+fn element_count(rows: usize, cols: usize) -> Result<u32> {
+    rows.checked_mul(cols)
+        .and_then(|len| u32::try_from(len).ok())
+        .ok_or(Error::TooLarge { rows, cols })
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add = 0,
+    Sub = 1,
+    Mul = 2,
+}
+
+// 持有设备、队列和编译好的计算管线，创建一次后可反复使用
+pub struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    matmul: wgpu::ComputePipeline,
+    elementwise: wgpu::ComputePipeline,
+}
+
+impl GpuContext {
+    // 阻塞等待适配器和设备就绪；没有可用 GPU 时返回 Error::NoAdapter
+    pub fn new() -> Result<Self> {
+        pollster::block_on(Self::new_async())
+    }
+
+    async fn new_async() -> Result<Self> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .map_err(Error::NoAdapter)?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("matrix"),
+                required_limits: adapter.limits(),
+                ..Default::default()
+            })
+            .await
+            .map_err(Error::Device)?;
+        let matmul = pipeline(&device, "matmul", MATMUL_SHADER);
+        let elementwise = pipeline(&device, "elementwise", ELEMENTWISE_SHADER);
+        Ok(GpuContext {
+            device,
+            queue,
+            matmul,
+            elementwise,
+        })
+    }
+
+    pub fn dot_product<const X: usize, const Y: usize, const Z: usize>(
+        &self,
+        matrix0: &DynMatrics<f32, X, Y>,
+        matrix1: &DynMatrics<f32, Y, Z>,
+    ) -> Result<DynMatrics<f32, X, Z>> {
+        // wgpu 不允许绑定空缓冲区，结果为空或全零时不必上传
+        if X * Z == 0 || Y == 0 {
+            return Ok(DynMatrics::default());
+        }
+        element_count(X, Y)?;
+        element_count(Y, Z)?;
+        element_count(X, Z)?;
+        if X > 16 * MAX_GROUPS as usize || Z > 16 * MAX_GROUPS as usize {
+            return Err(Error::TooLarge { rows: X, cols: Z });
+        }
+        // 三个维度都不超过某个元素个数，转换为 u32 不会截断
+        let (m, k, n) = (X as u32, Y as u32, Z as u32);
+        let groups = (n.div_ceil(16), m.div_ceil(16));
+        let data = self.run(
+            &self.matmul,
+            matrix0.as_slice(),
+            matrix1.as_slice(),
+            [m, k, n, 0],
+            X * Z,
+            groups,
+        )?;
+        Ok(DynMatrics::try_from(data).expect("gpu output has exactly X * Z elements"))
+    }
+
+    pub fn add<const X: usize, const Y: usize>(
+        &self,
+        matrix0: &DynMatrics<f32, X, Y>,
+        matrix1: &DynMatrics<f32, X, Y>,
+    ) -> Result<DynMatrics<f32, X, Y>> {
+        self.elementwise(matrix0, matrix1, Op::Add)
+    }
+
+    pub fn sub<const X: usize, const Y: usize>(
+        &self,
+        matrix0: &DynMatrics<f32, X, Y>,
+        matrix1: &DynMatrics<f32, X, Y>,
+    ) -> Result<DynMatrics<f32, X, Y>> {
+        self.elementwise(matrix0, matrix1, Op::Sub)
+    }
+
+    // 逐元素相乘（Hadamard 积）
+    pub fn hadamard<const X: usize, const Y: usize>(
+        &self,
+        matrix0: &DynMatrics<f32, X, Y>,
+        matrix1: &DynMatrics<f32, X, Y>,
+    ) -> Result<DynMatrics<f32, X, Y>> {
+        self.elementwise(matrix0, matrix1, Op::Mul)
+    }
+
+    fn elementwise<const X: usize, const Y: usize>(
+        &self,
+        matrix0: &DynMatrics<f32, X, Y>,
+        matrix1: &DynMatrics<f32, X, Y>,
+        op: Op,
+    ) -> Result<DynMatrics<f32, X, Y>> {
+        if X * Y == 0 {
+            return Ok(DynMatrics::default());
+        }
+        let total = element_count(X, Y)?.div_ceil(256);
+        let groups = (total.min(MAX_GROUPS), total.div_ceil(MAX_GROUPS));
+        let data = self.run(
+            &self.elementwise,
+            matrix0.as_slice(),
+            matrix1.as_slice(),
+            [X as u32, 0, Y as u32, op as u32],
+            X * Y,
+            groups,
+        )?;
+        Ok(DynMatrics::try_from(data).expect("gpu output has exactly X * Y elements"))
+    }
+
+    // 上传 a、b，执行一次计算管线并把 len 个结果读回内存
+    fn run(
+        &self,
+        pipeline: &wgpu::ComputePipeline,
+        a: &[f32],
+        b: &[f32],
+        dims: [u32; 4],
+        len: usize,
+        groups: (u32, u32),
+    ) -> Result<Vec<f32>> {
+        let input = |label, data: &[f32]| {
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(label),
+                    contents: bytemuck::cast_slice(data),
+                    usage: wgpu::BufferUsages::STORAGE,
+                })
+        };
+        let a = input("a", a);
+        let b = input("b", b);
+        let dims = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("dims"),
+                contents: bytemuck::cast_slice(&dims),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let size = (len * std::mem::size_of::<f32>()) as wgpu::BufferAddress;
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("c"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("staging"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: a.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: b.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: output.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: dims.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(groups.0, groups.1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output, 0, &staging, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        let (sender, receiver) = mpsc::channel();
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(Error::Poll)?;
+        receiver
+            .recv()
+            .expect("map_async callback runs before poll returns")
+            .map_err(Error::Map)?;
+        let data =
+            bytemuck::cast_slice(&slice.get_mapped_range().expect("buffer is mapped")).to_vec();
+        staging.unmap();
+        Ok(data)
+    }
+}
+
+fn pipeline(device: &wgpu::Device, label: &str, source: &str) -> wgpu::ComputePipeline {
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some(label),
+        source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
+    });
+    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some(label),
+        layout: None,
+        module: &module,
+        entry_point: Some("main"),
+        compilation_options: Default::default(),
+        cache: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_too_large_for_dispatch() {
+        assert_eq!(element_count(3, 5).unwrap(), 15);
+        // 2^16 × 2^16 个元素超出 u32，不能被截断成 0
+        assert!(matches!(
+            element_count(1 << 16, 1 << 16),
+            Err(Error::TooLarge {
+                rows: 65536,
+                cols: 65536
+            })
+        ));
+        assert!(element_count(usize::MAX, 2).is_err());
+    }
+
+    // 需要可用的 GPU 适配器（包括软件实现），用 cargo test --features gpu -- --ignored 运行
+    #[test]
+    #[ignore = "requires a gpu adapter"]
+    fn test_gpu_dot_product() {
+        let gpu = GpuContext::new().unwrap();
+        let a = DynMatrics::<f32, 37, 19>::from_fn(|i, j| ((i * 7 + j) % 5) as f32 - 2.0);
+        let b = DynMatrics::<f32, 19, 23>::from_fn(|i, j| ((i + j * 3) % 4) as f32 * 0.5);
+        // 小整数和 0.5 的乘积累加在 f32 中是精确的
+        assert_eq!(gpu.dot_product(&a, &b).unwrap(), a.dot_product(&b));
+
+        let empty = DynMatrics::<f32, 3, 0>::default();
+        let result = gpu.dot_product(&empty, &DynMatrics::<f32, 0, 2>::default());
+        assert_eq!(result.unwrap(), DynMatrics::from([[0.0; 2]; 3]));
+    }
+
+    #[test]
+    #[ignore = "requires a gpu adapter"]
+    fn test_gpu_elementwise() {
+        let gpu = GpuContext::new().unwrap();
+        let a = DynMatrics::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = DynMatrics::from([[0.5, 1.0, 1.5], [2.0, 2.5, 3.0]]);
+        assert_eq!(
+            gpu.add(&a, &b).unwrap(),
+            DynMatrics::from([[1.5, 3.0, 4.5], [6.0, 7.5, 9.0]])
+        );
+        assert_eq!(
+            gpu.sub(&a, &b).unwrap(),
+            DynMatrics::from([[0.5, 1.0, 1.5], [2.0, 2.5, 3.0]])
+        );
+        assert_eq!(
+            gpu.hadamard(&a, &b).unwrap(),
+            DynMatrics::from([[0.5, 2.0, 4.5], [8.0, 12.5, 18.0]])
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// 以邻接矩阵表示的有向图算法：A[i][j] 表示从 i 到 j 的边
+use core::ops::{Add, Mul};
+
+use num_traits::{One, Zero};
+
+use crate::bitmatrix::BitMatrix;
+use crate::{GemmElement, Matrix};
+
+// 传递闭包：closure[i][j] 为真当且仅当存在从 i 到 j、长度至少为 1 的路径
+// 在布尔半环上反复计算 R ∨ R·R，每轮覆盖的路径长度翻倍，最多 ⌈log₂ N⌉ + 1 轮
+pub fn transitive_closure<const N: usize>(adjacency: &BitMatrix<N, N>) -> BitMatrix<N, N> {
+    let mut reach = adjacency.clone();
+    loop {
+        let next = &reach | &reach.dot_product(&reach);
+        if next == reach {
+            return reach;
+        }
+        reach = next;
+    }
+}
+
+// paths[i][j] 为从 i 到 j、恰好经过 k 条边的路径条数（允许重复经过顶点）
+pub fn count_paths<T, const N: usize>(adjacency: &Matrix<T, N, N>, k: u32) -> Matrix<T, N, N>
+where
+    T: Zero + Mul<Output = T> + Clone + One + GemmElement,
+{
+    adjacency.pow(k)
+}
+
+// None 表示 +∞，即没有路径
+fn min<T: PartialOrd>(x: Option<T>, y: Option<T>) -> Option<T> {
+    match (x, y) {
+        (Some(x), Some(y)) => Some(if y < x { y } else { x }),
+        (x, None) => x,
+        (None, y) => y,
+    }
+}
+
+// min-plus 半环上的乘积：result[i][j] = min_k (a[i][k] + b[k][j])
+// 当 a、b 都是距离矩阵时，结果为先走 a 中一段、再走 b 中一段的最短距离
+pub fn min_plus_product<T, const R: usize, const K: usize, const C: usize>(
+    a: &Matrix<Option<T>, R, K>,
+    b: &Matrix<Option<T>, K, C>,
+) -> Matrix<Option<T>, R, C>
+where
+    T: Add<Output = T> + PartialOrd + Clone,
+{
+    // None 表示 +∞，既是 min 的单位元，也使 + 的结果为 None
+    a.dot_product_semiring(b, min, |x, y| Some(x? + y?), None)
+}
+
+// Floyd–Warshall 全源最短路：weights[i][j] 为边权，None 表示没有边
+// 第 k 轮用经过顶点 k 的路径做一次 min-plus 秩一更新：D = min(D, D[:, k] ⊗ D[k, :])
+// 结果中 None 表示不可达；存在负环时环上顶点的对角线为负
+pub fn shortest_paths<T, const N: usize>(
+    weights: &Matrix<Option<T>, N, N>,
+) -> Matrix<Option<T>, N, N>
+where
+    T: Zero + PartialOrd + Clone,
+{
+    let mut dist = weights.clone();
+    for i in 0..N {
+        dist[i][i] = min(dist[i][i].clone(), Some(T::zero()));
+    }
+    for k in 0..N {
+        let through = Matrix::<Option<T>, N, 1>::from_fn(|i, _| dist[i][k].clone());
+        let from = Matrix::<Option<T>, 1, N>::from_fn(|_, j| dist[k][j].clone());
+        let relaxed = min_plus_product(&through, &from);
+        for i in 0..N {
+            for j in 0..N {
+                dist[i][j] = min(dist[i][j].clone(), relaxed[i][j].clone());
+            }
+        }
+    }
+    dist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closure_and_path_counts() {
+        // 0 → 1 → 2 → 0 构成环，3 → 0 单向
+        let edges = Matrix::from([[0, 1, 0, 0], [0, 0, 1, 0], [1, 0, 0, 0], [1, 0, 0, 0]]);
+        let bits = BitMatrix::from(&Matrix::from_fn(|i, j| edges[i][j] == 1));
+        let closure = Matrix::<bool, 4, 4>::from(&transitive_closure(&bits));
+        assert_eq!(closure[0], [true, true, true, false]);
+        assert_eq!(closure[3], [true, true, true, false]);
+
+        assert_eq!(count_paths(&edges, 0)[2], [0, 0, 1, 0]);
+        assert_eq!(count_paths(&edges, 3)[0], [1, 0, 0, 0]);
+        assert_eq!(count_paths(&edges, 5)[3], [0, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let weights = Matrix::from([
+            [None, Some(4), Some(1), None],
+            [None, None, None, Some(1)],
+            [None, Some(2), None, Some(5)],
+            [None, None, None, None],
+        ]);
+        let dist = shortest_paths(&weights);
+        assert_eq!(dist[0], [Some(0), Some(3), Some(1), Some(4)]);
+        assert_eq!(dist[3], [None, None, None, Some(0)]);
+        // 恰好两条边的最短距离
+        assert_eq!(
+            min_plus_product(&weights, &weights)[0],
+            [None, Some(3), None, Some(5)]
+        );
+    }
+}
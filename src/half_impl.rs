@@ -0,0 +1,118 @@
+use core::any::TypeId;
+use core::mem::transmute_copy;
+use core::ops::Range;
+
+use half::{bf16, f16};
+
+use crate::gemm::{self, Output};
+
+// T 为 f16/bf16 时在 f32 中累加计算 C = A·B 并返回 true，其他类型返回 false
+// 元素仍以半精度存放，只额外占用 A 的一行与 C 的一行 f32 作为临时缓冲
+// 半精度直接累加时，和超过 2048（f16）或 256（bf16）后再加 1 就会被舍入掉
+// 只计算 B 的 cols 列，C 为 m×cols.len() 行优先
+pub(crate) fn gemm<T, S>(
+    a: &[T],
+    b: &[T],
+    m: usize,
+    k: usize,
+    n: usize,
+    cols: Range<usize>,
+    c: &mut [S],
+) -> bool
+where
+    T: 'static,
+    S: Output<T>,
+{
+    if TypeId::of::<T>() == TypeId::of::<f16>() {
+        // SAFETY: T 就是 f16
+        let (a, b) = unsafe { (cast::<T, f16>(a), cast::<T, f16>(b)) };
+        widening_gemm(a, b, (m, k, n), cols, f16::from_f32, c);
+        true
+    } else if TypeId::of::<T>() == TypeId::of::<bf16>() {
+        // SAFETY: T 就是 bf16
+        let (a, b) = unsafe { (cast::<T, bf16>(a), cast::<T, bf16>(b)) };
+        widening_gemm(a, b, (m, k, n), cols, bf16::from_f32, c);
+        true
+    } else {
+        false
+    }
+}
+
+// SAFETY: 调用方保证 T 与 H 是同一类型
+unsafe fn cast<T, H>(slice: &[T]) -> &[H] {
+    unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+}
+
+// narrow 把 f32 的和舍入回半精度
+fn widening_gemm<H, T, S>(
+    a: &[H],
+    b: &[H],
+    (m, k, n): (usize, usize, usize),
+    cols: Range<usize>,
+    narrow: fn(f32) -> H,
+    c: &mut [S],
+) where
+    H: Copy + Into<f32> + 'static,
+    T: 'static,
+    S: Output<T>,
+{
+    assert!(TypeId::of::<H>() == TypeId::of::<T>());
+    // SAFETY: 上面已断言 H 与 T 是同一类型
+    let finish = |sum| unsafe { transmute_copy::<H, T>(&narrow(sum)) };
+    gemm::gemm_mixed(a, b, m, k, n, cols, finish, c);
+}
+
+#[cfg(test)]
+mod tests {
+    use half::{bf16, f16};
+
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_widening_accumulation() {
+        // 4096 个 1 相加：f16 直接累加会停在 2048
+        let a = Matrix::<f16, 1, 4096>::filled(f16::ONE);
+        let b = Matrix::<f16, 4096, 2>::filled(f16::ONE);
+        assert_eq!(a.dot_product(&b).as_slice(), [f16::from_f32(4096.0); 2]);
+
+        let a = DynMatrics::<bf16, 2, 512>::filled(bf16::ONE);
+        let b = DynMatrics::<bf16, 512, 1>::filled(bf16::ONE);
+        assert_eq!(a.dot_product(&b).as_slice(), [bf16::from_f32(512.0); 2]);
+
+        let m = Matrix::from([[1.5f32, -2.0], [0.25, 4.0]]);
+        let half = Matrix::<f16, 2, 2>::from_fn(|i, j| f16::from_f32(m[i][j]));
+        let product = half.dot_product(&half);
+        assert_eq!(
+            product
+                .as_slice()
+                .iter()
+                .map(|x| x.to_f32())
+                .collect::<Vec<_>>(),
+            m.dot_product(&m).as_slice()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_parallel_widening_accumulation() {
+        let a = Matrix::<f16, 2, 4096>::filled(f16::ONE);
+        let b = Matrix::<f16, 4096, 2>::filled(f16::ONE);
+        let expected = [f16::from_f32(4096.0); 4];
+        // 2 个线程按行切块，4 个线程时每行再按列切分，两条路径都要在 f32 中累加
+        for parallel in [2, 4] {
+            assert_eq!(a.dot_product_in_parallel(&b, parallel).as_slice(), expected);
+        }
+        let monitored = a
+            .dot_product_in_parallel_monitored(&b, 2, &crate::Monitor::new())
+            .unwrap();
+        assert_eq!(monitored.as_slice(), expected);
+
+        let a = DynMatrics::<bf16, 3, 512>::filled(bf16::ONE);
+        let b = DynMatrics::<bf16, 512, 5>::filled(bf16::ONE);
+        assert_eq!(
+            a.dot_product_in_parallel(&b, 8).as_slice(),
+            [bf16::from_f32(512.0); 15]
+        );
+    }
+}
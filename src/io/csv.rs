@@ -0,0 +1,321 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use super::{Error, Result};
+use crate::dynamic::DynMatrix;
+use crate::{MatrixBase, MatrixError, OwnedStorage, Storage};
+
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    delimiter: char,
+    has_header: bool,
+    column_names: Option<Vec<String>>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+            column_names: None,
+        }
+    }
+}
+
+impl CsvOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    // 读取时跳过首行；写入时输出列名（未指定时为列序号）
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    pub fn column_names<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        self.column_names = Some(names.into_iter().map(Into::into).collect());
+        self.has_header = true;
+        self
+    }
+}
+
+// cols 为 None 时列数取自第一条记录；返回行数、列数与行优先数据
+fn parse_records<T: FromStr, R: Read>(
+    reader: R,
+    options: &CsvOptions,
+    cols: Option<usize>,
+) -> Result<(usize, usize, Vec<T>)> {
+    let mut data = Vec::new();
+    let mut cols = cols;
+    let mut read_rows = 0;
+    let lines = BufReader::new(reader).lines().enumerate();
+    for (line_number, line) in lines.skip(options.has_header as usize) {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let start = data.len();
+        for field in line.split(options.delimiter) {
+            let field = field.trim();
+            let value = field.parse().map_err(|_| {
+                Error::Format(format!(
+                    "line {}: invalid value {:?}",
+                    line_number + 1,
+                    field
+                ))
+            })?;
+            data.push(value);
+        }
+        let expected = *cols.get_or_insert(data.len() - start);
+        if data.len() - start != expected {
+            return Err(Error::Format(format!(
+                "line {}: expected {} fields, got {}",
+                line_number + 1,
+                expected,
+                data.len() - start
+            )));
+        }
+        read_rows += 1;
+    }
+    Ok((read_rows, cols.unwrap_or(0), data))
+}
+
+fn read_records<T: FromStr, R: Read>(
+    reader: R,
+    options: &CsvOptions,
+    rows: usize,
+    cols: usize,
+) -> Result<Vec<T>> {
+    let (read_rows, _, data) = parse_records(reader, options, Some(cols))?;
+    if read_rows != rows {
+        return Err(MatrixError::DimensionMismatch {
+            expected: rows,
+            got: read_rows,
+        }
+        .into());
+    }
+    Ok(data)
+}
+
+// 编译期不知道尺寸时使用（如命令行工具）：返回行数、列数与行优先数据
+pub fn read_csv<T: FromStr, R: Read>(
+    reader: R,
+    options: &CsvOptions,
+) -> Result<(usize, usize, Vec<T>)> {
+    parse_records(reader, options, None)
+}
+
+// 把 cols 列的行优先数据写成 CSV
+pub fn write_csv<T: Display, W: Write>(
+    writer: W,
+    options: &CsvOptions,
+    cols: usize,
+    data: &[T],
+) -> Result<()> {
+    let complete_rows = match cols {
+        0 => data.is_empty(),
+        cols => data.len().is_multiple_of(cols),
+    };
+    if !complete_rows {
+        return Err(MatrixError::DimensionMismatch {
+            expected: cols,
+            got: data.len(),
+        }
+        .into());
+    }
+    write_records(writer, options, cols, data)
+}
+
+fn write_records<T: Display, W: Write>(
+    mut writer: W,
+    options: &CsvOptions,
+    cols: usize,
+    data: &[T],
+) -> Result<()> {
+    let delimiter = options.delimiter.to_string();
+    if options.has_header {
+        let header = match &options.column_names {
+            Some(names) if names.len() == cols => names.join(&delimiter),
+            Some(names) => {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: cols,
+                    got: names.len(),
+                }
+                .into())
+            }
+            None => (0..cols)
+                .map(|col| col.to_string())
+                .collect::<Vec<_>>()
+                .join(&delimiter),
+        };
+        writeln!(writer, "{}", header)?;
+    }
+    for row in data.chunks(cols.max(1)) {
+        for (col, value) in row.iter().enumerate() {
+            if col > 0 {
+                writer.write_all(delimiter.as_bytes())?;
+            }
+            write!(writer, "{}", value)?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn from_csv_reader<Rd: Read>(reader: Rd, options: &CsvOptions) -> Result<Self>
+    where
+        T: FromStr,
+        S: OwnedStorage<T>,
+    {
+        Ok(Self::try_from(read_records(reader, options, R, C)?)?)
+    }
+
+    pub fn write_csv<W: Write>(&self, writer: W, options: &CsvOptions) -> Result<()>
+    where
+        T: Display,
+    {
+        write_records(writer, options, C, self.as_slice())
+    }
+
+    pub fn load_csv(path: impl AsRef<Path>, options: &CsvOptions) -> Result<Self>
+    where
+        T: FromStr,
+        S: OwnedStorage<T>,
+    {
+        Self::from_csv_reader(File::open(path)?, options)
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>, options: &CsvOptions) -> Result<()>
+    where
+        T: Display,
+    {
+        self.write_csv(BufWriter::new(File::create(path)?), options)
+    }
+}
+
+// 行列数取自文件内容
+impl<T> DynMatrix<T> {
+    pub fn from_csv_reader<Rd: Read>(reader: Rd, options: &CsvOptions) -> Result<Self>
+    where
+        T: FromStr,
+    {
+        let (rows, cols, data) = read_csv(reader, options)?;
+        Ok(DynMatrix::new(rows, cols, data)?)
+    }
+
+    pub fn write_csv<W: Write>(&self, writer: W, options: &CsvOptions) -> Result<()>
+    where
+        T: Display,
+    {
+        write_records(writer, options, self.cols(), self.as_slice())
+    }
+
+    pub fn load_csv(path: impl AsRef<Path>, options: &CsvOptions) -> Result<Self>
+    where
+        T: FromStr,
+    {
+        Self::from_csv_reader(File::open(path)?, options)
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>, options: &CsvOptions) -> Result<()>
+    where
+        T: Display,
+    {
+        self.write_csv(BufWriter::new(File::create(path)?), options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_csv_round_trip() {
+        let m = Matrix::from([[1.5, 2.0], [3.0, -4.25]]);
+        let mut buffer = Vec::new();
+        m.write_csv(&mut buffer, &CsvOptions::new()).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "1.5,2\n3,-4.25\n"
+        );
+        let read = Matrix::<f64, 2, 2>::from_csv_reader(&buffer[..], &CsvOptions::new()).unwrap();
+        assert_eq!(read, m);
+    }
+
+    #[test]
+    fn test_csv_header_and_delimiter() {
+        let options = CsvOptions::new()
+            .delimiter(';')
+            .column_names(["a", "b", "c"]);
+        let m = DynMatrics::from([[1, 2, 3], [4, 5, 6]]);
+        let mut buffer = Vec::new();
+        m.write_csv(&mut buffer, &options).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "a;b;c\n1;2;3\n4;5;6\n"
+        );
+        let read = DynMatrics::<i32, 2, 3>::from_csv_reader(&buffer[..], &options).unwrap();
+        assert_eq!(read, m);
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let options = CsvOptions::new();
+        assert!(matches!(
+            Matrix::<i32, 2, 2>::from_csv_reader("1,2\n3\n".as_bytes(), &options),
+            Err(Error::Format(_))
+        ));
+        assert!(matches!(
+            Matrix::<i32, 2, 2>::from_csv_reader("1,2\n3,x\n".as_bytes(), &options),
+            Err(Error::Format(_))
+        ));
+        assert!(matches!(
+            Matrix::<i32, 2, 2>::from_csv_reader("1,2\n".as_bytes(), &options),
+            Err(Error::Matrix(MatrixError::DimensionMismatch { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_csv_runtime_shape() {
+        let options = CsvOptions::new().has_header(true);
+        let (rows, cols, data) =
+            read_csv::<f64, _>("x,y\n1,2\n\n3,4\n5,6\n".as_bytes(), &options).unwrap();
+        assert_eq!((rows, cols), (3, 2));
+        assert_eq!(data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(read_csv::<i32, _>("1,2\n3\n".as_bytes(), &CsvOptions::new()).is_err());
+        assert_eq!(
+            read_csv::<i32, _>("".as_bytes(), &CsvOptions::new()).unwrap(),
+            (0, 0, vec![])
+        );
+
+        let mut buffer = Vec::new();
+        write_csv(&mut buffer, &CsvOptions::new(), 3, &[1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "1,2,3\n4,5,6\n");
+        assert!(write_csv(Vec::new(), &CsvOptions::new(), 4, &[1, 2, 3, 4, 5, 6]).is_err());
+    }
+
+    #[test]
+    fn test_csv_runtime_matrix() {
+        let m = DynMatrix::<i32>::from_csv_reader("1,2,3\n4,5,6\n".as_bytes(), &CsvOptions::new())
+            .unwrap();
+        assert_eq!(m, DynMatrix::from([[1, 2, 3], [4, 5, 6]]));
+        let mut buffer = Vec::new();
+        m.transpose()
+            .write_csv(&mut buffer, &CsvOptions::new())
+            .unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "1,4\n2,5\n3,6\n");
+    }
+}
@@ -0,0 +1,273 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::marker::PhantomData;
+use std::ops::{Index, Mul};
+use std::path::Path;
+
+use bytemuck::Pod;
+use memmap2::{Mmap, MmapMut, MmapOptions};
+use num_traits::Zero;
+
+use super::{Error, Result};
+use crate::dynamic::DynMatrix;
+use crate::storage::BoxFamily;
+use crate::{GemmElement, MatrixBase, MatrixError, MatrixOps, Storage, StorageMut};
+
+// 文件内容就是元素的原始字节：本机字节序、行优先、没有文件头，与 as_bytes 的输出一致
+// 映射由操作系统按页换入换出，矩阵可以比内存大；作为 Matrix 的存储后端，
+// 乘法、转置等算法都能直接使用，按行分块的并行乘法每个线程只会访问自己那几行
+
+// 只读映射
+pub struct MmapStorage<T> {
+    map: Mmap,
+    _element: PhantomData<T>,
+}
+
+// 写时复制映射：修改只落在进程私有的页上，不会写回文件
+pub struct CowMmapStorage<T> {
+    map: MmapMut,
+    _element: PhantomData<T>,
+}
+
+pub type MmapMatrix<T, const R: usize, const C: usize> = MatrixBase<T, R, C, MmapStorage<T>>;
+pub type CowMmapMatrix<T, const R: usize, const C: usize> = MatrixBase<T, R, C, CowMmapStorage<T>>;
+
+impl<T: Pod> Storage<T> for MmapStorage<T> {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        bytemuck::cast_slice(&self.map)
+    }
+}
+
+impl<T: Pod> Storage<T> for CowMmapStorage<T> {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        bytemuck::cast_slice(&self.map)
+    }
+}
+
+impl<T: Pod> StorageMut<T> for CowMmapStorage<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        bytemuck::cast_slice_mut(&mut self.map)
+    }
+}
+
+// 映射从页边界开始，总是满足 T 的对齐；只需检查长度，字节数溢出时按 usize::MAX 报告
+fn check_len<T>(file: &File, len: usize) -> Result<()> {
+    let expected = len.saturating_mul(std::mem::size_of::<T>());
+    let got = file.metadata()?.len();
+    if got != expected as u64 {
+        return Err(Error::Matrix(MatrixError::DimensionMismatch {
+            expected,
+            got: got as usize,
+        }));
+    }
+    Ok(())
+}
+
+// 只读映射整个文件，长度必须正好是 len 个元素
+//
+// SAFETY: 调用方保证映射期间文件不被修改或截断
+unsafe fn map_file<T: Pod>(path: impl AsRef<Path>, len: usize) -> Result<MmapStorage<T>> {
+    let file = File::open(path)?;
+    check_len::<T>(&file, len)?;
+    Ok(MmapStorage {
+        map: Mmap::map(&file)?,
+        _element: PhantomData,
+    })
+}
+
+impl<T: Pod, const R: usize, const C: usize> MmapMatrix<T, R, C> {
+    /// 只读映射 [`save_raw`](MatrixBase::save_raw) 写出的文件。
+    ///
+    /// # Safety
+    ///
+    /// 映射期间文件不能被本进程或其它进程修改、截断；
+    /// 否则读到的元素未定义，截断后访问映射还会触发 SIGBUS。
+    pub unsafe fn open_mmap(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(MatrixBase::from_storage(map_file(path, R * C)?)?)
+    }
+}
+
+impl<T: Pod, const R: usize, const C: usize> CowMmapMatrix<T, R, C> {
+    /// 写时复制地映射文件，写入过的页会复制到内存中，不会写回文件。
+    ///
+    /// # Safety
+    ///
+    /// 与 [`MmapMatrix::open_mmap`] 相同：映射期间文件不能被修改或截断，
+    /// 尚未写入过的页仍然直接读取文件内容。
+    pub unsafe fn open_mmap_copy_on_write(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        check_len::<T>(&file, R * C)?;
+        let map = MmapOptions::new().map_copy(&file)?;
+        Ok(MatrixBase::from_storage(CowMmapStorage {
+            map,
+            _element: PhantomData,
+        })?)
+    }
+}
+
+// 行列数在运行时给出的只读映射矩阵，用于尺寸写在别处（如文件名或元数据）的数据
+pub struct DynMmapMatrix<T> {
+    rows: usize,
+    cols: usize,
+    storage: MmapStorage<T>,
+}
+
+impl<T: Pod> DynMmapMatrix<T> {
+    /// 只读映射 rows×cols 个元素的原始字节文件，文件长度不符或 rows * cols 溢出时报告 DimensionMismatch。
+    ///
+    /// # Safety
+    ///
+    /// 与 [`MmapMatrix::open_mmap`] 相同：映射期间文件不能被修改或截断。
+    pub unsafe fn open_mmap(path: impl AsRef<Path>, rows: usize, cols: usize) -> Result<Self> {
+        let len = rows.saturating_mul(cols);
+        Ok(DynMmapMatrix {
+            rows,
+            cols,
+            storage: map_file(path, len)?,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.storage.as_slice()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.as_slice()[row * self.cols + col])
+    }
+
+    // 复制到内存中
+    pub fn to_dyn(&self) -> DynMatrix<T> {
+        DynMatrix::new(self.rows, self.cols, self.as_slice().to_vec())
+            .expect("mapping has exactly rows * cols elements")
+    }
+
+    // 与 MatrixOps::try_dot_product 相同，右侧可以是任意存储，形状在运行时检查
+    pub fn try_dot_product<M>(&self, matrix1: &M) -> crate::Result<DynMatrix<T>>
+    where
+        M: MatrixOps<T> + ?Sized,
+        T: Zero + Mul<Output = T> + GemmElement,
+    {
+        crate::ops::dot_slices(
+            self.as_slice(),
+            self.shape(),
+            matrix1.as_slice(),
+            matrix1.shape(),
+        )
+    }
+}
+
+impl<T: Pod> Index<usize> for DynMmapMatrix<T> {
+    type Output = [T];
+
+    fn index(&self, row: usize) -> &[T] {
+        assert!(
+            row < self.rows,
+            "row {} out of bounds for {} rows",
+            row,
+            self.rows
+        );
+        &self.as_slice()[row * self.cols..(row + 1) * self.cols]
+    }
+}
+
+impl<T: Pod, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    // 写出可供 open_mmap 映射的原始字节文件
+    pub fn save_raw(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(bytemuck::cast_slice(self.as_slice()))?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+
+    #[test]
+    fn test_mmap_round_trip() {
+        let path = std::env::temp_dir().join(format!("matrix-mmap-{}.bin", std::process::id()));
+        let a = Matrix::<f64, 3, 2>::from_fn(|i, j| (i * 2 + j) as f64);
+        a.save_raw(&path).unwrap();
+
+        // SAFETY: 测试期间没有其它代码改动这个文件
+        let mapped = unsafe { MmapMatrix::<f64, 3, 2>::open_mmap(&path) }.unwrap();
+        assert_eq!(mapped.as_slice(), a.as_slice());
+        let b = Matrix::from([[1.0, 0.0, 2.0], [0.0, 1.0, 3.0]]);
+        assert_eq!(mapped.dot_product(&b), a.dot_product(&b));
+        assert_eq!(mapped.dot_product_in_parallel(&b, 2), a.dot_product(&b));
+
+        // 写时复制：修改不影响文件本身
+        let mut cow =
+            unsafe { CowMmapMatrix::<f64, 3, 2>::open_mmap_copy_on_write(&path) }.unwrap();
+        cow[0][0] = 100.0;
+        assert_eq!(cow[0][0], 100.0);
+        let reopened = unsafe { MmapMatrix::<f64, 3, 2>::open_mmap(&path) }.unwrap();
+        assert_eq!(reopened[0][0], 0.0);
+
+        assert!(matches!(
+            unsafe { MmapMatrix::<f64, 4, 2>::open_mmap(&path) },
+            Err(Error::Matrix(MatrixError::DimensionMismatch {
+                expected: 64,
+                got: 48
+            }))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_dyn_mmap() {
+        let path = std::env::temp_dir().join(format!("matrix-dyn-mmap-{}.bin", std::process::id()));
+        let a = Matrix::<i32, 2, 3>::from([[1, 2, 3], [4, 5, 6]]);
+        a.save_raw(&path).unwrap();
+
+        // SAFETY: 测试期间没有其它代码改动这个文件
+        let mapped = unsafe { DynMmapMatrix::<i32>::open_mmap(&path, 2, 3) }.unwrap();
+        assert_eq!(mapped.shape(), (2, 3));
+        assert_eq!(mapped[1], [4, 5, 6]);
+        assert_eq!((mapped.get(0, 2), mapped.get(2, 0)), (Some(&3), None));
+        assert_eq!(mapped.to_dyn(), DynMatrix::from([[1, 2, 3], [4, 5, 6]]));
+        let b = DynMatrix::from([[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(
+            mapped.try_dot_product(&b).unwrap(),
+            a.try_dot_product(&b).unwrap()
+        );
+        assert!(mapped.try_dot_product(&a).is_err());
+
+        // 同一个文件也可以按 3×2 解读；长度不符或行列数溢出时报错
+        let reshaped = unsafe { DynMmapMatrix::<i32>::open_mmap(&path, 3, 2) }.unwrap();
+        assert_eq!(reshaped[2], [5, 6]);
+        assert!(matches!(
+            unsafe { DynMmapMatrix::<i32>::open_mmap(&path, 3, 3) },
+            Err(Error::Matrix(MatrixError::DimensionMismatch {
+                expected: 36,
+                got: 24
+            }))
+        ));
+        assert!(matches!(
+            unsafe { DynMmapMatrix::<i32>::open_mmap(&path, usize::MAX, 2) },
+            Err(Error::Matrix(MatrixError::DimensionMismatch {
+                expected: usize::MAX,
+                got: 24
+            }))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
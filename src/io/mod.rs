@@ -0,0 +1,52 @@
+pub mod csv;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod mtx;
+pub mod npy;
+#[cfg(feature = "stream")]
+pub mod stream;
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::MatrixError;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Format(String),
+    Matrix(MatrixError),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "io error: {}", err),
+            Error::Format(msg) => write!(f, "format error: {}", msg),
+            Error::Matrix(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Format(_) => None,
+            Error::Matrix(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<MatrixError> for Error {
+    fn from(err: MatrixError) -> Self {
+        Error::Matrix(err)
+    }
+}
@@ -0,0 +1,431 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use num_traits::Zero;
+
+use super::{Error, Result};
+use crate::{MatrixBase, MatrixError, OwnedStorage, Storage};
+
+const BANNER: &str = "%%MatrixMarket";
+
+pub trait MtxElement: FromStr + Display {
+    const FIELD: &'static str;
+}
+
+macro_rules! impl_mtx_element {
+    ($field:expr => $($ty:ty),*) => {
+        $(
+            impl MtxElement for $ty {
+                const FIELD: &'static str = $field;
+            }
+        )*
+    };
+}
+
+impl_mtx_element!("integer" => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_mtx_element!("real" => f32, f64);
+
+// 坐标格式的 (行, 列, 值) 三元组，索引从 0 开始，对称存储已展开
+// 重复的坐标原样保留，按格式规定在转换为稠密或 CSR 矩阵时累加
+#[derive(Debug, Clone, PartialEq)]
+pub struct MtxTriplets<T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub entries: Vec<(usize, usize, T)>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+enum Parsed<T> {
+    Array {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    },
+    Coordinate(MtxTriplets<T>),
+}
+
+fn format_error(line: usize, msg: impl Display) -> Error {
+    Error::Format(format!("line {}: {}", line, msg))
+}
+
+fn parse_value<T: FromStr>(token: &str, line: usize, negate: bool) -> Result<T> {
+    // 反对称矩阵在文本层面取负，避免对 T 增加 Neg 约束
+    let negated;
+    let token = match (negate, token.strip_prefix('-')) {
+        (false, _) => token,
+        (true, Some(positive)) => positive,
+        (true, None) => {
+            negated = format!("-{}", token);
+            &negated
+        }
+    };
+    token
+        .parse()
+        .map_err(|_| format_error(line, format!("invalid value {:?}", token)))
+}
+
+fn parse_usize(token: Option<&str>, line: usize) -> Result<usize> {
+    let token = token.ok_or_else(|| format_error(line, "missing field"))?;
+    token
+        .parse()
+        .map_err(|_| format_error(line, format!("invalid integer {:?}", token)))
+}
+
+// 形状来自文件头，乘积溢出时返回 Format
+fn element_count(rows: usize, cols: usize, line: usize) -> Result<usize> {
+    rows.checked_mul(cols)
+        .ok_or_else(|| format_error(line, format!("{}×{} matrix is too large", rows, cols)))
+}
+
+// expected 为要求的形状，在读取数据之前检查，不符时返回 DimensionMismatch
+fn parse<T: FromStr + Clone, R: Read>(
+    reader: R,
+    expected: Option<(usize, usize)>,
+) -> Result<Parsed<T>> {
+    let mut lines = BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line));
+
+    let banner = match lines.next() {
+        Some((_, line)) => line?.to_ascii_lowercase(),
+        None => return Err(Error::Format("empty matrix market file".to_string())),
+    };
+    let header: Vec<&str> = banner.split_whitespace().collect();
+    if header.len() != 5 || header[0] != BANNER.to_ascii_lowercase() || header[1] != "matrix" {
+        return Err(format_error(1, "invalid matrix market banner"));
+    }
+    let coordinate = match header[2] {
+        "coordinate" => true,
+        "array" => false,
+        format => return Err(format_error(1, format!("unsupported format {}", format))),
+    };
+    let pattern = match header[3] {
+        "real" | "integer" | "double" => false,
+        "pattern" if coordinate => true,
+        field => return Err(format_error(1, format!("unsupported field {}", field))),
+    };
+    let symmetry = match header[4] {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        symmetry => {
+            return Err(format_error(
+                1,
+                format!("unsupported symmetry {}", symmetry),
+            ))
+        }
+    };
+
+    let mut data_lines = lines.filter(|(_, line)| {
+        line.as_ref()
+            .map(|line| !line.trim().is_empty() && !line.starts_with('%'))
+            .unwrap_or(true)
+    });
+    let (size_line, size) = data_lines
+        .next()
+        .ok_or_else(|| Error::Format("missing size line".to_string()))?;
+    let size = size?;
+    let mut size = size.split_whitespace();
+    let rows = parse_usize(size.next(), size_line)?;
+    let cols = parse_usize(size.next(), size_line)?;
+    if let Some((expected_rows, expected_cols)) = expected {
+        for (expected, got) in [(expected_rows, rows), (expected_cols, cols)] {
+            if expected != got {
+                return Err(MatrixError::DimensionMismatch { expected, got }.into());
+            }
+        }
+    }
+    if symmetry != Symmetry::General && rows != cols {
+        return Err(format_error(
+            size_line,
+            format!("{}×{} matrix cannot be symmetric", rows, cols),
+        ));
+    }
+
+    if coordinate {
+        // nnz 同样来自文件头，不按它预先分配
+        let nnz = parse_usize(size.next(), size_line)?;
+        let mut entries = Vec::new();
+        for _ in 0..nnz {
+            let (line_number, line) = data_lines
+                .next()
+                .ok_or_else(|| Error::Format(format!("expected {} entries", nnz)))?;
+            let line = line?;
+            let mut tokens = line.split_whitespace();
+            let i = parse_usize(tokens.next(), line_number)?;
+            let j = parse_usize(tokens.next(), line_number)?;
+            if i == 0 || j == 0 || i > rows || j > cols {
+                return Err(format_error(line_number, "index out of bounds"));
+            }
+            let token = if pattern { Some("1") } else { tokens.next() };
+            let token = token.ok_or_else(|| format_error(line_number, "missing value"))?;
+            let value: T = parse_value(token, line_number, false)?;
+            if symmetry != Symmetry::General && i != j {
+                let mirrored =
+                    parse_value(token, line_number, symmetry == Symmetry::SkewSymmetric)?;
+                entries.push((j - 1, i - 1, mirrored));
+            }
+            entries.push((i - 1, j - 1, value));
+        }
+        Ok(Parsed::Coordinate(MtxTriplets {
+            rows,
+            cols,
+            entries,
+        }))
+    } else {
+        // 数组格式按列优先存储；对称矩阵只存储下三角
+        // 先读出全部元素再分配 rows×cols 的缓冲区，数据不足时不会按文件头的形状分配
+        let len = element_count(rows, cols, size_line)?;
+        let mut entries = Vec::new();
+        for j in 0..cols {
+            let start = if symmetry == Symmetry::General { 0 } else { j };
+            let start = start + (symmetry == Symmetry::SkewSymmetric) as usize;
+            for i in start..rows {
+                let (line_number, line) = data_lines
+                    .next()
+                    .ok_or_else(|| Error::Format("not enough array entries".to_string()))?;
+                let line = line?;
+                let token = line.trim();
+                entries.push((i, j, parse_value(token, line_number, false)?));
+                if symmetry != Symmetry::General && i != j {
+                    let mirrored =
+                        parse_value(token, line_number, symmetry == Symmetry::SkewSymmetric)?;
+                    entries.push((j, i, mirrored));
+                }
+            }
+        }
+        let mut slots: Vec<Option<T>> = vec![None; len];
+        for (i, j, value) in entries {
+            slots[i * cols + j] = Some(value);
+        }
+        if symmetry == Symmetry::SkewSymmetric {
+            for k in 0..rows.min(cols) {
+                slots[k * cols + k] = Some(parse_value("0", 0, false)?);
+            }
+        }
+        let data = slots
+            .into_iter()
+            .map(|slot| slot.ok_or_else(|| Error::Format("incomplete array".to_string())))
+            .collect::<Result<Vec<T>>>()?;
+        Ok(Parsed::Array { rows, cols, data })
+    }
+}
+
+fn read_dense<T: FromStr + Clone + Zero, R: Read>(
+    reader: R,
+    rows: usize,
+    cols: usize,
+) -> Result<Vec<T>> {
+    // 形状在 parse 中已与 rows×cols 比较过
+    match parse(reader, Some((rows, cols)))? {
+        Parsed::Array { data, .. } => Ok(data),
+        Parsed::Coordinate(triplets) => {
+            let mut data = vec![T::zero(); element_count(rows, cols, 0)?];
+            for (i, j, value) in triplets.entries {
+                let slot = &mut data[i * cols + j];
+                *slot = slot.clone() + value;
+            }
+            Ok(data)
+        }
+    }
+}
+
+fn write_array<T: MtxElement, W: Write>(
+    mut writer: W,
+    rows: usize,
+    cols: usize,
+    data: &[T],
+) -> Result<()> {
+    writeln!(writer, "{} matrix array {} general", BANNER, T::FIELD)?;
+    writeln!(writer, "{} {}", rows, cols)?;
+    for j in 0..cols {
+        for i in 0..rows {
+            writeln!(writer, "{}", data[i * cols + j])?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+impl<T: FromStr + Clone> MtxTriplets<T> {
+    pub fn read<R: Read>(reader: R) -> Result<Self> {
+        match parse(reader, None)? {
+            Parsed::Coordinate(triplets) => Ok(triplets),
+            Parsed::Array { rows, cols, data } => Ok(MtxTriplets {
+                rows,
+                cols,
+                entries: data
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, value)| (index / cols, index % cols, value))
+                    .collect(),
+            }),
+        }
+    }
+}
+
+impl<T: MtxElement> MtxTriplets<T> {
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "{} matrix coordinate {} general", BANNER, T::FIELD)?;
+        writeln!(writer, "{} {} {}", self.rows, self.cols, self.entries.len())?;
+        for (i, j, value) in &self.entries {
+            writeln!(writer, "{} {} {}", i + 1, j + 1, value)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn read_mtx<Rd: Read>(reader: Rd) -> Result<Self>
+    where
+        T: FromStr + Clone + Zero,
+        S: OwnedStorage<T>,
+    {
+        Ok(Self::try_from(read_dense(reader, R, C)?)?)
+    }
+
+    pub fn write_mtx<W: Write>(&self, writer: W) -> Result<()>
+    where
+        T: MtxElement,
+    {
+        write_array(writer, R, C, self.as_slice())
+    }
+
+    pub fn load_mtx(path: impl AsRef<Path>) -> Result<Self>
+    where
+        T: FromStr + Clone + Zero,
+        S: OwnedStorage<T>,
+    {
+        Self::read_mtx(File::open(path)?)
+    }
+
+    pub fn save_mtx(&self, path: impl AsRef<Path>) -> Result<()>
+    where
+        T: MtxElement,
+    {
+        self.write_mtx(BufWriter::new(File::create(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_array_round_trip() {
+        let m = Matrix::from([[1.5, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut buffer = Vec::new();
+        m.write_mtx(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert!(text.starts_with("%%MatrixMarket matrix array real general\n2 3\n1.5\n4\n2\n"));
+        assert_eq!(Matrix::<f64, 2, 3>::read_mtx(&buffer[..]).unwrap(), m);
+    }
+
+    #[test]
+    fn test_coordinate_symmetric() {
+        let text = "%%MatrixMarket matrix coordinate integer symmetric\n\
+                    % comment\n\
+                    3 3 3\n\
+                    1 1 4\n\
+                    3 1 -2\n\
+                    2 2 5\n";
+        let m = DynMatrics::<i32, 3, 3>::read_mtx(text.as_bytes()).unwrap();
+        assert_eq!(m, DynMatrics::from([[4, 0, -2], [0, 5, 0], [-2, 0, 0]]));
+
+        let text = "%%MatrixMarket matrix coordinate integer symmetric\n2 3 1\n1 1 4\n";
+        assert!(matches!(
+            MtxTriplets::<i32>::read(text.as_bytes()),
+            Err(Error::Format(_))
+        ));
+        let text = "%%MatrixMarket matrix array real symmetric\n3 2\n1\n2\n3\n4\n5\n";
+        assert!(matches!(
+            DynMatrics::<f64, 3, 2>::read_mtx(text.as_bytes()),
+            Err(Error::Format(_))
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_entries_are_summed() {
+        let text = "%%MatrixMarket matrix coordinate real general\n2 2 3\n1 2 1.5\n2 1 1\n1 2 2\n";
+        let m = Matrix::<f64, 2, 2>::read_mtx(text.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[0.0, 3.5], [1.0, 0.0]]));
+        // 三元组保留重复项
+        let triplets = MtxTriplets::<f64>::read(text.as_bytes()).unwrap();
+        assert_eq!(triplets.entries.len(), 3);
+    }
+
+    #[test]
+    fn test_skew_symmetric_and_pattern() {
+        let text = "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n2 1 -1.5\n";
+        let m = Matrix::<f64, 2, 2>::read_mtx(text.as_bytes()).unwrap();
+        assert_eq!(m, Matrix::from([[0.0, 1.5], [-1.5, 0.0]]));
+
+        let text = "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n";
+        let triplets = MtxTriplets::<u8>::read(text.as_bytes()).unwrap();
+        assert_eq!(triplets.entries, vec![(0, 2, 1), (1, 0, 1)]);
+    }
+
+    #[test]
+    fn test_triplets_round_trip() {
+        let triplets = MtxTriplets {
+            rows: 4,
+            cols: 5,
+            entries: vec![(0, 0, 1.0), (3, 4, -2.5)],
+        };
+        let mut buffer = Vec::new();
+        triplets.write(&mut buffer).unwrap();
+        assert_eq!(MtxTriplets::read(&buffer[..]).unwrap(), triplets);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let text = "%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1.0\n";
+        assert!(matches!(
+            MtxTriplets::<f64>::read(text.as_bytes()),
+            Err(Error::Format(_))
+        ));
+        let text = "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n4\n";
+        assert!(matches!(
+            Matrix::<f64, 3, 2>::read_mtx(text.as_bytes()),
+            Err(Error::Matrix(MatrixError::DimensionMismatch { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_huge_header() {
+        // 文件头声明的 nnz 或形状远大于实际数据，或行列数的乘积溢出
+        for text in [
+            "%%MatrixMarket matrix coordinate real general\n1 1 99999999999999\n1 1 1.0\n",
+            "%%MatrixMarket matrix array real general\n100000 100000\n1\n2\n",
+            "%%MatrixMarket matrix array real general\n18446744073709551615 2\n1\n",
+        ] {
+            assert!(matches!(
+                MtxTriplets::<f64>::read(text.as_bytes()),
+                Err(Error::Format(_))
+            ));
+        }
+        // 形状与要求的不符时在读取数据之前报告，不会按文件头的形状构造稠密矩阵
+        let text =
+            "%%MatrixMarket matrix coordinate real general\n4294967296 4294967296 1\n1 1 1.0\n";
+        assert!(matches!(
+            Matrix::<f64, 2, 2>::read_mtx(text.as_bytes()),
+            Err(Error::Matrix(MatrixError::DimensionMismatch {
+                expected: 2,
+                got: 4294967296
+            }))
+        ));
+    }
+}
@@ -0,0 +1,469 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use super::{Error, Result};
+use crate::{MatrixBase, MatrixError, OwnedStorage, Storage};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+const HEADER_ALIGN: usize = 64;
+
+pub trait NpyElement: Copy {
+    // 小端 dtype 描述符，如 "<f8"
+    const DESCR: &'static str;
+    const SIZE: usize;
+
+    fn decode(bytes: &[u8], big_endian: bool) -> Self;
+    fn encode(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($($ty:ty => $descr:expr),* $(,)?) => {
+        $(
+            impl NpyElement for $ty {
+                const DESCR: &'static str = $descr;
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn decode(bytes: &[u8], big_endian: bool) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    if big_endian {
+                        <$ty>::from_be_bytes(bytes)
+                    } else {
+                        <$ty>::from_le_bytes(bytes)
+                    }
+                }
+
+                fn encode(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_npy_element!(
+    i8 => "|i1", u8 => "|u1",
+    i16 => "<i2", u16 => "<u2",
+    i32 => "<i4", u32 => "<u4",
+    i64 => "<i8", u64 => "<u8",
+    f32 => "<f4", f64 => "<f8",
+);
+
+impl NpyElement for bool {
+    const DESCR: &'static str = "|b1";
+    const SIZE: usize = 1;
+
+    fn decode(bytes: &[u8], _big_endian: bool) -> Self {
+        bytes[0] != 0
+    }
+
+    fn encode(self, out: &mut Vec<u8>) {
+        out.push(self as u8);
+    }
+}
+
+// 按行优先存储的二维数组，作为 .npy 数据与矩阵类型之间的中间表示
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpyArray<T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<T>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NpyView<'a, T> {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: &'a [T],
+}
+
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+fn dict_value<'a>(header: &'a str, key: &str) -> Result<&'a str> {
+    let key = format!("'{}':", key);
+    let start = header
+        .find(&key)
+        .ok_or_else(|| Error::Format(format!("missing {} in npy header", key)))?;
+    Ok(header[start + key.len()..].trim_start())
+}
+
+fn parse_header(header: &str) -> Result<Header> {
+    let descr = dict_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|rest| rest.split('\'').next())
+        .ok_or_else(|| Error::Format(format!("invalid descr: {}", descr)))?
+        .to_string();
+
+    let fortran_order = dict_value(header, "fortran_order")?.starts_with("True");
+
+    let shape = dict_value(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|rest| rest.split(')').next())
+        .ok_or_else(|| Error::Format(format!("invalid shape: {}", shape)))?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse()
+                .map_err(|_| Error::Format(format!("invalid dimension: {}", dim)))
+        })
+        .collect::<Result<Vec<usize>>>()?;
+
+    Ok(Header {
+        descr,
+        fortran_order,
+        shape,
+    })
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<Header> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return Err(Error::Format("not an npy file".to_string()));
+    }
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        version => {
+            return Err(Error::Format(format!(
+                "unsupported npy version {}",
+                version
+            )))
+        }
+    };
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header)?;
+    let header = String::from_utf8(header)
+        .map_err(|_| Error::Format("npy header is not valid text".to_string()))?;
+    parse_header(&header)
+}
+
+impl<T: NpyElement> NpyArray<T> {
+    // 一维数组 (n,) 按 1×n 的行向量读取；形状来自文件头，数据不足时返回 Format 而不是按形状预先分配
+    pub fn read<R: Read>(mut reader: R) -> Result<Self> {
+        let header = read_header(&mut reader)?;
+        if header.descr.get(1..) != T::DESCR.get(1..) {
+            return Err(Error::Format(format!(
+                "dtype mismatch: expected {}, got {}",
+                T::DESCR,
+                header.descr
+            )));
+        }
+        let big_endian = header.descr.starts_with('>');
+        let (rows, cols) = match header.shape[..] {
+            [rows, cols] => (rows, cols),
+            [len] => (1, len),
+            _ => {
+                return Err(Error::Format(format!(
+                    "expected a 2-dimensional array, got shape {:?}",
+                    header.shape
+                )))
+            }
+        };
+
+        let len = rows
+            .checked_mul(cols)
+            .and_then(|n| n.checked_mul(T::SIZE))
+            .ok_or_else(|| Error::Format(format!("shape {:?} is too large", header.shape)))?;
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(Error::Format(format!(
+                "expected {} bytes of data, got {}",
+                len,
+                bytes.len()
+            )));
+        }
+        let elements: Vec<T> = bytes
+            .chunks_exact(T::SIZE)
+            .map(|chunk| T::decode(chunk, big_endian))
+            .collect();
+
+        let data = if header.fortran_order {
+            (0..rows * cols)
+                .map(|index| elements[(index % cols) * rows + index / cols])
+                .collect()
+        } else {
+            elements
+        };
+        Ok(NpyArray { rows, cols, data })
+    }
+}
+
+impl<T: NpyElement> NpyView<'_, T> {
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        if self.data.len() != self.rows * self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.rows * self.cols,
+                got: self.data.len(),
+            }
+            .into());
+        }
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            T::DESCR,
+            self.rows,
+            self.cols
+        );
+        // 魔数(6) + 版本(2) + 长度(2) + 头部，以换行结尾并按 64 字节对齐
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        let padding = (HEADER_ALIGN - unpadded % HEADER_ALIGN) % HEADER_ALIGN;
+        header.extend(std::iter::repeat_n(' ', padding));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+
+        let mut bytes = Vec::with_capacity(self.data.len() * T::SIZE);
+        for &element in self.data {
+            element.encode(&mut bytes);
+        }
+        writer.write_all(&bytes)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> TryFrom<NpyArray<T>>
+    for MatrixBase<T, R, C, S>
+{
+    type Error = MatrixError;
+
+    fn try_from(array: NpyArray<T>) -> std::result::Result<Self, MatrixError> {
+        if array.rows != R {
+            return Err(MatrixError::DimensionMismatch {
+                expected: R,
+                got: array.rows,
+            });
+        }
+        if array.cols != C {
+            return Err(MatrixError::DimensionMismatch {
+                expected: C,
+                got: array.cols,
+            });
+        }
+        Self::try_from(array.data)
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize, S: Storage<T>> From<&'a MatrixBase<T, R, C, S>>
+    for NpyView<'a, T>
+{
+    fn from(matrix: &'a MatrixBase<T, R, C, S>) -> Self {
+        NpyView {
+            rows: R,
+            cols: C,
+            data: matrix.as_slice(),
+        }
+    }
+}
+
+impl<T: NpyElement, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn read_npy<Rd: Read>(reader: Rd) -> Result<Self>
+    where
+        S: OwnedStorage<T>,
+    {
+        Ok(Self::try_from(NpyArray::read(reader)?)?)
+    }
+
+    pub fn write_npy<W: Write>(&self, writer: W) -> Result<()> {
+        NpyView::from(self).write(writer)
+    }
+
+    pub fn load_npy(path: impl AsRef<Path>) -> Result<Self>
+    where
+        S: OwnedStorage<T>,
+    {
+        Self::read_npy(BufReader::new(File::open(path)?))
+    }
+
+    pub fn save_npy(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.write_npy(BufWriter::new(File::create(path)?))
+    }
+}
+
+#[cfg(feature = "npz")]
+pub use npz::{NpzReader, NpzWriter};
+
+#[cfg(feature = "npz")]
+mod npz {
+    use std::fs::File;
+    use std::io::{BufReader, BufWriter, Read, Seek, Write};
+    use std::path::Path;
+
+    use zip::result::ZipError;
+    use zip::write::SimpleFileOptions;
+    use zip::{ZipArchive, ZipWriter};
+
+    use super::{NpyArray, NpyElement, NpyView};
+    use crate::io::{Error, Result};
+
+    impl From<ZipError> for Error {
+        fn from(err: ZipError) -> Self {
+            match err {
+                ZipError::Io(err) => Error::Io(err),
+                err => Error::Format(err.to_string()),
+            }
+        }
+    }
+
+    // .npz 是由多个 .npy 组成的 zip 归档，条目名为 "<name>.npy"
+    pub struct NpzReader<R> {
+        archive: ZipArchive<R>,
+    }
+
+    impl NpzReader<BufReader<File>> {
+        pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+            Self::new(BufReader::new(File::open(path)?))
+        }
+    }
+
+    impl<R: Read + Seek> NpzReader<R> {
+        pub fn new(reader: R) -> Result<Self> {
+            Ok(NpzReader {
+                archive: ZipArchive::new(reader)?,
+            })
+        }
+
+        pub fn names(&self) -> Vec<String> {
+            self.archive
+                .file_names()
+                .filter_map(|name| name.ok())
+                .map(|name| name.strip_suffix(".npy").unwrap_or(&name).to_string())
+                .collect()
+        }
+
+        pub fn read<T: NpyElement>(&mut self, name: &str) -> Result<NpyArray<T>> {
+            NpyArray::read(self.archive.by_name(&format!("{}.npy", name))?)
+        }
+    }
+
+    pub struct NpzWriter<W: Write + Seek> {
+        zip: ZipWriter<W>,
+    }
+
+    impl NpzWriter<BufWriter<File>> {
+        pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+            Ok(Self::new(BufWriter::new(File::create(path)?)))
+        }
+    }
+
+    impl<W: Write + Seek> NpzWriter<W> {
+        pub fn new(writer: W) -> Self {
+            NpzWriter {
+                zip: ZipWriter::new(writer),
+            }
+        }
+
+        pub fn add<'a, T: NpyElement + 'a>(
+            &mut self,
+            name: &str,
+            array: impl Into<NpyView<'a, T>>,
+        ) -> Result<()> {
+            self.zip
+                .start_file(format!("{}.npy", name), SimpleFileOptions::default())?;
+            array.into().write(&mut self.zip)
+        }
+
+        pub fn finish(self) -> Result<W> {
+            Ok(self.zip.finish()?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_npy_round_trip() {
+        let m = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut buffer = Vec::new();
+        m.write_npy(&mut buffer).unwrap();
+        assert_eq!(buffer.len() % HEADER_ALIGN, (6 * 8) % HEADER_ALIGN);
+        assert_eq!(Matrix::<f64, 2, 3>::read_npy(&buffer[..]).unwrap(), m);
+    }
+
+    #[test]
+    fn test_npy_fortran_big_endian() {
+        let header = "{'descr': '>i4', 'fortran_order': True, 'shape': (2, 3), }\n";
+        let mut buffer = MAGIC.to_vec();
+        buffer.extend_from_slice(&[1, 0]);
+        buffer.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        buffer.extend_from_slice(header.as_bytes());
+        for value in [1i32, 4, 2, 5, 3, 6] {
+            buffer.extend_from_slice(&value.to_be_bytes());
+        }
+        let m = DynMatrics::<i32, 2, 3>::read_npy(Cursor::new(buffer)).unwrap();
+        assert_eq!(m, DynMatrics::from([[1, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    fn test_npy_mismatch() {
+        let mut buffer = Vec::new();
+        Matrix::from([[1i64, 2]]).write_npy(&mut buffer).unwrap();
+        assert!(matches!(
+            Matrix::<i32, 1, 2>::read_npy(&buffer[..]),
+            Err(Error::Format(_))
+        ));
+        assert!(matches!(
+            Matrix::<i64, 2, 1>::read_npy(&buffer[..]),
+            Err(Error::Matrix(MatrixError::DimensionMismatch { .. }))
+        ));
+
+        // 文件头声明的形状远大于实际数据，或乘积溢出
+        for shape in ["(100000, 100000)", "(18446744073709551615, 2)"] {
+            let header = format!(
+                "{{'descr': '<i4', 'fortran_order': False, 'shape': {}, }}\n",
+                shape
+            );
+            let mut buffer = MAGIC.to_vec();
+            buffer.extend_from_slice(&[1, 0]);
+            buffer.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            buffer.extend_from_slice(header.as_bytes());
+            buffer.extend_from_slice(&[0; 16]);
+            assert!(matches!(
+                NpyArray::<i32>::read(&buffer[..]),
+                Err(Error::Format(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "npz")]
+    #[test]
+    fn test_npz_round_trip() {
+        let a = Matrix::from([[1.0f32, 2.0], [3.0, 4.0]]);
+        let b = DynMatrics::from([[1u8, 2, 3]]);
+        let mut writer = NpzWriter::new(Cursor::new(Vec::new()));
+        writer.add("a", &a).unwrap();
+        writer.add("b", &b).unwrap();
+        let buffer = writer.finish().unwrap();
+
+        let mut reader = NpzReader::new(buffer).unwrap();
+        assert_eq!(reader.names(), vec!["a", "b"]);
+        let read_a: Matrix<f32, 2, 2> = reader.read("a").unwrap().try_into().unwrap();
+        let read_b: DynMatrics<u8, 1, 3> = reader.read("b").unwrap().try_into().unwrap();
+        assert_eq!(read_a, a);
+        assert_eq!(read_b, b);
+    }
+}
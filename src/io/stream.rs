@@ -0,0 +1,221 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::ops::{Mul, Range};
+use std::path::Path;
+
+use bytemuck::Pod;
+use num_traits::Zero;
+
+use super::Result;
+use crate::parallel;
+
+// 核外矩阵乘法：A（m×k）、B（k×n）与结果 C 都是原始字节格式（本机字节序、行优先，与 save_raw 相同）
+// A 按行面板顺序读入，B 按列面板读入，每个面板交给并行内核；
+// 结果按行面板依次写出，内存中最多同时存放一个 A 面板、一个 B 面板和一个 C 行面板
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamingMultiply {
+    memory_budget: usize,
+    threads: usize,
+}
+
+impl Default for StreamingMultiply {
+    fn default() -> Self {
+        StreamingMultiply {
+            memory_budget: 256 << 20,
+            threads: num_cpus::get(),
+        }
+    }
+}
+
+// 一次乘法的面板划分，单位均为元素个数
+#[derive(Debug, PartialEq, Eq)]
+struct Panels {
+    rows: usize,
+    cols: usize,
+}
+
+impl StreamingMultiply {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 面板缓冲区的总字节数上限；即使单行也放不下时仍按一行、一列的面板计算
+    pub fn memory_budget(mut self, bytes: usize) -> Self {
+        self.memory_budget = bytes;
+        self
+    }
+
+    // 线程数，0 视为 1
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    // 缓冲区共四块：A 面板 rows×k、C 行面板 rows×n、B 面板 k×cols，
+    // 以及按列分面板时的结果块 rows×cols
+    // B 能整体放进一半预算时只读一次，不需要结果块；否则 A、C 面板最多占一半，其余留给 B 面板与结果块
+    fn panels<T>(&self, m: usize, k: usize, n: usize) -> Panels {
+        let budget = self.memory_budget / std::mem::size_of::<T>().max(1);
+        let b_len = k.saturating_mul(n);
+        if b_len <= budget / 2 {
+            let rows = (budget - b_len) / k.saturating_add(n).max(1);
+            return Panels {
+                rows: rows.clamp(1, m.max(1)),
+                cols: n,
+            };
+        }
+        let rows = (budget / 2 / k.saturating_add(n).max(1)).clamp(1, m.max(1));
+        let rest = budget.saturating_sub(rows.saturating_mul(k.saturating_add(n)));
+        Panels {
+            rows,
+            cols: (rest / k.saturating_add(rows)).clamp(1, n.max(1)),
+        }
+    }
+
+    pub fn multiply<T, A, B, W>(
+        &self,
+        mut a: A,
+        mut b: B,
+        (m, k, n): (usize, usize, usize),
+        mut out: W,
+    ) -> Result<()>
+    where
+        T: Pod + Zero + Mul<Output = T> + Send + Sync,
+        A: Read,
+        B: Read + Seek,
+        W: Write,
+    {
+        let panels = self.panels::<T>(m, k, n);
+        let mut b_panel = vec![T::zeroed(); k * panels.cols];
+        // B 只有一个面板时提前读入，之后每个 A 面板直接复用
+        let whole_b = panels.cols == n;
+        if whole_b {
+            read_b_panel(&mut b, k, n, 0..n, &mut b_panel)?;
+        }
+        let mut a_panel = vec![T::zeroed(); panels.rows * k];
+        let mut c_panel = vec![T::zeroed(); panels.rows * n];
+        // 按列分面板时，每块结果先算进 block 再拷入 C 的行面板
+        let mut block = vec![
+            T::zeroed();
+            if whole_b {
+                0
+            } else {
+                panels.rows * panels.cols
+            }
+        ];
+        for row_start in (0..m).step_by(panels.rows) {
+            let rows = panels.rows.min(m - row_start);
+            let a_rows = &mut a_panel[..rows * k];
+            a.read_exact(bytemuck::cast_slice_mut(a_rows))?;
+            let c_rows = &mut c_panel[..rows * n];
+            if whole_b {
+                parallel::gemm(a_rows, &b_panel, rows, k, n, c_rows, self.threads);
+            } else {
+                for col_start in (0..n).step_by(panels.cols) {
+                    let cols = panels.cols.min(n - col_start);
+                    let b_cols = &mut b_panel[..k * cols];
+                    read_b_panel(&mut b, k, n, col_start..col_start + cols, b_cols)?;
+                    let c_block = &mut block[..rows * cols];
+                    parallel::gemm(a_rows, b_cols, rows, k, cols, c_block, self.threads);
+                    for (target, source) in c_rows.chunks_mut(n).zip(c_block.chunks(cols)) {
+                        target[col_start..col_start + cols].copy_from_slice(source);
+                    }
+                }
+            }
+            out.write_all(bytemuck::cast_slice(c_rows))?;
+        }
+        out.flush()?;
+        Ok(())
+    }
+
+    // 文件版本；a、b、out 均为原始字节文件
+    pub fn multiply_files<T>(
+        &self,
+        a: impl AsRef<Path>,
+        b: impl AsRef<Path>,
+        shape: (usize, usize, usize),
+        out: impl AsRef<Path>,
+    ) -> Result<()>
+    where
+        T: Pod + Zero + Mul<Output = T> + Send + Sync,
+    {
+        self.multiply::<T, _, _, _>(
+            BufReader::new(File::open(a)?),
+            BufReader::new(File::open(b)?),
+            shape,
+            BufWriter::new(File::create(out)?),
+        )
+    }
+}
+
+// 读入 B 的 cols 这些列，按 k×cols 行优先存入 panel
+fn read_b_panel<T: Pod, B: Read + Seek>(
+    b: &mut B,
+    k: usize,
+    n: usize,
+    cols: Range<usize>,
+    panel: &mut [T],
+) -> Result<()> {
+    let size = std::mem::size_of::<T>();
+    let width = cols.len();
+    for (p, row) in panel.chunks_mut(width.max(1)).take(k).enumerate() {
+        b.seek(SeekFrom::Start(((p * n + cols.start) * size) as u64))?;
+        b.read_exact(bytemuck::cast_slice_mut(row))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::dot_product_slices;
+
+    #[test]
+    fn test_streaming_multiply() {
+        let (m, k, n) = (37, 11, 23);
+        let a: Vec<i64> = (0..m * k).map(|v| v as i64 % 7 - 3).collect();
+        let b: Vec<i64> = (0..k * n).map(|v| v as i64 % 5 - 2).collect();
+        let expected = dot_product_slices(&a, &b, m, k, n, 1).unwrap();
+
+        // 预算足够放下整个 B，以及预算小到 A、B 都要切成多个面板
+        for budget in [1 << 20, 8 * 64] {
+            let stream = StreamingMultiply::new().memory_budget(budget).threads(3);
+            let mut out = Vec::new();
+            stream
+                .multiply::<i64, _, _, _>(
+                    Cursor::new(bytemuck::cast_slice::<i64, u8>(&a)),
+                    Cursor::new(bytemuck::cast_slice::<i64, u8>(&b)),
+                    (m, k, n),
+                    &mut out,
+                )
+                .unwrap();
+            assert_eq!(bytemuck::pod_collect_to_vec::<u8, i64>(&out), expected);
+        }
+        assert_eq!(
+            StreamingMultiply::new()
+                .memory_budget(64)
+                .panels::<i64>(m, k, n),
+            Panels { rows: 1, cols: 1 }
+        );
+    }
+
+    #[test]
+    fn test_panels_fit_budget() {
+        // 四块缓冲区合计不超过预算，只要预算至少放得下一行、一列的面板：(k + n) + (k + 1) 个元素
+        let (m, k, n) = (1000, 300, 500);
+        for budget in [8 * 1101, 8 * 5000, 1 << 20, 8 * 300 * 500, 1 << 22] {
+            let panels = StreamingMultiply::new()
+                .memory_budget(budget)
+                .panels::<f64>(m, k, n);
+            let block = if panels.cols == n {
+                0
+            } else {
+                panels.rows * panels.cols
+            };
+            let total = panels.rows * (k + n) + k * panels.cols + block;
+            assert!(total * 8 <= budget, "{:?} exceeds {}", panels, budget);
+        }
+    }
+}
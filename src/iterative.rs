@@ -0,0 +1,429 @@
+use alloc::{vec, vec::Vec};
+
+use num_traits::Float;
+
+#[cfg(feature = "std")]
+use crate::parallel::{for_each_chunk, validate};
+use crate::{GemmElement, MatrixBase, MatrixError, OwnedMatrix, Result, Storage};
+
+// 迭代求解器的停止条件：相对残差 ‖b - A·x‖ / ‖b‖ 不超过 tolerance，或迭代次数达到上限
+#[derive(Debug, Clone, PartialEq)]
+pub struct IterativeConfig<T> {
+    tolerance: T,
+    max_iterations: usize,
+}
+
+impl<T: Float> Default for IterativeConfig<T> {
+    fn default() -> Self {
+        IterativeConfig {
+            tolerance: T::epsilon().sqrt(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl<T: Float> IterativeConfig<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+}
+
+// 求解结束时的状态；未收敛不算错误，调用方可以根据 residual 决定是否采用结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConvergenceReport<T> {
+    pub iterations: usize,
+    pub residual: T,
+    pub converged: bool,
+}
+
+// 迭代求解的结果：解向量与收敛状态
+pub type Solution<F, T, const N: usize> = (OwnedMatrix<F, T, N, 1>, ConvergenceReport<T>);
+
+fn norm<T: Float>(v: impl Iterator<Item = T>) -> T {
+    v.fold(T::zero(), |sum, x| sum + x * x).sqrt()
+}
+
+// ‖b - A·x‖ / ‖b‖；b 为零向量时返回绝对残差
+fn relative_residual<T: Float>(a: &[T], b: &[T], x: &[T]) -> T {
+    let n = b.len();
+    let residual = norm((0..n).map(|i| {
+        let row = &a[i * n..(i + 1) * n];
+        b[i] - row
+            .iter()
+            .zip(x)
+            .fold(T::zero(), |sum, (&a, &x)| sum + a * x)
+    }));
+    let scale = norm(b.iter().copied());
+    if scale == T::zero() {
+        residual
+    } else {
+        residual / scale
+    }
+}
+
+// 对角元为零时 Jacobi 与 Gauss-Seidel 都无从除起
+fn check_diagonal<T: Float>(a: &[T], n: usize) -> Result<()> {
+    if (0..n).any(|i| a[i * n + i] == T::zero()) {
+        return Err(MatrixError::Singular);
+    }
+    Ok(())
+}
+
+// 用其余分量的当前值解出第 i 个未知数
+fn solve_row<T: Float>(a: &[T], b: &[T], x: &[T], i: usize) -> T {
+    let n = b.len();
+    let row = &a[i * n..(i + 1) * n];
+    let off_diagonal = (0..n)
+        .filter(|&j| j != i)
+        .fold(T::zero(), |sum, j| sum + row[j] * x[j]);
+    (b[i] - off_diagonal) / row[i]
+}
+
+// 开始前与每轮迭代结束后以 (已完成的轮数, 最大轮数) 调用，返回 false 时放弃求解并得到 Cancelled
+// 普通版本传入总是返回 true 的闭包，带监控的版本借此报告进度、响应取消
+pub(crate) type Step<'a> = &'a mut dyn FnMut(usize, usize) -> bool;
+
+// 从零向量出发反复调用 sweep，直到满足 config 的停止条件
+fn iterate<T: Float>(
+    a: &[T],
+    b: &[T],
+    config: &IterativeConfig<T>,
+    step: Step<'_>,
+    mut sweep: impl FnMut(&mut Vec<T>),
+) -> Result<(Vec<T>, ConvergenceReport<T>)> {
+    if !step(0, config.max_iterations) {
+        return Err(MatrixError::Cancelled);
+    }
+    let mut x = vec![T::zero(); b.len()];
+    let mut residual = relative_residual(a, b, &x);
+    let mut iterations = 0;
+    while residual > config.tolerance && iterations < config.max_iterations {
+        sweep(&mut x);
+        iterations += 1;
+        residual = relative_residual(a, b, &x);
+        if !step(iterations, config.max_iterations) {
+            return Err(MatrixError::Cancelled);
+        }
+    }
+    let report = ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= config.tolerance,
+    };
+    Ok((x, report))
+}
+
+fn dot<T: Float>(u: &[T], v: &[T]) -> T {
+    u.iter().zip(v).fold(T::zero(), |sum, (&u, &v)| sum + u * v)
+}
+
+// 预条件共轭梯度法，A 只通过 matvec(p, out) 即 out = A·p 访问，稠密与稀疏矩阵共用
+// inverse_diagonal 为对角预条件子 M⁻¹ 的对角元，None 时即普通共轭梯度
+// 残差按递推公式更新，不再额外计算 A·x；pᵀ·A·p ≤ 0 说明 A 不是正定的，返回 NotPositiveDefinite
+fn conjugate_gradient<T: Float>(
+    matvec: impl Fn(&[T], &mut [T]),
+    b: &[T],
+    inverse_diagonal: Option<&[T]>,
+    config: &IterativeConfig<T>,
+    step: Step<'_>,
+) -> Result<(Vec<T>, ConvergenceReport<T>)> {
+    let n = b.len();
+    let precondition = |r: &[T]| -> Vec<T> {
+        match inverse_diagonal {
+            Some(d) => r.iter().zip(d).map(|(&r, &d)| r * d).collect(),
+            None => r.to_vec(),
+        }
+    };
+    let scale = match norm(b.iter().copied()) {
+        s if s == T::zero() => T::one(),
+        s => s,
+    };
+    let mut x = vec![T::zero(); n];
+    let mut r = b.to_vec();
+    let mut z = precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    let mut ap = vec![T::zero(); n];
+    let mut residual = norm(r.iter().copied()) / scale;
+    let mut iterations = 0;
+    if !step(0, config.max_iterations) {
+        return Err(MatrixError::Cancelled);
+    }
+    while residual > config.tolerance && iterations < config.max_iterations {
+        matvec(&p, &mut ap);
+        let curvature = dot(&p, &ap);
+        if curvature <= T::zero() {
+            return Err(MatrixError::NotPositiveDefinite);
+        }
+        let alpha = rz / curvature;
+        for ((x, r), (&p, &ap)) in x.iter_mut().zip(&mut r).zip(p.iter().zip(&ap)) {
+            *x = *x + alpha * p;
+            *r = *r - alpha * ap;
+        }
+        iterations += 1;
+        residual = norm(r.iter().copied()) / scale;
+        z = precondition(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (p, &z) in p.iter_mut().zip(&z) {
+            *p = z + beta * *p;
+        }
+        if !step(iterations, config.max_iterations) {
+            return Err(MatrixError::Cancelled);
+        }
+    }
+    let report = ConvergenceReport {
+        iterations,
+        residual,
+        converged: residual <= config.tolerance,
+    };
+    Ok((x, report))
+}
+
+// A 严格对角占优时 Jacobi 与 Gauss-Seidel 都保证收敛；Gauss-Seidel 在 A 对称正定时也收敛
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S>
+where
+    T: Float + GemmElement,
+{
+    // 每轮所有分量都只用上一轮的值，各行互不依赖
+    pub fn solve_jacobi(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.jacobi(b, config, &mut |_, _| true)
+    }
+
+    pub(crate) fn jacobi(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        step: Step<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        let (a, b) = (self.as_slice(), b.as_slice());
+        check_diagonal(a, N)?;
+        let (x, report) = iterate(a, b, config, step, |x| {
+            *x = (0..N).map(|i| solve_row(a, b, x, i)).collect();
+        })?;
+        Ok((MatrixBase::try_from(x)?, report))
+    }
+
+    // Jacobi 的各行按块分给 parallel 个线程同时计算
+    #[cfg(feature = "std")]
+    pub fn solve_jacobi_in_parallel(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        parallel: usize,
+    ) -> Result<Solution<S::Family, T, N>>
+    where
+        T: Send + Sync,
+    {
+        self.jacobi_in_parallel(b, config, parallel, &mut |_, _| true)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn jacobi_in_parallel(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        parallel: usize,
+        step: Step<'_>,
+    ) -> Result<Solution<S::Family, T, N>>
+    where
+        T: Send + Sync,
+    {
+        let (a, b) = (self.as_slice(), b.as_slice());
+        check_diagonal(a, N)?;
+        let chunk_len = N.div_ceil(validate(parallel, N));
+        let (x, report) = iterate(a, b, config, step, |x| {
+            let mut next = vec![T::zero(); N];
+            let current: &[T] = x;
+            for_each_chunk(&mut next, chunk_len, |c, chunk| {
+                for (offset, target) in chunk.iter_mut().enumerate() {
+                    *target = solve_row(a, b, current, c * chunk_len + offset);
+                }
+            });
+            *x = next;
+        })?;
+        Ok((MatrixBase::try_from(x)?, report))
+    }
+
+    // 更新第 i 个分量时立即使用本轮已经算出的前 i 个分量，通常比 Jacobi 收敛快一倍
+    pub fn solve_gauss_seidel(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.gauss_seidel(b, config, &mut |_, _| true)
+    }
+
+    pub(crate) fn gauss_seidel(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        step: Step<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        let (a, b) = (self.as_slice(), b.as_slice());
+        check_diagonal(a, N)?;
+        let (x, report) = iterate(a, b, config, step, |x| {
+            for i in 0..N {
+                x[i] = solve_row(a, b, x, i);
+            }
+        })?;
+        Ok((MatrixBase::try_from(x)?, report))
+    }
+
+    // 共轭梯度法，要求 A 对称正定；精确算术下至多 N 步收敛
+    pub fn solve_cg(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.cg(b, config, &mut |_, _| true)
+    }
+
+    pub(crate) fn cg(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        step: Step<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        let matvec = |p: &[T], out: &mut [T]| self.matvec(p, out);
+        let (x, report) = conjugate_gradient(matvec, b.as_slice(), None, config, step)?;
+        Ok((MatrixBase::try_from(x)?, report))
+    }
+
+    // 以对角线为预条件子（Jacobi 预条件）的共轭梯度法，对角元量级差异大时收敛明显更快
+    // 对称正定矩阵的对角元必为正，出现非正对角元时返回 NotPositiveDefinite
+    pub fn solve_pcg(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.pcg(b, config, &mut |_, _| true)
+    }
+
+    pub(crate) fn pcg(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        step: Step<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        if (0..N).any(|i| self[i][i] <= T::zero()) {
+            return Err(MatrixError::NotPositiveDefinite);
+        }
+        let inverse_diagonal: Vec<T> = (0..N).map(|i| T::one() / self[i][i]).collect();
+        let matvec = |p: &[T], out: &mut [T]| self.matvec(p, out);
+        let (x, report) =
+            conjugate_gradient(matvec, b.as_slice(), Some(&inverse_diagonal), config, step)?;
+        Ok((MatrixBase::try_from(x)?, report))
+    }
+
+    fn matvec(&self, p: &[T], out: &mut [T]) {
+        for (i, target) in out.iter_mut().enumerate() {
+            *target = dot(&self[i], p);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_jacobi_and_gauss_seidel() {
+        // 严格对角占优，解为 (1, 2, -1)
+        let a = Matrix::from([[10.0, -1.0, 2.0], [-1.0, 11.0, -1.0], [2.0, -1.0, 10.0]]);
+        let b = Matrix::from([[6.0], [22.0], [-10.0]]);
+        let expected = Matrix::from([[1.0], [2.0], [-1.0]]);
+        let config = IterativeConfig::new().tolerance(1e-12);
+
+        let (x, jacobi) = a.solve_jacobi(&b, &config).unwrap();
+        assert!(jacobi.converged && jacobi.residual <= 1e-12);
+        assert!(x.approx_eq(&expected, 1e-10));
+        let (x, seidel) = a.solve_gauss_seidel(&b, &config).unwrap();
+        assert!(seidel.converged && seidel.iterations < jacobi.iterations);
+        assert!(x.approx_eq(&expected, 1e-10));
+        #[cfg(feature = "std")]
+        {
+            let (x, parallel) = a.solve_jacobi_in_parallel(&b, &config, 2).unwrap();
+            assert_eq!(parallel, jacobi);
+            assert!(x.approx_eq(&expected, 1e-10));
+        }
+    }
+
+    #[test]
+    fn test_non_convergence_and_zero_diagonal() {
+        // 不是对角占优，Jacobi 发散
+        let a = DynMatrics::from([[1.0, 3.0], [3.0, 1.0]]);
+        let b = DynMatrics::from([[1.0], [1.0]]);
+        let config = IterativeConfig::new().max_iterations(20);
+        let (_, report) = a.solve_jacobi(&b, &config).unwrap();
+        assert_eq!(report.iterations, 20);
+        assert!(!report.converged);
+
+        let zero = DynMatrics::from([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(
+            zero.solve_gauss_seidel(&b, &config),
+            Err(MatrixError::Singular)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_conjugate_gradient() {
+        let mut rng = crate::bench_utils::seeded_rng(5);
+        let a = crate::gallery::random_spd::<f64, 12>(&mut rng, 1e3);
+        let expected = Matrix::<f64, 12, 1>::from_fn(|i, _| i as f64 - 5.5);
+        let b = a.dot_product(&expected);
+        let config = IterativeConfig::new().tolerance(1e-12);
+
+        let (x, cg) = a.solve_cg(&b, &config).unwrap();
+        assert!(cg.converged);
+        assert!(x.approx_eq(&expected, 1e-6));
+        let (x, pcg) = a.solve_pcg(&b, &config).unwrap();
+        assert!(pcg.converged);
+        assert!(x.approx_eq(&expected, 1e-6));
+
+        // D·B·D，B 为良态的三对角矩阵、D 的对角元差几个数量级；对角预条件抵消了 D 的影响
+        let d = [1e3, 1.0, 1e-2];
+        let tridiagonal = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]];
+        let scaled = DynMatrics::<f64, 3, 3>::from_fn(|i, j| d[i] * tridiagonal[i][j] * d[j]);
+        let rhs = DynMatrics::from([[1.0], [2.0], [3.0]]);
+        let (_, plain) = scaled.solve_cg(&rhs, &config).unwrap();
+        let (_, preconditioned) = scaled.solve_pcg(&rhs, &config).unwrap();
+        assert!(preconditioned.converged);
+        assert!(preconditioned.iterations <= plain.iterations);
+        let indefinite = DynMatrics::from([[-1.0, 0.0], [0.0, 1.0]]);
+        assert_eq!(
+            indefinite.solve_pcg(&DynMatrics::from([[1.0], [1.0]]), &config),
+            Err(MatrixError::NotPositiveDefinite)
+        );
+
+        // 对称但不定（特征值 3 与 -1），对角元为正，要到迭代中遇到 pᵀ·A·p < 0 才能发现
+        let indefinite = DynMatrics::from([[1.0, 2.0], [2.0, 1.0]]);
+        let rhs = DynMatrics::from([[1.0], [-1.0]]);
+        assert_eq!(
+            indefinite.solve_cg(&rhs, &config),
+            Err(MatrixError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            indefinite.solve_pcg(&rhs, &config),
+            Err(MatrixError::NotPositiveDefinite)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::{gemm, GemmElement, Matrix, MatrixBase, MatrixError, MatrixView, Result, Storage};
+
+// 元素在连续内存中的排列顺序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    RowMajor,
+    ColMajor,
+}
+
+impl Layout {
+    // rows×cols 矩阵中 (row, col) 元素在连续存储中的下标
+    pub fn index(self, row: usize, col: usize, rows: usize, cols: usize) -> usize {
+        match self {
+            Layout::RowMajor => row * cols + col,
+            Layout::ColMajor => col * rows + row,
+        }
+    }
+}
+
+// 列优先存放的 R×C 矩阵，与 Fortran/BLAS/LAPACK 的约定一致
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ColMajorMatrix<T, const R: usize, const C: usize> {
+    data: Vec<T>,
+}
+
+impl<T, const R: usize, const C: usize> ColMajorMatrix<T, R, C> {
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        ColMajorMatrix {
+            data: (0..R * C).map(|index| f(index % R, index / R)).collect(),
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < R && col < C).then(|| &self.data[Layout::ColMajor.index(row, col, R, C)])
+    }
+
+    pub fn column(&self, col: usize) -> Option<&[T]> {
+        (col < C).then(|| &self.data[col * R..(col + 1) * R])
+    }
+
+    // 列优先的 R×C 与行优先的 C×R 是同一块内存，转置不需要复制
+    pub fn transpose(self) -> MatrixBase<T, C, R, Vec<T>> {
+        MatrixBase::from_storage(self.data).expect("storage has exactly R * C elements")
+    }
+
+    pub fn transpose_view(&self) -> MatrixView<'_, T, C, R> {
+        MatrixView::from_slice(&self.data).expect("storage has exactly R * C elements")
+    }
+
+    pub fn to_row_major(&self) -> Matrix<T, R, C>
+    where
+        T: Clone,
+    {
+        Matrix::from_fn(|i, j| self.data[Layout::ColMajor.index(i, j, R, C)].clone())
+    }
+
+    // 两侧都是列优先时 Cᵀ = Bᵀ·Aᵀ 正好是两块存储的行优先乘积
+    pub fn dot_product<const Z: usize>(
+        &self,
+        matrix1: &ColMajorMatrix<T, C, Z>,
+    ) -> ColMajorMatrix<T, R, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_dispatch 会写满全部 R * Z 个元素
+        let data = unsafe { gemm::uninit_vec(R * Z, |c| gemm::gemm_dispatch(b, a, Z, C, R, c)) };
+        ColMajorMatrix { data }
+    }
+
+    // 与行优先矩阵相乘，按各自的布局打包，不做物理转置
+    pub fn dot_product_row_major<const Z: usize, S: Storage<T>>(
+        &self,
+        matrix1: &MatrixBase<T, C, Z, S>,
+    ) -> Matrix<T, R, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_layout_into 会写满全部 R * Z 个元素
+        let data = unsafe {
+            gemm::uninit_vec(R * Z, |c| {
+                gemm::gemm_layout_into((a, Layout::ColMajor), (b, Layout::RowMajor), R, C, Z, c)
+            })
+        };
+        Matrix::try_from(data).expect("product has exactly R * Z elements")
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn to_col_major(&self) -> ColMajorMatrix<T, R, C>
+    where
+        T: Clone,
+    {
+        ColMajorMatrix::from_fn(|i, j| self[i][j].clone())
+    }
+
+    pub fn dot_product_col_major<const Z: usize>(
+        &self,
+        matrix1: &ColMajorMatrix<T, C, Z>,
+    ) -> Matrix<T, R, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_layout_into 会写满全部 R * Z 个元素
+        let data = unsafe {
+            gemm::uninit_vec(R * Z, |c| {
+                gemm::gemm_layout_into((a, Layout::RowMajor), (b, Layout::ColMajor), R, C, Z, c)
+            })
+        };
+        Matrix::try_from(data).expect("product has exactly R * Z elements")
+    }
+}
+
+// 数据按列优先解释，长度必须为 R * C
+impl<T, const R: usize, const C: usize> TryFrom<Vec<T>> for ColMajorMatrix<T, R, C> {
+    type Error = MatrixError;
+
+    fn try_from(data: Vec<T>) -> Result<Self> {
+        if data.len() != R * C {
+            return Err(MatrixError::DimensionMismatch {
+                expected: R * C,
+                got: data.len(),
+            });
+        }
+        Ok(ColMajorMatrix { data })
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize, S: Storage<T>> From<&MatrixBase<T, R, C, S>>
+    for ColMajorMatrix<T, R, C>
+{
+    fn from(matrix: &MatrixBase<T, R, C, S>) -> Self {
+        matrix.to_col_major()
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> From<&ColMajorMatrix<T, R, C>> for Matrix<T, R, C> {
+    fn from(matrix: &ColMajorMatrix<T, R, C>) -> Self {
+        matrix.to_row_major()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_conversions() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let col = ColMajorMatrix::from(&m);
+        assert_eq!(col.as_slice(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(col.get(1, 2), Some(&6));
+        assert_eq!(col.get(2, 0), None);
+        assert_eq!(col.column(1), Some(&[2, 5][..]));
+        assert_eq!(Matrix::from(&col), m);
+        assert_eq!(col.transpose_view().to_matrix(), m.transpose());
+        assert_eq!(col.clone().transpose().to_matrix(), m.transpose());
+        assert_eq!(
+            ColMajorMatrix::<_, 2, 3>::try_from(vec![1, 4, 2, 5, 3, 6]).unwrap(),
+            col
+        );
+        assert!(ColMajorMatrix::<i32, 2, 3>::try_from(vec![1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_layout_aware_products() {
+        let a = Matrix::<i64, 5, 7>::from_fn(|i, j| (i * 7 + j) as i64 - 10);
+        let b = Matrix::<i64, 7, 6>::from_fn(|i, j| (i as i64 - j as i64) * 3);
+        let expected = a.dot_product(&b);
+        let (ca, cb) = (a.to_col_major(), b.to_col_major());
+        assert_eq!(ca.dot_product(&cb).to_row_major(), expected);
+        assert_eq!(ca.dot_product_row_major(&b), expected);
+        assert_eq!(a.dot_product_col_major(&cb), expected);
+        assert_eq!(a.view().dot_product_col_major(&cb), expected);
+    }
+}
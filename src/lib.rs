@@ -1,102 +1,437 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[macro_use]
+mod macros;
+mod accumulate;
+pub mod aligned;
+#[cfg(feature = "allocator")]
+pub mod allocator;
+mod approx_eq;
+mod arith;
+#[cfg(feature = "autodiff")]
+pub mod autodiff;
+pub mod banded;
+#[cfg(feature = "std")]
+pub mod bench_utils;
+pub mod bitmatrix;
+#[cfg(feature = "blas")]
+mod blas_impl;
+#[cfg(feature = "bytes")]
+mod bytes;
+pub mod chain;
+mod complex;
+mod convolve;
+mod cumulative;
+pub mod diagonal;
+mod display;
 pub mod dynamic;
+mod eigen;
+mod error;
+pub mod expr;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fixed")]
+mod fixed_impl;
+// 后台计算运行在常驻线程池上，与 pool 一样不支持 wasm32-unknown-unknown
+#[cfg(all(
+    feature = "async",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub mod future;
+pub mod gallery;
+mod gemm;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod graph;
+#[cfg(feature = "half")]
+mod half_impl;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iterative;
+pub mod layout;
+pub mod linalg;
+mod markov;
+mod mask;
+mod mixed;
+#[cfg(feature = "std")]
+mod monitor;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+pub mod nn;
+mod normalize;
+mod ops;
+mod overflow;
+#[cfg(feature = "std")]
+mod parallel;
+pub mod permutation;
+// wasm32-unknown-unknown 上无法创建常驻线程
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod pool;
+#[cfg(feature = "python")]
+mod python;
+pub mod quaternion;
+mod random;
+mod reorder;
+mod select;
+mod semiring;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "std")]
+pub mod simd;
+pub mod sparse;
+mod stats;
+pub mod stencil;
+pub mod storage;
+mod structure;
+pub mod symmetric;
+mod trace;
+pub mod transform;
+mod transpose;
+pub mod triangular;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use complex::Conjugate;
+pub use error::{MatrixError, Result};
+#[cfg(all(
+    feature = "async",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use future::MatrixFuture;
+pub use gemm::GemmElement;
+pub use iterative::{ConvergenceReport, IterativeConfig};
+pub use linalg::Field;
+#[cfg(feature = "std")]
+pub use monitor::{CancellationToken, Monitor};
+pub use ops::MatrixOps;
+#[cfg(feature = "std")]
+pub use parallel::{dot_product_slices, ParallelConfig};
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use pool::MatrixThreadPool;
+pub use storage::{
+    ArrayMatrix, MatrixView, MatrixViewMut, OwnedMatrix, OwnedStorage, Storage, StorageFamily,
+    StorageMut,
+};
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut, Mul};
 
-use std::ops::{Add, Index, IndexMut, Mul};
+use num_traits::Zero;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
-pub struct Matrix<T, const R: usize, const C: usize> {
-    data: Box<[[T; C]; R]>,
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+// 存储类型 S 可以是堆上数组、Vec、借用切片或内联数组，算法对所有存储只实现一次
+pub struct MatrixBase<T, const R: usize, const C: usize, S> {
+    data: S,
+    _element: PhantomData<T>,
 }
 
-impl<T, const R: usize, const C: usize> Index<usize> for Matrix<T, R, C> {
+// 默认存储：堆上的定长数组
+pub type Matrix<T, const R: usize, const C: usize> = MatrixBase<T, R, C, Box<[[T; C]; R]>>;
+
+// 按元素比较，与存储类型无关；大小按行优先的字典序
+impl<T: PartialEq, const R: usize, const C: usize, S: Storage<T>> PartialEq
+    for MatrixBase<T, R, C, S>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const R: usize, const C: usize, S: Storage<T>> Eq for MatrixBase<T, R, C, S> {}
+
+impl<T: PartialOrd, const R: usize, const C: usize, S: Storage<T>> PartialOrd
+    for MatrixBase<T, R, C, S>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const R: usize, const C: usize, S: Storage<T>> Ord for MatrixBase<T, R, C, S> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> Index<usize> for MatrixBase<T, R, C, S> {
     type Output = [T; C];
 
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data[index]
+        self.as_slice()[index * C..(index + 1) * C]
+            .try_into()
+            .expect("row has exactly C elements")
     }
 }
 
-impl<T, const R: usize, const C: usize> IndexMut<usize> for Matrix<T, R, C> {
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> IndexMut<usize>
+    for MatrixBase<T, R, C, S>
+{
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data[index]
+        (&mut self.as_mut_slice()[index * C..(index + 1) * C])
+            .try_into()
+            .expect("row has exactly C elements")
     }
 }
 
-impl<T, const X: usize, const Y: usize> Default for Matrix<T, X, Y>
+impl<T, const X: usize, const Y: usize, S: OwnedStorage<T>> Default for MatrixBase<T, X, Y, S>
 where
-    T: Default + Copy,
+    T: Default,
 {
+    // 逐个构造元素，不要求 T: Copy
     fn default() -> Self {
-        Matrix {
-            data: Box::new([[T::default(); Y]; X]),
-        }
+        Self::from_fn(|_, _| T::default())
+    }
+}
+
+// 经由 Vec 复制，避免 Box::clone 在栈上构造整个数组
+impl<T: Clone, const X: usize, const Y: usize, S: OwnedStorage<T>> Clone
+    for MatrixBase<T, X, Y, S>
+{
+    fn clone(&self) -> Self {
+        Self::from_vec(self.as_slice().to_vec())
     }
 }
 
-impl<T, const X: usize, const Y: usize> Matrix<T, X, Y> {
-    pub fn dot_product<const Z: usize>(&self, matrix1: &Matrix<T, Y, Z>) -> Matrix<T, X, Z>
+impl<T, const X: usize, const Y: usize, S: OwnedStorage<T>> MatrixBase<T, X, Y, S> {
+    // 直接在堆上逐个构造元素，大尺寸矩阵不会撑爆栈
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self::from_vec((0..X * Y).map(|index| f(index / Y, index % Y)).collect())
+    }
+
+    pub fn filled(value: T) -> Self
     where
-        T: Default + Add<Output = T> + Mul<Output = T> + Copy,
+        T: Clone,
     {
-        let mut result = Matrix::<T, X, Z>::default();
-        for i in 0..X {
-            for j in 0..Z {
-                let mut sum = T::default();
-                for k in 0..Y {
-                    sum = sum + self.data[i][k] * matrix1.data[k][j];
-                }
-                result.data[i][j] = sum;
-            }
+        Self::from_vec(vec![value; X * Y])
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data.into_vec()
+    }
+
+    // 调用方保证 data 恰有 X * Y 个元素
+    fn from_vec(data: Vec<T>) -> Self {
+        Self {
+            data: S::from_vec(data),
+            _element: PhantomData,
         }
-        result
+    }
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T]
+    where
+        S: StorageMut<T>,
+    {
+        self.data.as_mut_slice()
+    }
+
+    // 复制为与 self 同一存储族的自有矩阵，视图与内联数组得到 Matrix
+    pub fn to_owned_matrix(&self) -> OwnedMatrix<S::Family, T, X, Y>
+    where
+        T: Clone,
+    {
+        MatrixBase::from_vec(self.as_slice().to_vec())
+    }
+
+    pub fn transpose(&self) -> OwnedMatrix<S::Family, T, Y, X>
+    where
+        T: Clone,
+    {
+        let a = self.as_slice();
+        // SAFETY: transpose_rows 会写满结果的全部 Y 行
+        let data =
+            unsafe { gemm::uninit_vec(X * Y, |c| transpose::transpose_rows(a, X, Y, 0..Y, c)) };
+        MatrixBase::from_vec(data)
+    }
+
+    pub fn transpose_into<S1: StorageMut<T>>(&self, out: &mut MatrixBase<T, Y, X, S1>)
+    where
+        T: Clone,
+    {
+        transpose::transpose_rows(self.as_slice(), X, Y, 0..Y, out.as_mut_slice());
+    }
+
+    // 按结果的行切块，多线程分块转置
+    #[cfg(feature = "std")]
+    pub fn transpose_in_parallel(&self, parallel: usize) -> OwnedMatrix<S::Family, T, Y, X>
+    where
+        T: Clone + Send + Sync,
+    {
+        let a = self.as_slice();
+        // SAFETY: parallel::transpose 会写满全部 X * Y 个元素
+        let data =
+            unsafe { gemm::uninit_vec(X * Y, |c| parallel::transpose(a, X, Y, c, parallel)) };
+        MatrixBase::from_vec(data)
+    }
+
+    pub fn dot_product<const Z: usize, S1: Storage<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_dispatch 会写满全部 X * Z 个元素
+        let data = unsafe { gemm::uninit_vec(X * Z, |c| gemm::gemm_dispatch(a, b, X, Y, Z, c)) };
+        MatrixBase::from_vec(data)
     }
 
-    pub fn dot_product_in_parallel<const Z: usize>(
+    #[cfg(feature = "std")]
+    pub fn dot_product_in_parallel<const Z: usize, S1: Storage<T>>(
         &self,
-        matrix1: &Matrix<T, Y, Z>,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
         parallel: usize,
-    ) -> Matrix<T, X, Z>
+    ) -> OwnedMatrix<S::Family, T, X, Z>
     where
-        T: Default + Add<Output = T> + Mul<Output = T> + Copy + Send + Sync,
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
     {
-        let mut result = Matrix::<T, X, Z>::default();
-        let matrix0 = &self.data;
-        let matrix1_data = &matrix1.data;
-
-        std::thread::scope(|scope| {
-            let chunk_size = (X + parallel - 1) / parallel; // 计算每个线程应处理的行数
-            result
-                .data
-                .chunks_mut(chunk_size)
-                .enumerate()
-                .for_each(|(i, chunk)| {
-                    scope.spawn(move || {
-                        let start_index = i * chunk_size; // 计算全局行的起始索引
-                        for (local_index, row) in chunk.iter_mut().enumerate() {
-                            let global_index = start_index + local_index; // 计算全局行索引
-                            for z in 0..Z {
-                                let mut sum = T::default();
-                                for y in 0..Y {
-                                    sum = sum + matrix0[global_index][y] * matrix1_data[y][z];
-                                }
-                                row[z] = sum;
-                            }
-                        }
-                    });
-                });
-        });
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: parallel::gemm 会写满全部 X * Z 个元素
+        let data =
+            unsafe { gemm::uninit_vec(X * Z, |c| parallel::gemm(a, b, X, Y, Z, c, parallel)) };
+        MatrixBase::from_vec(data)
+    }
 
+    // 工作线程 panic 时返回 WorkerPanicked 而不是 panic，其余行照常计算
+    #[cfg(feature = "std")]
+    pub fn try_dot_product_in_parallel<const Z: usize, S1: Storage<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+        parallel: usize,
+    ) -> Result<OwnedMatrix<S::Family, T, X, Z>>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // 出错时部分元素不会被写入，不能使用未初始化的缓冲区
+        let mut data = vec![T::zero(); X * Z];
+        parallel::try_gemm(a, b, X, Y, Z, &mut data, parallel)?;
+        Ok(MatrixBase::from_vec(data))
+    }
+
+    // 结果写入调用方提供的 out，循环中反复相乘时可复用同一块内存
+    pub fn dot_product_into<const Z: usize, S1: Storage<T>, S2: StorageMut<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+        out: &mut MatrixBase<T, X, Z, S2>,
+    ) where
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        gemm::gemm_dispatch(
+            self.as_slice(),
+            matrix1.as_slice(),
+            X,
+            Y,
+            Z,
+            out.as_mut_slice(),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    pub fn dot_product_into_in_parallel<const Z: usize, S1: Storage<T>, S2: StorageMut<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+        out: &mut MatrixBase<T, X, Z, S2>,
+        parallel: usize,
+    ) where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        parallel::gemm(
+            self.as_slice(),
+            matrix1.as_slice(),
+            X,
+            Y,
+            Z,
+            out.as_mut_slice(),
+            parallel,
+        );
+    }
+}
+
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S> {
+    // 平方求幂，共 O(log exponent) 次乘法；exponent 为 0 时返回单位矩阵
+    pub fn pow(&self, mut exponent: u32) -> OwnedMatrix<S::Family, T, N, N>
+    where
+        T: Zero + Mul<Output = T> + Clone + num_traits::One + GemmElement,
+    {
+        let mut result =
+            OwnedMatrix::<S::Family, T, N, N>::from_fn(
+                |i, j| {
+                    if i == j {
+                        T::one()
+                    } else {
+                        T::zero()
+                    }
+                },
+            );
+        let mut base = self.to_owned_matrix();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.dot_product(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.dot_product(&base);
+            }
+        }
         result
     }
 }
 
-impl<T, const X: usize, const Y: usize> From<[[T; Y]; X]> for Matrix<T, X, Y> {
+impl<T, const X: usize, const Y: usize, S: OwnedStorage<T>> From<[[T; Y]; X]>
+    for MatrixBase<T, X, Y, S>
+{
     fn from(data: [[T; Y]; X]) -> Self {
+        Self::from_vec(data.into_iter().flatten().collect())
+    }
+}
+
+impl<T, const X: usize, const Y: usize> From<Box<[[T; Y]; X]>> for Matrix<T, X, Y> {
+    fn from(data: Box<[[T; Y]; X]>) -> Self {
         Self {
-            data: Box::new(data),
+            data,
+            _element: PhantomData,
         }
     }
 }
 
+impl<T, const X: usize, const Y: usize, S: OwnedStorage<T>> TryFrom<Vec<T>>
+    for MatrixBase<T, X, Y, S>
+{
+    type Error = MatrixError;
+
+    fn try_from(data: Vec<T>) -> Result<Self> {
+        if data.len() != X * Y {
+            return Err(MatrixError::DimensionMismatch {
+                expected: X * Y,
+                got: data.len(),
+            });
+        }
+        Ok(Self::from_vec(data))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +471,83 @@ mod tests {
         assert_eq!(result.data, expected.data);
     }
 
+    #[test]
+    fn test_try_from_vec() {
+        let m = Matrix::<_, 2, 3>::try_from(vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+        assert!(Matrix::<i32, 2, 3>::try_from(vec![1, 2]).is_err());
+        assert_eq!(m.into_vec(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_round_trip() {
+        let m = Matrix::from([[1.0f64, 2.0], [3.0, 4.0]]);
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&m).unwrap();
+        let archived = rkyv::access::<
+            ArchivedMatrixBase<f64, 2, 2, Box<[[f64; 2]; 2]>>,
+            rkyv::rancor::Error,
+        >(&bytes)
+        .unwrap();
+        assert_eq!(archived.data[1][0], 3.0);
+        let restored =
+            rkyv::deserialize::<Matrix<f64, 2, 2>, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(restored, m);
+    }
+
+    #[test]
+    fn test_non_copy_elements() {
+        use num_bigint::BigInt;
+
+        // 结果超出 i128 范围
+        let big = BigInt::from(u128::MAX);
+        let a = Matrix::from([
+            [big.clone(), BigInt::from(1)],
+            [BigInt::from(0), big.clone()],
+        ]);
+        let b = Matrix::from([[big.clone()], [BigInt::from(2)]]);
+        let expected = Matrix::from([[&big * &big + 2], [&big * 2]]);
+        assert_eq!(a.dot_product(&b), expected);
+        #[cfg(feature = "std")]
+        assert_eq!(a.dot_product_in_parallel(&b, 2), expected);
+        assert_eq!(Matrix::<BigInt, 2, 2>::default()[1][1], BigInt::from(0));
+    }
+
+    #[test]
+    fn test_dot_product_into() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[2, 0], [1, 2]]);
+        let mut out = Matrix::from([[9, 9], [9, 9]]);
+        a.dot_product_into(&b, &mut out);
+        assert_eq!(out, a.dot_product(&b));
+        #[cfg(feature = "std")]
+        {
+            a.dot_product_into_in_parallel(&a, &mut out, 2);
+            assert_eq!(out, a.dot_product(&a));
+        }
+    }
+
+    #[test]
+    fn test_heap_first_construction() {
+        // 4 MB，超过测试线程默认的栈大小
+        let m = Matrix::<i32, 1000, 1000>::from_fn(|i, j| (i * 1000 + j) as i32);
+        assert_eq!(m[999][998], 999_998);
+        let copy = m.clone();
+        assert_eq!(copy[1][2], 1002);
+        let zeros = Matrix::<u8, 2000, 2000>::default();
+        assert_eq!(zeros.as_slice().len(), 4_000_000);
+        let filled = Matrix::<_, 2, 2>::filled(7);
+        assert_eq!(filled, Matrix::from(Box::new([[7, 7], [7, 7]])));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.transpose(), Matrix::from([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(m.transpose().transpose(), m);
+    }
+
+    #[cfg(feature = "std")]
     #[test]
     fn test_dot_product_2x2_par() {
         let a = Matrix::from([[1, 2], [3, 4]]);
@@ -145,6 +557,7 @@ mod tests {
         assert_eq!(result.data, expected.data);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_dot_product_2x3_and_3x2_par() {
         let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
@@ -154,6 +567,7 @@ mod tests {
         assert_eq!(result.data, expected.data);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_dot_product_identity_matrix_par() {
         let a = Matrix::from([[1, 0], [0, 1]]);
@@ -162,6 +576,7 @@ mod tests {
         assert_eq!(result.data, b.data);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn test_dot_product_with_zero_matrix_par() {
         let a = Matrix::from([[0, 0], [0, 0]]);
@@ -0,0 +1,234 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Div, Neg, Sub};
+
+use num_traits::{One, Zero};
+
+use crate::{MatrixBase, MatrixError, OwnedMatrix, Result, Storage};
+
+// 支持除法的数域，消元算法只依赖这些运算，不假设元素是浮点数
+pub trait Field:
+    Clone + PartialEq + Zero + One + Sub<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    // 选主元时的权重，越大越优先；精确类型只需区分零与非零
+    fn pivot_weight(&self) -> f64;
+}
+
+macro_rules! impl_float_field {
+    ($($ty:ty),*) => {
+        $(
+            impl Field for $ty {
+                fn pivot_weight(&self) -> f64 {
+                    self.abs() as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_float_field!(f32, f64);
+
+#[cfg(feature = "rational")]
+macro_rules! impl_ratio_field {
+    ($($ty:ty),*) => {
+        $(
+            impl Field for num_rational::Ratio<$ty> {
+                fn pivot_weight(&self) -> f64 {
+                    if self.is_zero() {
+                        0.0
+                    } else {
+                        1.0
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "rational")]
+impl_ratio_field!(i8, i16, i32, i64, i128, isize, num_bigint::BigInt);
+
+struct Reduction<T> {
+    rank: usize,
+    determinant: T,
+}
+
+// 对行优先存储的 rows×cols 矩阵就地做高斯-若尔当消元，只在前 pivot_cols 列中选主元
+fn gauss_jordan<T: Field>(
+    data: &mut [T],
+    rows: usize,
+    cols: usize,
+    pivot_cols: usize,
+) -> Reduction<T> {
+    let mut rank = 0;
+    let mut determinant = T::one();
+    for col in 0..pivot_cols {
+        if rank == rows {
+            break;
+        }
+        let best = (rank..rows)
+            .max_by(|&a, &b| {
+                let a = data[a * cols + col].pivot_weight();
+                let b = data[b * cols + col].pivot_weight();
+                a.partial_cmp(&b).unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .unwrap();
+        let pivot = data[best * cols + col].clone();
+        if pivot.is_zero() {
+            determinant = T::zero();
+            continue;
+        }
+        if best != rank {
+            for j in 0..cols {
+                data.swap(best * cols + j, rank * cols + j);
+            }
+            determinant = -determinant;
+        }
+        determinant = determinant * pivot.clone();
+
+        for j in 0..cols {
+            data[rank * cols + j] = data[rank * cols + j].clone() / pivot.clone();
+        }
+        for i in (0..rows).filter(|&i| i != rank) {
+            let factor = data[i * cols + col].clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for j in 0..cols {
+                data[i * cols + j] =
+                    data[i * cols + j].clone() - factor.clone() * data[rank * cols + j].clone();
+            }
+        }
+        rank += 1;
+    }
+    if rank < rows.min(pivot_cols) {
+        determinant = T::zero();
+    }
+    Reduction { rank, determinant }
+}
+
+fn inverse_of<T: Field>(data: &[T], n: usize) -> Result<Vec<T>> {
+    let width = 2 * n;
+    let mut augmented = vec![T::zero(); n * width];
+    for i in 0..n {
+        augmented[i * width..i * width + n].clone_from_slice(&data[i * n..(i + 1) * n]);
+        augmented[i * width + n + i] = T::one();
+    }
+    if gauss_jordan(&mut augmented, n, width, n).rank < n {
+        return Err(MatrixError::Singular);
+    }
+    Ok(augmented
+        .chunks(width)
+        .flat_map(|row| row[n..].iter().cloned())
+        .collect())
+}
+
+impl<T: Field, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    // 简化行阶梯形
+    pub fn rref(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        let mut result = self.to_owned_matrix();
+        gauss_jordan(result.as_mut_slice(), R, C, C);
+        result
+    }
+
+    pub fn rank(&self) -> usize {
+        let mut data = self.as_slice().to_vec();
+        gauss_jordan(&mut data, R, C, C).rank
+    }
+}
+
+impl<T: Field, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S> {
+    pub fn determinant(&self) -> T {
+        let mut data = self.as_slice().to_vec();
+        gauss_jordan(&mut data, N, N, N).determinant
+    }
+
+    pub fn inverse(&self) -> Result<OwnedMatrix<S::Family, T, N, N>> {
+        MatrixBase::try_from(inverse_of(self.as_slice(), N)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_determinant() {
+        let m = Matrix::from([[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]]);
+        assert!((m.determinant() - 6.0f64).abs() < 1e-12);
+        let swapped = Matrix::from([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(swapped.determinant(), -1.0);
+        let singular = DynMatrics::from([[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = Matrix::from([[4.0, 7.0], [2.0, 6.0]]);
+        let inverse = m.inverse().unwrap();
+        let identity = Matrix::from([[1.0, 0.0], [0.0, 1.0]]);
+        assert!(m.dot_product(&inverse).approx_eq(&identity, 1e-12));
+        assert_eq!(
+            Matrix::from([[1.0, 2.0], [2.0, 4.0]])
+                .inverse()
+                .unwrap_err(),
+            MatrixError::Singular
+        );
+    }
+
+    #[test]
+    fn test_rref_and_rank() {
+        let m = DynMatrics::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+        assert_eq!(m.rank(), 2);
+        let rref = m.rref();
+        assert!(rref.approx_eq(
+            &DynMatrics::from([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [0.0, 0.0, 0.0]]),
+            1e-12
+        ));
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn test_exact_rational() {
+        use num_rational::Ratio;
+
+        let r = |n: i64| Ratio::from_integer(n);
+        let hilbert = Matrix::from([
+            [r(1), Ratio::new(1, 2), Ratio::new(1, 3)],
+            [Ratio::new(1, 2), Ratio::new(1, 3), Ratio::new(1, 4)],
+            [Ratio::new(1, 3), Ratio::new(1, 4), Ratio::new(1, 5)],
+        ]);
+        assert_eq!(hilbert.determinant(), Ratio::new(1, 2160));
+        let inverse = hilbert.inverse().unwrap();
+        assert_eq!(
+            inverse,
+            Matrix::from([
+                [r(9), r(-36), r(30)],
+                [r(-36), r(192), r(-180)],
+                [r(30), r(-180), r(180)],
+            ])
+        );
+        let identity = Matrix::from([[r(1), r(0), r(0)], [r(0), r(1), r(0)], [r(0), r(0), r(1)]]);
+        assert_eq!(hilbert.dot_product(&inverse), identity);
+        assert_eq!(
+            Matrix::from([[r(1), r(2), r(3)], [r(2), r(4), r(7)]]).rref(),
+            Matrix::from([[r(1), r(2), r(0)], [r(0), r(0), r(1)]])
+        );
+    }
+
+    #[cfg(feature = "rational")]
+    #[test]
+    fn test_big_rational() {
+        use num_bigint::BigInt;
+        use num_rational::BigRational;
+
+        let r = |n: i64| BigRational::from_integer(BigInt::from(n));
+        let m = DynMatrics::from([[r(2), r(1)], [r(7), r(4)]]);
+        assert_eq!(m.determinant(), r(1));
+        assert_eq!(
+            m.inverse().unwrap(),
+            DynMatrics::from([[r(4), r(-1)], [r(-7), r(2)]])
+        );
+    }
+}
@@ -0,0 +1,41 @@
+// 按行构造 Matrix，行之间用 `;` 分隔，如 `matrix![1, 2; 3, 4]`
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {
+        $crate::Matrix::from([$([$($x),+]),+])
+    };
+}
+
+// 与 `matrix!` 语法相同，构造 DynMatrics
+#[macro_export]
+macro_rules! dynmatrix {
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {
+        $crate::dynamic::DynMatrics::from([$([$($x),+]),+])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+    use alloc::vec;
+
+    #[test]
+    fn test_matrix_macro() {
+        let m = matrix![1, 2, 3; 4, 5, 6];
+        assert_eq!(m, Matrix::from([[1, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    fn test_matrix_macro_single_column() {
+        let m: Matrix<i32, 3, 1> = matrix![1; 2; 3;];
+        assert_eq!(m[2], [3]);
+    }
+
+    #[test]
+    fn test_dynmatrix_macro() {
+        let m = dynmatrix![1, 2; 3, 4; 5, 6];
+        let expected = DynMatrics::<_, 3, 2>::try_from(vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m, expected);
+    }
+}
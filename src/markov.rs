@@ -0,0 +1,102 @@
+use num_traits::Float;
+
+use crate::{GemmElement, MatrixBase, OwnedMatrix, Storage};
+
+// 行和与 1 的允许误差，取机器精度的平方根
+fn row_sum_tolerance<T: Float>() -> T {
+    T::epsilon().sqrt()
+}
+
+// 转移矩阵约定按行存放：P[i][j] 为从状态 i 转移到状态 j 的概率
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S>
+where
+    T: Float + GemmElement,
+{
+    // 元素非负且每行之和为 1
+    pub fn is_row_stochastic(&self) -> bool {
+        self.as_slice().iter().all(|&x| x >= T::zero())
+            && (0..R).all(|i| {
+                let sum = self[i].iter().fold(T::zero(), |sum, &x| sum + x);
+                (sum - T::one()).abs() <= row_sum_tolerance()
+            })
+    }
+
+    // 每行除以行和；和为零的行没有可用的比例，保持原样
+    pub fn normalize_rows_to_one(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        let sums: [T; R] =
+            core::array::from_fn(|i| self[i].iter().fold(T::zero(), |sum, &x| sum + x));
+        MatrixBase::from_fn(|i, j| {
+            if sums[i] == T::zero() {
+                self[i][j]
+            } else {
+                self[i][j] / sums[i]
+            }
+        })
+    }
+}
+
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S>
+where
+    T: Float + GemmElement,
+{
+    // 满足 π·P = π 且各分量之和为 1 的行向量
+    // 从均匀分布出发对 Pᵀ 做幂迭代，相邻两次的 L1 距离小于 tolerance 时返回；
+    // 周期链等不收敛的情况在 max_iterations 次后返回 None
+    pub fn stationary_distribution(
+        &self,
+        tolerance: T,
+        max_iterations: usize,
+    ) -> Option<OwnedMatrix<S::Family, T, 1, N>> {
+        let transposed = self.transpose();
+        let uniform = T::one() / T::from(N).expect("state count fits in a float");
+        let mut x = OwnedMatrix::<S::Family, T, N, 1>::filled(uniform);
+        for _ in 0..max_iterations {
+            let next = transposed.dot_product(&x);
+            // 重新归一化，抵消舍入误差的累积
+            let sum = next.as_slice().iter().fold(T::zero(), |sum, &v| sum + v);
+            let next = OwnedMatrix::<S::Family, T, N, 1>::from_fn(|i, _| next[i][0] / sum);
+            let delta = (0..N).fold(T::zero(), |d, i| d + (next[i][0] - x[i][0]).abs());
+            x = next;
+            if delta < tolerance {
+                return Some(x.transpose());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_stochastic_helpers() {
+        let counts = Matrix::from([[1.0, 3.0], [2.0, 2.0], [0.0, 0.0]]);
+        assert!(!counts.is_row_stochastic());
+        let p = counts.normalize_rows_to_one();
+        assert_eq!(p, Matrix::from([[0.25, 0.75], [0.5, 0.5], [0.0, 0.0]]));
+        assert!(!p.is_row_stochastic());
+        assert!(Matrix::from([[0.1, 0.2, 0.7]]).is_row_stochastic());
+        assert!(!DynMatrics::from([[1.5, -0.5]]).is_row_stochastic());
+    }
+
+    #[test]
+    fn test_stationary_distribution() {
+        // 两状态链 [[1-a, a], [b, 1-b]] 的平稳分布为 (b, a) / (a + b)
+        let p = Matrix::from([[0.9, 0.1], [0.3, 0.7]]);
+        let pi = p.stationary_distribution(1e-12, 1000).unwrap();
+        assert!(pi.approx_eq(&Matrix::from([[0.75, 0.25]]), 1e-9));
+        assert!(pi.dot_product(&p).approx_eq(&pi, 1e-9));
+
+        // 均匀分布本身就是双随机矩阵的平稳分布，第一步即收敛
+        let flip = DynMatrics::from([[0.0, 1.0], [1.0, 0.0]]);
+        assert_eq!(
+            flip.stationary_distribution(1e-12, 100),
+            Some(DynMatrics::from([[0.5, 0.5]]))
+        );
+        // 周期为 2 的链从均匀分布出发会来回振荡
+        let bipartite = DynMatrics::from([[0.0, 1.0, 0.0], [0.5, 0.0, 0.5], [0.0, 1.0, 0.0]]);
+        assert_eq!(bipartite.stationary_distribution(1e-12, 1000), None);
+    }
+}
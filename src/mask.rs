@@ -0,0 +1,106 @@
+use crate::{MatrixBase, OwnedMatrix, Storage, StorageMut};
+
+// 逐元素比较得到同形状的布尔掩码，与标量比较时另一侧用 filled 构造
+// 方法名都带 _elem 后缀，不与 PartialOrd::gt/lt、PartialEq::eq 混淆
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    fn compare(
+        &self,
+        other: &Self,
+        f: impl Fn(&T, &T) -> bool,
+    ) -> OwnedMatrix<S::Family, bool, R, C> {
+        let (a, b) = (self.as_slice(), other.as_slice());
+        MatrixBase::from_fn(|i, j| f(&a[i * C + j], &b[i * C + j]))
+    }
+
+    pub fn gt_elem(&self, other: &Self) -> OwnedMatrix<S::Family, bool, R, C>
+    where
+        T: PartialOrd,
+    {
+        self.compare(other, |a, b| a > b)
+    }
+
+    pub fn lt_elem(&self, other: &Self) -> OwnedMatrix<S::Family, bool, R, C>
+    where
+        T: PartialOrd,
+    {
+        self.compare(other, |a, b| a < b)
+    }
+
+    pub fn eq_elem(&self, other: &Self) -> OwnedMatrix<S::Family, bool, R, C>
+    where
+        T: PartialEq,
+    {
+        self.compare(other, |a, b| a == b)
+    }
+
+    // mask 为真处取 if_true，否则取 if_false
+    pub fn select<S1: Storage<bool>>(
+        mask: &MatrixBase<bool, R, C, S1>,
+        if_true: &Self,
+        if_false: &Self,
+    ) -> OwnedMatrix<S::Family, T, R, C>
+    where
+        T: Clone,
+    {
+        let (m, a, b) = (mask.as_slice(), if_true.as_slice(), if_false.as_slice());
+        MatrixBase::from_fn(|i, j| {
+            let index = i * C + j;
+            if m[index] { &a[index] } else { &b[index] }.clone()
+        })
+    }
+
+    // 就地把 mask 为真的元素改为 value
+    pub fn masked_fill<S1: Storage<bool>>(&mut self, mask: &MatrixBase<bool, R, C, S1>, value: T)
+    where
+        T: Clone,
+        S: StorageMut<T>,
+    {
+        for (slot, &hit) in self.as_mut_slice().iter_mut().zip(mask.as_slice()) {
+            if hit {
+                *slot = value.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_threshold_and_fill() {
+        let data = Matrix::from([[-1.0, 0.5, 3.0], [f64::NAN, 2.0, -0.5]]);
+        let zeros = Matrix::filled(0.0);
+        let positive = data.gt_elem(&zeros);
+        assert_eq!(
+            positive,
+            Matrix::from([[false, true, true], [false, true, false]])
+        );
+        assert_eq!(
+            data.lt_elem(&zeros),
+            Matrix::from([[true, false, false], [false, false, true]])
+        );
+
+        // ReLU：负值与 NaN 都置零
+        assert_eq!(
+            Matrix::select(&positive, &data, &zeros),
+            Matrix::from([[0.0, 0.5, 3.0], [0.0, 2.0, 0.0]])
+        );
+
+        // NaN 与自身不相等：eq_elem 为假处即缺失值
+        let present = data.eq_elem(&data);
+        assert_eq!(
+            present,
+            Matrix::from([[true, true, true], [false, true, true]])
+        );
+        let mut cleaned = Matrix::select(&present, &data, &Matrix::filled(-1.0));
+        cleaned.masked_fill(&positive, 1.0);
+        assert_eq!(cleaned, Matrix::from([[-1.0, 1.0, 1.0], [-1.0, 1.0, -0.5]]));
+
+        let mut d = DynMatrics::from([[1, 5], [7, 2]]);
+        let mask = d.gt_elem(&DynMatrics::filled(4));
+        d.masked_fill(&mask, 4);
+        assert_eq!(d, DynMatrics::from([[1, 4], [4, 2]]));
+    }
+}
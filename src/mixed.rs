@@ -0,0 +1,70 @@
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::{gemm, MatrixBase, OwnedMatrix, Storage};
+
+// 混合精度乘法：两个操作数的元素类型可以不同，先各自转换为累加类型 Acc 再相乘累加，
+// 例如 u8·i8 在 i32 中累加，或 f16·f16 得到 f32 的结果
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 结果保持累加类型的精度
+    pub fn dot_product_mixed<Acc, U, const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<U, Y, Z, impl Storage<U>>,
+    ) -> OwnedMatrix<S::Family, Acc, X, Z>
+    where
+        T: Clone + Into<Acc>,
+        U: Clone + Into<Acc>,
+        Acc: Zero + Mul<Output = Acc> + Clone,
+    {
+        self.dot_product_mixed_map(matrix1, |sum| sum)
+    }
+
+    // 每个元素累加完成后经 finish 转换为输出类型，例如把 i32 的和重新量化为 i8
+    pub fn dot_product_mixed_map<Acc, U, O, const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<U, Y, Z, impl Storage<U>>,
+        finish: impl Fn(Acc) -> O,
+    ) -> OwnedMatrix<S::Family, O, X, Z>
+    where
+        T: Clone + Into<Acc>,
+        U: Clone + Into<Acc>,
+        Acc: Zero + Mul<Output = Acc> + Clone,
+        O: Clone,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        // SAFETY: gemm_mixed 会写满全部 X * Z 个元素
+        let data = unsafe {
+            gemm::uninit_vec(X * Z, |c| gemm::gemm_mixed(a, b, X, Y, Z, 0..Z, finish, c))
+        };
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_quantized_product() {
+        // u8 激活乘 i8 权重，单个乘积就可能超出 i8/u8 的范围
+        let activations = Matrix::<u8, 2, 3>::from([[255, 0, 10], [1, 2, 3]]);
+        let weights = Matrix::<i8, 3, 2>::from([[-128, 1], [5, 127], [-1, 0]]);
+        let product: Matrix<i32, 2, 2> = activations.dot_product_mixed(&weights);
+        assert_eq!(product, Matrix::from([[-32650, 255], [-121, 255]]));
+
+        // 重新量化：右移后截断到 i8
+        let requantized = activations.dot_product_mixed_map(&weights, |sum: i32| {
+            (sum >> 8).clamp(i8::MIN as i32, i8::MAX as i32) as i8
+        });
+        assert_eq!(requantized, Matrix::from([[-128, 0], [-1, 0]]));
+
+        let a = DynMatrics::from([[1.5f32, 2.0]]);
+        let b = DynMatrics::from([[2.0f64], [0.25]]);
+        assert_eq!(
+            a.dot_product_mixed::<f64, _, 1>(&b),
+            DynMatrics::from([[3.5]])
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::ops::Mul;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use num_traits::{Float, Zero};
+
+use crate::iterative::Solution;
+use crate::parallel::{for_each_part_dynamic, validate};
+use crate::{
+    gemm, transpose, GemmElement, IterativeConfig, MatrixBase, MatrixError, OwnedMatrix, Result,
+    Storage,
+};
+
+// 可在任意线程上触发的取消标志，克隆后共享同一个状态
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// 长时间运算的进度回调与取消令牌
+// 回调在工作线程上调用，参数为已完成的行数与总行数，应尽快返回
+#[derive(Default)]
+pub struct Monitor<'a> {
+    token: Option<CancellationToken>,
+    progress: Option<Box<dyn Fn(usize, usize) + Sync + 'a>>,
+}
+
+impl<'a> Monitor<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    pub fn on_progress(mut self, progress: impl Fn(usize, usize) + Sync + 'a) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    fn report(&self, done: usize, total: usize) {
+        if let Some(progress) = &self.progress {
+            progress(done, total);
+        }
+    }
+
+    // 迭代求解器每轮调用一次：报告进度，已取消时返回 false
+    fn step(&self) -> impl FnMut(usize, usize) -> bool + '_ {
+        move |done, total| {
+            self.report(done, total);
+            !self.is_cancelled()
+        }
+    }
+}
+
+// 每个线程分到的块数；块越多进度越细、取消越及时，但调度开销也越大
+const BLOCKS_PER_THREAD: usize = 16;
+
+// 按行分块并行计算 C = A·B，每块开始前检查取消、完成后报告进度
+// 取消时已开始的块会算完，其余块跳过，返回 Err(Cancelled)
+fn gemm_monitored<T>(
+    a: &[T],
+    b: &[T],
+    (x, y, z): (usize, usize, usize),
+    parallel: usize,
+    monitor: &Monitor<'_>,
+) -> Result<Vec<T>>
+where
+    T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+{
+    let parallel = validate(parallel, x);
+    let mut c = vec![T::zero(); x * z];
+    let block_rows = x.div_ceil(parallel * BLOCKS_PER_THREAD).max(1);
+    let done = AtomicUsize::new(0);
+    let parts = c.chunks_mut(block_rows * z.max(1)).collect();
+    for_each_part_dynamic(parts, parallel, |i, chunk| {
+        if monitor.is_cancelled() {
+            return;
+        }
+        let start = i * block_rows;
+        let rows = chunk.len() / z.max(1);
+        gemm::gemm_dispatch(&a[start * y..(start + rows) * y], b, rows, y, z, chunk);
+        let finished = done.fetch_add(rows, Ordering::Relaxed) + rows;
+        monitor.report(finished, x);
+    });
+    if monitor.is_cancelled() {
+        return Err(MatrixError::Cancelled);
+    }
+    Ok(c)
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 与 dot_product_in_parallel 相同，但可以报告进度、中途取消
+    pub fn dot_product_in_parallel_monitored<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+        parallel: usize,
+        monitor: &Monitor<'_>,
+    ) -> Result<OwnedMatrix<S::Family, T, X, Z>>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        let data = gemm_monitored(a, b, (X, Y, Z), parallel, monitor)?;
+        Ok(MatrixBase::try_from(data).expect("product has exactly X * Z elements"))
+    }
+
+    // 与 transpose_in_parallel 相同，但可以报告进度、中途取消；进度按结果的行数计
+    pub fn transpose_in_parallel_monitored(
+        &self,
+        parallel: usize,
+        monitor: &Monitor<'_>,
+    ) -> Result<OwnedMatrix<S::Family, T, Y, X>>
+    where
+        T: Clone + Send + Sync,
+    {
+        let a = self.as_slice();
+        let parallel = validate(parallel, Y);
+        let block_rows = Y.div_ceil(parallel * BLOCKS_PER_THREAD).max(1);
+        let done = AtomicUsize::new(0);
+        let fill = |c: &mut [_]| {
+            let parts = c.chunks_mut(block_rows * X.max(1)).collect();
+            for_each_part_dynamic(parts, parallel, |i, chunk| {
+                if monitor.is_cancelled() {
+                    return;
+                }
+                let start = i * block_rows;
+                let rows = chunk.len() / X.max(1);
+                transpose::transpose_rows(a, X, Y, start..start + rows, chunk);
+                let finished = done.fetch_add(rows, Ordering::Relaxed) + rows;
+                monitor.report(finished, Y);
+            });
+            // 取消标志一经设置不会复原，有块被跳过时这里一定返回 Err
+            if monitor.is_cancelled() {
+                return Err(MatrixError::Cancelled);
+            }
+            Ok(())
+        };
+        // SAFETY: 未取消时每一块都由 transpose_rows 写满
+        let data = unsafe { gemm::try_uninit_vec(X * Y, fill)? };
+        Ok(MatrixBase::from_vec(data))
+    }
+}
+
+// 迭代求解器的监控版本：开始前与每轮迭代后报告 (已完成的轮数, 最大轮数)，
+// 取消后在当前这一轮结束时返回 Err(Cancelled)
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S>
+where
+    T: Float + GemmElement,
+{
+    pub fn solve_jacobi_monitored(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        monitor: &Monitor<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.jacobi(b, config, &mut monitor.step())
+    }
+
+    pub fn solve_jacobi_in_parallel_monitored(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        parallel: usize,
+        monitor: &Monitor<'_>,
+    ) -> Result<Solution<S::Family, T, N>>
+    where
+        T: Send + Sync,
+    {
+        self.jacobi_in_parallel(b, config, parallel, &mut monitor.step())
+    }
+
+    pub fn solve_gauss_seidel_monitored(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        monitor: &Monitor<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.gauss_seidel(b, config, &mut monitor.step())
+    }
+
+    pub fn solve_cg_monitored(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        monitor: &Monitor<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.cg(b, config, &mut monitor.step())
+    }
+
+    pub fn solve_pcg_monitored(
+        &self,
+        b: &MatrixBase<T, N, 1, impl Storage<T>>,
+        config: &IterativeConfig<T>,
+        monitor: &Monitor<'_>,
+    ) -> Result<Solution<S::Family, T, N>> {
+        self.pcg(b, config, &mut monitor.step())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_progress_and_cancel() {
+        let a = Matrix::<i64, 100, 20>::from_fn(|i, j| (i + j) as i64);
+        let b = Matrix::<i64, 20, 10>::from_fn(|i, j| (i * j) as i64 % 3);
+        let reports = Mutex::new(Vec::new());
+        let monitor = Monitor::new().on_progress(|done, total| {
+            reports.lock().unwrap().push((done, total));
+        });
+        let c = a
+            .dot_product_in_parallel_monitored(&b, 4, &monitor)
+            .unwrap();
+        assert_eq!(c, a.dot_product(&b));
+        drop(monitor);
+        let reports = reports.into_inner().unwrap();
+        assert!(reports.len() > 1);
+        assert_eq!(reports.iter().map(|r| r.0).max(), Some(100));
+        assert!(reports.iter().all(|&(_, total)| total == 100));
+
+        // 第一块完成后即取消
+        let token = CancellationToken::new();
+        let monitor = Monitor::new()
+            .cancellation(token.clone())
+            .on_progress(|_, _| token.cancel());
+        let d = DynMatrics::<i64, 100, 20>::try_from(a.into_vec()).unwrap();
+        assert_eq!(
+            d.dot_product_in_parallel_monitored(&DynMatrics::filled(1), 1, &monitor),
+            Err::<DynMatrics<i64, 100, 1>, _>(MatrixError::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_transpose_monitored() {
+        let a = Matrix::<u32, 37, 300>::from_fn(|i, j| (i * 300 + j) as u32);
+        let reports = Mutex::new(Vec::new());
+        let monitor = Monitor::new().on_progress(|done, total| {
+            reports.lock().unwrap().push((done, total));
+        });
+        let t = a.transpose_in_parallel_monitored(3, &monitor).unwrap();
+        assert_eq!(t, a.transpose());
+        drop(monitor);
+        let reports = reports.into_inner().unwrap();
+        assert!(reports.len() > 1);
+        assert_eq!(reports.iter().map(|r| r.0).max(), Some(300));
+
+        // 已写入的元素在取消时不会被当作完整的结果
+        let token = CancellationToken::new();
+        let monitor = Monitor::new()
+            .cancellation(token.clone())
+            .on_progress(|_, _| token.cancel());
+        let strings = DynMatrics::<String, 40, 3>::from_fn(|i, j| format!("{}-{}", i, j));
+        assert_eq!(
+            strings.transpose_in_parallel_monitored(1, &monitor),
+            Err(MatrixError::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_iterative_monitored() {
+        let a = Matrix::from([[10.0, -1.0, 2.0], [-1.0, 11.0, -1.0], [2.0, -1.0, 10.0]]);
+        let b = Matrix::from([[6.0], [22.0], [-10.0]]);
+        let config = IterativeConfig::new().tolerance(1e-12);
+        let reports = Mutex::new(Vec::new());
+        let monitor = Monitor::new().on_progress(|done, total| {
+            reports.lock().unwrap().push((done, total));
+        });
+        assert_eq!(
+            a.solve_jacobi_monitored(&b, &config, &monitor),
+            a.solve_jacobi(&b, &config)
+        );
+        assert_eq!(
+            a.solve_jacobi_in_parallel_monitored(&b, &config, 2, &monitor),
+            a.solve_jacobi(&b, &config)
+        );
+        assert_eq!(
+            a.solve_gauss_seidel_monitored(&b, &config, &monitor),
+            a.solve_gauss_seidel(&b, &config)
+        );
+        assert_eq!(
+            a.solve_cg_monitored(&b, &config, &monitor),
+            a.solve_cg(&b, &config)
+        );
+        assert_eq!(
+            a.solve_pcg_monitored(&b, &config, &monitor),
+            a.solve_pcg(&b, &config)
+        );
+        drop(monitor);
+        let reports = reports.into_inner().unwrap();
+        let (_, jacobi) = a.solve_jacobi(&b, &config).unwrap();
+        // 开始前报告一次 0，之后每轮一次
+        assert_eq!(reports[0], (0, 1000));
+        assert_eq!(reports[jacobi.iterations], (jacobi.iterations, 1000));
+
+        // 第三轮结束后取消
+        let token = CancellationToken::new();
+        let monitor = Monitor::new()
+            .cancellation(token.clone())
+            .on_progress(|done, _| {
+                if done == 3 {
+                    token.cancel()
+                }
+            });
+        let slow = DynMatrics::from([[1.0, 0.9], [0.9, 1.0]]);
+        let rhs = DynMatrics::from([[1.0], [2.0]]);
+        assert_eq!(
+            slow.solve_jacobi_monitored(&rhs, &config, &monitor),
+            Err(MatrixError::Cancelled)
+        );
+        token.cancel();
+        assert_eq!(
+            a.solve_cg_monitored(&b, &config, &monitor),
+            Err(MatrixError::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use nalgebra::{DMatrix, SMatrix, Scalar};
+
+use crate::dynamic::DynMatrics;
+use crate::{Matrix, MatrixError};
+
+// nalgebra 按列优先存储，这里统一按 (i, j) 逐个搬运
+fn row_major<T: Scalar>(rows: usize, cols: usize, get: impl Fn(usize, usize) -> T) -> Vec<T> {
+    (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| (i, j)))
+        .map(|(i, j)| get(i, j))
+        .collect()
+}
+
+impl<T: Scalar, const R: usize, const C: usize> From<Matrix<T, R, C>> for SMatrix<T, R, C> {
+    fn from(matrix: Matrix<T, R, C>) -> Self {
+        SMatrix::from_row_slice(matrix.as_slice())
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T, R, C> {
+    fn from(matrix: SMatrix<T, R, C>) -> Self {
+        let data = row_major(R, C, |i, j| matrix[(i, j)].clone());
+        Matrix::try_from(data).expect("SMatrix has exactly R * C elements")
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> From<DynMatrics<T, R, C>> for DMatrix<T> {
+    fn from(matrix: DynMatrics<T, R, C>) -> Self {
+        DMatrix::from_row_slice(R, C, matrix.as_slice())
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> TryFrom<DMatrix<T>> for DynMatrics<T, R, C> {
+    type Error = MatrixError;
+
+    fn try_from(matrix: DMatrix<T>) -> Result<Self, MatrixError> {
+        if matrix.nrows() != R {
+            return Err(MatrixError::DimensionMismatch {
+                expected: R,
+                got: matrix.nrows(),
+            });
+        }
+        if matrix.ncols() != C {
+            return Err(MatrixError::DimensionMismatch {
+                expected: C,
+                got: matrix.ncols(),
+            });
+        }
+        DynMatrics::try_from(row_major(R, C, |i, j| matrix[(i, j)].clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_static_round_trip() {
+        let m = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let s: SMatrix<f64, 2, 3> = m.clone().into();
+        assert_eq!(s[(0, 2)], 3.0);
+        assert_eq!(s[(1, 0)], 4.0);
+        assert_eq!(Matrix::from(s), m);
+    }
+
+    #[test]
+    fn test_dynamic_round_trip() {
+        let m = DynMatrics::from([[1, 2], [3, 4], [5, 6]]);
+        let d: DMatrix<i32> = m.clone().into();
+        assert_eq!(d.shape(), (3, 2));
+        assert_eq!(d[(2, 1)], 6);
+        assert_eq!(DynMatrics::<i32, 3, 2>::try_from(d.clone()).unwrap(), m);
+        assert!(DynMatrics::<i32, 2, 3>::try_from(d).is_err());
+    }
+
+    #[test]
+    fn test_multiply_agrees_with_nalgebra() {
+        let a = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from([[5.0, 6.0], [7.0, 8.0]]);
+        let expected = SMatrix::from(a.clone()) * SMatrix::from(b.clone());
+        assert_eq!(Matrix::from(expected), a.dot_product(&b));
+    }
+}
@@ -0,0 +1,131 @@
+use ndarray::{Array2, ArrayView2, ArrayViewMut2};
+
+use crate::{MatrixBase, MatrixError, OwnedStorage, Storage, StorageMut};
+
+fn check_shape(shape: &[usize], rows: usize, cols: usize) -> Result<(), MatrixError> {
+    if shape[0] != rows {
+        return Err(MatrixError::DimensionMismatch {
+            expected: rows,
+            got: shape[0],
+        });
+    }
+    if shape[1] != cols {
+        return Err(MatrixError::DimensionMismatch {
+            expected: cols,
+            got: shape[1],
+        });
+    }
+    Ok(())
+}
+
+// 行优先连续存储时直接复用缓冲区，否则按逻辑顺序拷贝
+fn into_row_major<T: Clone>(array: Array2<T>) -> Vec<T> {
+    if !array.is_standard_layout() {
+        return array.iter().cloned().collect();
+    }
+    let len = array.len();
+    let (mut data, offset) = array.into_raw_vec_and_offset();
+    data.drain(..offset.unwrap_or(0));
+    data.truncate(len);
+    data
+}
+
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> From<MatrixBase<T, R, C, S>>
+    for Array2<T>
+{
+    fn from(matrix: MatrixBase<T, R, C, S>) -> Self {
+        Array2::from_shape_vec((R, C), matrix.into_vec())
+            .expect("matrix has exactly R * C elements")
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize, S: OwnedStorage<T>> TryFrom<Array2<T>>
+    for MatrixBase<T, R, C, S>
+{
+    type Error = MatrixError;
+
+    fn try_from(array: Array2<T>) -> Result<Self, MatrixError> {
+        check_shape(array.shape(), R, C)?;
+        Self::try_from(into_row_major(array))
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize, S: OwnedStorage<T>> TryFrom<ArrayView2<'_, T>>
+    for MatrixBase<T, R, C, S>
+{
+    type Error = MatrixError;
+
+    fn try_from(view: ArrayView2<'_, T>) -> Result<Self, MatrixError> {
+        check_shape(view.shape(), R, C)?;
+        Self::try_from(view.iter().cloned().collect::<Vec<_>>())
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize, S: Storage<T>> From<&'a MatrixBase<T, R, C, S>>
+    for ArrayView2<'a, T>
+{
+    fn from(matrix: &'a MatrixBase<T, R, C, S>) -> Self {
+        matrix.as_array_view()
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn as_array_view(&self) -> ArrayView2<'_, T> {
+        ArrayView2::from_shape((R, C), self.as_slice()).expect("matrix has exactly R * C elements")
+    }
+
+    pub fn as_array_view_mut(&mut self) -> ArrayViewMut2<'_, T>
+    where
+        S: StorageMut<T>,
+    {
+        ArrayViewMut2::from_shape((R, C), self.as_mut_slice())
+            .expect("matrix has exactly R * C elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+    use ndarray::{array, s};
+
+    #[test]
+    fn test_array2_round_trip_zero_copy() {
+        let m = DynMatrics::from([[1, 2, 3], [4, 5, 6]]);
+        let ptr = m.as_slice().as_ptr();
+        let array = Array2::from(m);
+        assert_eq!(array, array![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(array.as_ptr(), ptr);
+        let m = DynMatrics::<i32, 2, 3>::try_from(array).unwrap();
+        assert_eq!(m.as_slice().as_ptr(), ptr);
+
+        let mut array = array![[0, 0], [1, 2], [3, 4]];
+        array.slice_collapse(s![1.., ..]);
+        let m = Matrix::<i32, 2, 2>::try_from(array).unwrap();
+        assert_eq!(m, Matrix::from([[1, 2], [3, 4]]));
+    }
+
+    #[test]
+    fn test_non_standard_layout() {
+        let array = array![[1, 2], [3, 4], [5, 6]].reversed_axes();
+        let m = Matrix::<i32, 2, 3>::try_from(array).unwrap();
+        assert_eq!(m, Matrix::from([[1, 3, 5], [2, 4, 6]]));
+
+        let mut array = array![[1, 2, 3], [4, 5, 6]];
+        array.slice_collapse(s![.., 1..]);
+        let m = Matrix::<i32, 2, 2>::try_from(array).unwrap();
+        assert_eq!(m, Matrix::from([[2, 3], [5, 6]]));
+    }
+
+    #[test]
+    fn test_views() {
+        let mut m = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+        assert_eq!(m.as_array_view().sum(), 10.0);
+        m.as_array_view_mut().column_mut(0).fill(0.0);
+        assert_eq!(m, Matrix::from([[0.0, 2.0], [0.0, 4.0]]));
+        let copied = DynMatrics::<f64, 2, 2>::try_from(ArrayView2::from(&m)).unwrap();
+        assert_eq!(copied.as_slice(), m.as_slice());
+        assert!(Matrix::<f64, 1, 4>::try_from(m.as_array_view()).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use core::ops::{Add, Mul};
+
+use num_traits::{Float, Zero};
+
+use crate::{GemmElement, MatrixBase, OwnedMatrix, Storage};
+
+// 小型 MLP 推理用的层函数：每层只分配一次乘积，偏置与激活在同一遍中原地完成
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Activation {
+    #[default]
+    Identity,
+    Relu,
+    Sigmoid,
+    Tanh,
+}
+
+impl Activation {
+    pub fn apply<T: Float>(self, x: T) -> T {
+        match self {
+            Activation::Identity => x,
+            Activation::Relu => relu(x),
+            Activation::Sigmoid => T::one() / (T::one() + (-x).exp()),
+            Activation::Tanh => x.tanh(),
+        }
+    }
+}
+
+// NaN 保持为 NaN
+fn relu<T: Zero + PartialOrd>(x: T) -> T {
+    if x < T::zero() {
+        T::zero()
+    } else {
+        x
+    }
+}
+
+// 行优先的 rows×cols 数据逐行加上 bias 后再经过 f
+fn add_bias<T>(data: &mut [T], bias: &[T], f: impl Fn(T) -> T)
+where
+    T: Add<Output = T> + Clone,
+{
+    for row in data.chunks_mut(bias.len().max(1)) {
+        for (x, b) in row.iter_mut().zip(bias) {
+            *x = f(x.clone() + b.clone());
+        }
+    }
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // X·W + b，bias 是 1×Z 的行向量，加到每一行上
+    pub fn affine<const Z: usize, S1: Storage<T>, S2: Storage<T>>(
+        &self,
+        weights: &MatrixBase<T, Y, Z, S1>,
+        bias: &MatrixBase<T, 1, Z, S2>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        let mut result = self.dot_product(weights);
+        add_bias(result.as_mut_slice(), bias.as_slice(), |x| x);
+        result
+    }
+
+    // activation(X·W + b)，即一个全连接层
+    pub fn affine_activate<const Z: usize, S1: Storage<T>, S2: Storage<T>>(
+        &self,
+        weights: &MatrixBase<T, Y, Z, S1>,
+        bias: &MatrixBase<T, 1, Z, S2>,
+        activation: Activation,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Float + GemmElement,
+    {
+        let mut result = self.dot_product(weights);
+        add_bias(result.as_mut_slice(), bias.as_slice(), |x| {
+            activation.apply(x)
+        });
+        result
+    }
+
+    pub fn relu(&self) -> OwnedMatrix<S::Family, T, X, Y>
+    where
+        T: Zero + PartialOrd + Clone,
+    {
+        MatrixBase::from_fn(|i, j| relu(self[i][j].clone()))
+    }
+
+    pub fn sigmoid(&self) -> OwnedMatrix<S::Family, T, X, Y>
+    where
+        T: Float,
+    {
+        MatrixBase::from_fn(|i, j| Activation::Sigmoid.apply(self[i][j]))
+    }
+
+    pub fn tanh(&self) -> OwnedMatrix<S::Family, T, X, Y>
+    where
+        T: Float,
+    {
+        MatrixBase::from_fn(|i, j| self[i][j].tanh())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_affine_layers() {
+        let x = Matrix::from([[1, -2], [0, 3]]);
+        let w = Matrix::from([[2, 0, -1], [1, 1, 1]]);
+        let b = Matrix::from([[10, 0, -5]]);
+        let expected = Matrix::from([[10, -2, -8], [13, 3, -2]]);
+        assert_eq!(x.affine(&w, &b), expected);
+        assert_eq!(expected.relu(), Matrix::from([[10, 0, 0], [13, 3, 0]]));
+
+        // 两层 MLP，第一层 ReLU、第二层 sigmoid
+        let x = DynMatrics::from([[1.0, -1.0]]);
+        let w1 = DynMatrics::from([[1.0, -1.0], [1.0, 1.0]]);
+        let b1 = DynMatrics::from([[0.5, 0.0]]);
+        let hidden = x.affine_activate(&w1, &b1, Activation::Relu);
+        assert_eq!(hidden, DynMatrics::from([[0.5, 0.0]]));
+        assert_eq!(hidden, x.affine(&w1, &b1).relu());
+        let w2 = DynMatrics::from([[4.0], [1.0]]);
+        let b2 = DynMatrics::from([[-2.0]]);
+        let output = hidden.affine_activate(&w2, &b2, Activation::Sigmoid);
+        assert_eq!(output, DynMatrics::from([[0.5]]));
+        assert_eq!(
+            hidden.affine_activate(&w2, &b2, Activation::Tanh),
+            hidden.affine(&w2, &b2).tanh()
+        );
+        assert_eq!(
+            DynMatrics::from([[0.0f32]]).sigmoid(),
+            DynMatrics::from([[0.5]])
+        );
+    }
+}
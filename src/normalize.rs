@@ -0,0 +1,125 @@
+use num_traits::Float;
+
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// 对每一行原地调用 f；启用 std 且元素足够多时按行分块并行，分块规则与 dot_product_auto 相同
+fn for_each_row<T, F>(data: &mut [T], rows: usize, cols: usize, f: F)
+where
+    T: Send,
+    F: Fn(&mut [T]) + Sync,
+{
+    if data.is_empty() {
+        return;
+    }
+    #[cfg(feature = "std")]
+    {
+        let threads = crate::ParallelConfig::default().threads_for(rows, cols, 1);
+        if threads > 1 {
+            let block_rows = rows.div_ceil(threads);
+            crate::parallel::for_each_chunk(data, block_rows * cols, |_, chunk| {
+                chunk.chunks_mut(cols).for_each(&f)
+            });
+            return;
+        }
+    }
+    #[cfg(not(feature = "std"))]
+    let _ = rows;
+    data.chunks_mut(cols).for_each(f);
+}
+
+// 全零行没有方向，保持为零
+fn scale_by<T: Float>(row: &mut [T], norm: T) {
+    if norm > T::zero() {
+        row.iter_mut().for_each(|x| *x = *x / norm);
+    }
+}
+
+// 减去行最大值后再取指数，exp 不会上溢，结果与直接计算相同
+fn softmax<T: Float>(row: &mut [T]) {
+    let max = row.iter().fold(T::neg_infinity(), |m, &x| m.max(x));
+    let mut sum = T::zero();
+    for x in row.iter_mut() {
+        *x = (*x - max).exp();
+        sum = sum + *x;
+    }
+    row.iter_mut().for_each(|x| *x = *x / sum);
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S>
+where
+    T: Float + Send + Sync,
+{
+    fn map_rows(&self, f: impl Fn(&mut [T]) + Sync) -> OwnedMatrix<S::Family, T, R, C> {
+        let mut result = self.to_owned_matrix();
+        for_each_row(result.as_mut_slice(), R, C, f);
+        result
+    }
+
+    // 每行除以其 2-范数
+    pub fn normalize_rows_l2(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        self.map_rows(|row| {
+            let norm = row.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt();
+            scale_by(row, norm)
+        })
+    }
+
+    // 每行除以其 1-范数，非负的行因此和为 1
+    pub fn normalize_rows_l1(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        self.map_rows(|row| {
+            let norm = row.iter().fold(T::zero(), |sum, &x| sum + x.abs());
+            scale_by(row, norm)
+        })
+    }
+
+    // 逐行 softmax：exp(x - max) / Σ exp(x - max)
+    pub fn softmax_rows(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        self.map_rows(softmax)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_row_normalization() {
+        let m = Matrix::from([[3.0, 4.0], [0.0, 0.0], [-1.0, 3.0]]);
+        assert_eq!(
+            m.normalize_rows_l2(),
+            Matrix::from([
+                [0.6, 0.8],
+                [0.0, 0.0],
+                [-1.0 / 10f64.sqrt(), 3.0 / 10f64.sqrt()]
+            ])
+        );
+        assert_eq!(
+            m.normalize_rows_l1(),
+            Matrix::from([[3.0 / 7.0, 4.0 / 7.0], [0.0, 0.0], [-0.25, 0.75]])
+        );
+
+        // 直接计算 exp(1000) 会溢出为 inf
+        let logits = DynMatrics::from([[1000.0f32, 1000.0], [0.0, 2f32.ln()]]);
+        assert_eq!(
+            logits.softmax_rows(),
+            DynMatrics::from([[0.5, 0.5], [1.0 / 3.0, 2.0 / 3.0]])
+        );
+    }
+
+    #[test]
+    fn test_parallel_rows_match_serial() {
+        // 足够大时按行分块并行，结果与逐行计算一致
+        let m = Matrix::<f64, 300, 257>::from_fn(|i, j| ((i * 31 + j * 7) % 23) as f64 - 11.0);
+        let softmax = m.softmax_rows();
+        for i in [0, 150, 299] {
+            let mut row = m[i].to_vec();
+            super::softmax(&mut row);
+            assert_eq!(softmax[i], row[..]);
+        }
+        let mut sums = softmax
+            .as_slice()
+            .chunks(257)
+            .map(|row| row.iter().sum::<f64>());
+        assert!(sums.all(|s| (s - 1.0).abs() < 1e-12));
+    }
+}
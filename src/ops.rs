@@ -0,0 +1,197 @@
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::dynamic::DynMatrix;
+use crate::{gemm, GemmElement, MatrixBase, MatrixError, OwnedMatrix, Result, StorageMut};
+
+// 定长矩阵（任意存储）与 DynMatrix 的公共接口，算法只需针对该 trait 写一次
+// 元素均按行优先连续存放，默认方法都基于 as_slice 实现
+// transpose、get、shape 与各类型的固有方法签名相同，实现中直接转发；
+// 乘法的形状在运行时检查、返回 Result，与固有的 dot_product 不同，因此叫 try_dot_product
+// 行优先的 a（m×k）乘 b（k×n），形状在运行时检查
+pub(crate) fn dot_slices<T>(
+    a: &[T],
+    (m, k): (usize, usize),
+    b: &[T],
+    (b_rows, n): (usize, usize),
+) -> Result<DynMatrix<T>>
+where
+    T: Zero + Mul<Output = T> + Clone + GemmElement,
+{
+    if k != b_rows {
+        return Err(MatrixError::DimensionMismatch {
+            expected: k,
+            got: b_rows,
+        });
+    }
+    // SAFETY: gemm_dispatch 会写满全部 m * n 个元素
+    let data = unsafe { gemm::uninit_vec(m * n, |c| gemm::gemm_dispatch(a, b, m, k, n, c)) };
+    DynMatrix::new(m, n, data)
+}
+
+pub trait MatrixOps<T> {
+    type Transposed: MatrixOps<T>;
+
+    fn rows(&self) -> usize;
+
+    fn cols(&self) -> usize;
+
+    fn as_slice(&self) -> &[T];
+
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    fn transpose(&self) -> Self::Transposed
+    where
+        T: Clone;
+
+    fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows() && col < self.cols() {
+            self.as_slice().get(row * self.cols() + col)
+        } else {
+            None
+        }
+    }
+
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows() && col < self.cols() {
+            let cols = self.cols();
+            self.as_mut_slice().get_mut(row * cols + col)
+        } else {
+            None
+        }
+    }
+
+    fn row(&self, row: usize) -> Option<&[T]> {
+        let cols = self.cols();
+        (row < self.rows()).then(|| &self.as_slice()[row * cols..(row + 1) * cols])
+    }
+
+    // 两侧可以是不同的存储类型，形状在运行时检查，结果为 DynMatrix
+    fn try_dot_product<M>(&self, matrix1: &M) -> Result<DynMatrix<T>>
+    where
+        M: MatrixOps<T> + ?Sized,
+        T: Zero + Mul<Output = T> + Clone + GemmElement,
+    {
+        dot_slices(
+            self.as_slice(),
+            self.shape(),
+            matrix1.as_slice(),
+            matrix1.shape(),
+        )
+    }
+
+    fn to_dyn(&self) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        DynMatrix::new(self.rows(), self.cols(), self.as_slice().to_vec())
+            .expect("slice has exactly rows * cols elements")
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> MatrixOps<T> for MatrixBase<T, R, C, S> {
+    type Transposed = OwnedMatrix<S::Family, T, C, R>;
+
+    fn rows(&self) -> usize {
+        R
+    }
+
+    fn cols(&self) -> usize {
+        C
+    }
+
+    fn as_slice(&self) -> &[T] {
+        MatrixBase::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        MatrixBase::as_mut_slice(self)
+    }
+
+    fn transpose(&self) -> OwnedMatrix<S::Family, T, C, R>
+    where
+        T: Clone,
+    {
+        MatrixBase::transpose(self)
+    }
+}
+
+impl<T> MatrixOps<T> for DynMatrix<T> {
+    type Transposed = DynMatrix<T>;
+
+    fn rows(&self) -> usize {
+        DynMatrix::rows(self)
+    }
+
+    fn cols(&self) -> usize {
+        DynMatrix::cols(self)
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        DynMatrix::shape(self)
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        DynMatrix::get(self, row, col)
+    }
+
+    fn as_slice(&self) -> &[T] {
+        DynMatrix::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        DynMatrix::as_mut_slice(self)
+    }
+
+    fn transpose(&self) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        DynMatrix::transpose(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    // 只依赖 trait 的算法：A·Aᵀ 的迹，即所有元素的平方和
+    fn gram_trace<M: MatrixOps<i64>>(m: &M) -> i64 {
+        let gram = m.try_dot_product(&m.transpose()).unwrap();
+        (0..gram.rows()).map(|i| *gram.get(i, i).unwrap()).sum()
+    }
+
+    #[test]
+    fn test_generic_over_storage() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let d = DynMatrics::from([[1, 2, 3], [4, 5, 6]]);
+        let r = DynMatrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(gram_trace(&m), 91);
+        assert_eq!(gram_trace(&d), 91);
+        assert_eq!(gram_trace(&r), 91);
+
+        // 不同存储类型混合相乘
+        let product = m.try_dot_product(&r.transpose()).unwrap();
+        assert_eq!(product, DynMatrix::from(m.dot_product(&m.transpose())));
+        assert!(m.try_dot_product(&d).is_err());
+    }
+
+    #[test]
+    fn test_element_access() {
+        let mut m = DynMatrics::from([[1, 2], [3, 4]]);
+        assert_eq!(MatrixOps::shape(&m), (2, 2));
+        assert_eq!(MatrixOps::get(&m, 1, 0), Some(&3));
+        assert_eq!(MatrixOps::get(&m, 0, 2), None);
+        *MatrixOps::get_mut(&mut m, 0, 1).unwrap() = 20;
+        assert_eq!(m.row(0), Some(&[1, 20][..]));
+        assert_eq!(m.row(2), None);
+        assert_eq!(m.to_dyn(), DynMatrix::from([[1, 20], [3, 4]]));
+    }
+}
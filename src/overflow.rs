@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, WrappingAdd,
+    WrappingMul, Zero,
+};
+
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// 行优先的 x×y 与 y×z 相乘，step(sum, a, b) 返回 None 表示溢出
+fn dot_with<T, F>(a: &[T], b: &[T], x: usize, y: usize, z: usize, step: F) -> Option<Vec<T>>
+where
+    T: Zero,
+    F: Fn(T, &T, &T) -> Option<T>,
+{
+    let mut result = Vec::with_capacity(x * z);
+    for i in 0..x {
+        for j in 0..z {
+            let mut sum = T::zero();
+            for k in 0..y {
+                sum = step(sum, &a[i * y + k], &b[k * z + j])?;
+            }
+            result.push(sum);
+        }
+    }
+    Some(result)
+}
+
+fn zip_with<T>(a: &[T], b: &[T], op: impl Fn(&T, &T) -> Option<T>) -> Option<Vec<T>> {
+    a.iter().zip(b).map(|(x, y)| op(x, y)).collect()
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 任一元素的乘法或累加溢出时返回 None
+    pub fn checked_dot_product<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> Option<OwnedMatrix<S::Family, T, X, Z>>
+    where
+        T: Zero + CheckedAdd + CheckedMul,
+    {
+        let data = dot_with(self.as_slice(), matrix1.as_slice(), X, Y, Z, |sum, a, b| {
+            sum.checked_add(&a.checked_mul(b)?)
+        })?;
+        Some(MatrixBase::try_from(data).expect("product has exactly X * Z elements"))
+    }
+
+    // 乘法与累加均按补码回绕，与 release 模式下的原生整数运算一致
+    pub fn wrapping_dot_product<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + WrappingAdd + WrappingMul,
+    {
+        let data = dot_with(self.as_slice(), matrix1.as_slice(), X, Y, Z, |sum, a, b| {
+            Some(sum.wrapping_add(&a.wrapping_mul(b)))
+        })
+        .expect("wrapping arithmetic never fails");
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+
+    // 每一步乘法与累加都饱和到 T 的取值范围，结果与累加顺序有关
+    pub fn saturating_dot_product<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + SaturatingAdd + SaturatingMul,
+    {
+        let data = dot_with(self.as_slice(), matrix1.as_slice(), X, Y, Z, |sum, a, b| {
+            Some(sum.saturating_add(&a.saturating_mul(b)))
+        })
+        .expect("saturating arithmetic never fails");
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<OwnedMatrix<S::Family, T, X, Y>>
+    where
+        T: CheckedAdd,
+    {
+        let data = zip_with(self.as_slice(), other.as_slice(), T::checked_add)?;
+        Some(MatrixBase::try_from(data).expect("sum has exactly X * Y elements"))
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<OwnedMatrix<S::Family, T, X, Y>>
+    where
+        T: CheckedSub,
+    {
+        let data = zip_with(self.as_slice(), other.as_slice(), T::checked_sub)?;
+        Some(MatrixBase::try_from(data).expect("difference has exactly X * Y elements"))
+    }
+
+    pub fn checked_scale(&self, scalar: &T) -> Option<OwnedMatrix<S::Family, T, X, Y>>
+    where
+        T: CheckedMul,
+    {
+        let data: Option<Vec<T>> = self
+            .as_slice()
+            .iter()
+            .map(|value| value.checked_mul(scalar))
+            .collect();
+        Some(MatrixBase::try_from(data?).expect("scaled matrix has exactly X * Y elements"))
+    }
+
+    // 除数为零，或整数除法溢出（如 i32::MIN / -1）时返回 None
+    pub fn checked_div_scalar(&self, scalar: &T) -> Option<OwnedMatrix<S::Family, T, X, Y>>
+    where
+        T: CheckedDiv,
+    {
+        let data: Option<Vec<T>> = self
+            .as_slice()
+            .iter()
+            .map(|value| value.checked_div(scalar))
+            .collect();
+        Some(MatrixBase::try_from(data?).expect("scaled matrix has exactly X * Y elements"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_checked_dot_product() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[2, 0], [1, 2]]);
+        assert_eq!(a.checked_dot_product(&b), Some(a.dot_product(&b)));
+
+        // 单个乘积不溢出，但累加溢出
+        let big = Matrix::from([[i32::MAX / 2 + 1, i32::MAX / 2 + 1]]);
+        let ones = Matrix::from([[1], [1]]);
+        assert_eq!(big.checked_dot_product(&ones), None);
+        let wide = DynMatrics::from([[i32::MAX]]);
+        assert_eq!(wide.checked_dot_product(&DynMatrics::from([[2]])), None);
+    }
+
+    #[test]
+    fn test_checked_elementwise() {
+        let a = DynMatrics::from([[1u8, 200], [3, 4]]);
+        let b = DynMatrics::from([[1u8, 100], [3, 4]]);
+        assert_eq!(a.checked_add(&b), None);
+        assert_eq!(
+            a.checked_sub(&b),
+            Some(DynMatrics::from([[0, 100], [0, 0]]))
+        );
+        assert_eq!(b.checked_sub(&a), None);
+        assert_eq!(
+            Matrix::from([[1u8, 2]]).checked_scale(&100),
+            Some(Matrix::from([[100, 200]]))
+        );
+        assert_eq!(Matrix::from([[1u8, 3]]).checked_scale(&100), None);
+        assert_eq!(
+            a.checked_div_scalar(&2),
+            Some(DynMatrics::from([[0, 100], [1, 2]]))
+        );
+        assert_eq!(a.checked_div_scalar(&0), None);
+        assert_eq!(Matrix::from([[i8::MIN]]).checked_div_scalar(&-1), None);
+    }
+
+    #[test]
+    fn test_wrapping_and_saturating() {
+        let samples = Matrix::from([[i16::MAX, i16::MAX], [-3, 4]]);
+        let gains = Matrix::from([[2], [1]]);
+        assert_eq!(
+            samples.wrapping_dot_product(&gains),
+            Matrix::from([[i16::MAX.wrapping_mul(3)], [-2]])
+        );
+        assert_eq!(
+            samples.saturating_dot_product(&gains),
+            Matrix::from([[i16::MAX], [-2]])
+        );
+        let negative = DynMatrics::from([[i16::MIN, -1]]);
+        assert_eq!(
+            negative.saturating_dot_product(&DynMatrics::from([[1], [1]]))[0][0],
+            i16::MIN
+        );
+        assert_eq!(
+            negative.wrapping_dot_product(&DynMatrics::from([[1], [1]]))[0][0],
+            i16::MAX
+        );
+    }
+}
@@ -0,0 +1,812 @@
+use std::any::Any;
+use std::mem::MaybeUninit;
+use std::ops::{Mul, Range};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Mutex;
+
+use num_traits::Zero;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+use crate::gemm::{self, Output};
+use crate::transpose;
+use crate::{GemmElement, MatrixBase, MatrixError, OwnedMatrix, OwnedStorage, Storage};
+
+// 控制并行乘法的线程数；工作量（乘加次数）不足时少开线程甚至串行执行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParallelConfig {
+    threads: usize,
+    min_work_per_thread: usize,
+}
+
+impl Default for ParallelConfig {
+    fn default() -> Self {
+        ParallelConfig {
+            threads: num_cpus::get(),
+            min_work_per_thread: 1 << 16,
+        }
+    }
+}
+
+impl ParallelConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 线程数上限，0 视为 1
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    pub fn min_work_per_thread(mut self, work: usize) -> Self {
+        self.min_work_per_thread = work.max(1);
+        self
+    }
+
+    // x×y 乘 y×z 时实际使用的线程数，不超过输出元素个数
+    pub fn threads_for(&self, x: usize, y: usize, z: usize) -> usize {
+        // 超出 usize 的工作量按上限计，只会多开线程而不会溢出
+        let work = x.saturating_mul(y).saturating_mul(z);
+        (work / self.min_work_per_thread)
+            .min(self.threads)
+            .min(x.saturating_mul(z))
+            .max(1)
+    }
+}
+
+// parallel 为 0 时按 1 处理（与 ParallelConfig::threads 一致），避免在切块时除零；
+// 超过任务数的部分没有意义，截断
+pub(crate) fn validate(parallel: usize, tasks: usize) -> usize {
+    parallel.clamp(1, tasks.max(1))
+}
+
+// 并行处理若干互不重叠的块，f 接收块序号与块本身
+// 启用 `rayon` 特性时交给 Rayon 的全局线程池，否则每块开一个作用域线程，线程名为 matrix-parallel-<块序号>
+// wasm32-unknown-unknown 上标准库无法创建线程，退化为在当前线程依次执行
+pub(crate) fn for_each_part<T, F>(parts: Vec<&mut [T]>, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    for (i, part) in parts.into_iter().enumerate() {
+        f(i, part);
+    }
+
+    #[cfg(all(
+        feature = "rayon",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    {
+        use rayon::prelude::*;
+        parts
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, part)| f(i, part));
+    }
+
+    #[cfg(not(any(feature = "rayon", all(target_arch = "wasm32", target_os = "unknown"))))]
+    std::thread::scope(|scope| {
+        let f = &f;
+        for (i, part) in parts.into_iter().enumerate() {
+            std::thread::Builder::new()
+                .name(format!("matrix-parallel-{}", i))
+                .spawn_scoped(scope, move || f(i, part))
+                .expect("failed to spawn matrix thread");
+        }
+    });
+}
+
+// 动态调度：parts 放进共享队列，workers 个线程循环领取，先完成的线程继续处理剩余块
+// 启用 `rayon` 时改为向 Rayon 的全局线程池提交 workers 个同样循环领取的任务，同时处理的块不超过 workers 个
+pub(crate) fn for_each_part_dynamic<T, F>(parts: Vec<&mut [T]>, workers: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    if let Err(err) = try_for_each_part_dynamic(parts, workers, f) {
+        panic!("{}", err);
+    }
+}
+
+// 与 for_each_part_dynamic 相同，无法创建线程时返回 SpawnFailed 而不是 panic；
+// 此前已创建的线程仍会处理完队列中的全部块
+pub(crate) fn try_for_each_part_dynamic<T, F>(
+    parts: Vec<&mut [T]>,
+    workers: usize,
+    f: F,
+) -> Result<(), MatrixError>
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    let workers = workers.min(parts.len());
+    let queue = Mutex::new(parts.into_iter().enumerate());
+    let work = || loop {
+        // 取出任务后立即释放锁，任务 panic 不会毒化队列
+        let next = queue.lock().unwrap().next();
+        match next {
+            Some((i, part)) => f(i, part),
+            None => break,
+        }
+    };
+
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    {
+        let _ = workers;
+        work();
+        Ok(())
+    }
+
+    #[cfg(all(
+        feature = "rayon",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    {
+        rayon::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(move |_| work());
+            }
+        });
+        Ok(())
+    }
+
+    #[cfg(not(any(feature = "rayon", all(target_arch = "wasm32", target_os = "unknown"))))]
+    {
+        std::thread::scope(|scope| {
+            for w in 0..workers {
+                std::thread::Builder::new()
+                    .name(format!("matrix-parallel-{}", w))
+                    .spawn_scoped(scope, work)
+                    .map_err(|err| MatrixError::SpawnFailed {
+                        message: err.to_string(),
+                    })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+// 把 data 按 chunk_len 切块并行处理
+pub(crate) fn for_each_chunk<T, F>(data: &mut [T], chunk_len: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    if data.is_empty() {
+        return;
+    }
+    for_each_part(data.chunks_mut(chunk_len).collect(), f);
+}
+
+const BLOCKS_PER_THREAD: usize = 4;
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+// 执行负责 rows 这些结果行的一块计算，捕获其中的 panic；多个块失败时只保留第一个
+fn guarded(failure: &Mutex<Option<MatrixError>>, rows: Range<usize>, f: impl FnOnce()) {
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(f)) {
+        failure
+            .lock()
+            .unwrap()
+            .get_or_insert(MatrixError::WorkerPanicked {
+                start_row: rows.start,
+                end_row: rows.end,
+                message: panic_message(payload.as_ref()),
+            });
+    }
+}
+
+// 并行计算 C = A·B（x×y 乘 y×z），C 可以尚未初始化
+// 行数不少于线程数时按行分块；否则每行再按列切分，避免多余的线程空闲
+// 某块 panic 时其余块照常完成，返回 WorkerPanicked；此时 C 中该块的内容不确定
+// 每块都经过 gemm_dispatch，f16/bf16 与串行乘法一样在 f32 中累加
+pub(crate) fn try_gemm<T, S>(
+    a: &[T],
+    b: &[T],
+    x: usize,
+    y: usize,
+    z: usize,
+    c: &mut [S],
+    parallel: usize,
+) -> Result<(), MatrixError>
+where
+    T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    S: Output<T> + Send,
+{
+    let parallel = validate(parallel, x * z);
+    if c.is_empty() {
+        return Ok(());
+    }
+    let failure = Mutex::new(None);
+    if x >= parallel {
+        // 每个线程平均分到若干个小块，避免最慢的线程拖住整体
+        let block_rows = x.div_ceil(parallel * BLOCKS_PER_THREAD);
+        let parts = c.chunks_mut(block_rows * z).collect();
+        for_each_part_dynamic(parts, parallel, |i, chunk| {
+            let start_index = i * block_rows; // 计算全局行的起始索引
+            let rows = chunk.len() / z;
+            guarded(&failure, start_index..start_index + rows, || {
+                let local_a = &a[start_index * y..(start_index + rows) * y];
+                gemm::gemm_dispatch(local_a, b, rows, y, z, chunk);
+            });
+        });
+    } else {
+        let col_chunk = z.div_ceil(parallel.div_ceil(x)); // 每行切成若干列段
+        let pieces = z.div_ceil(col_chunk);
+        let parts = c
+            .chunks_mut(z)
+            .flat_map(|row| row.chunks_mut(col_chunk))
+            .collect();
+        for_each_part_dynamic(parts, parallel, |p, part| {
+            let (row, start) = (p / pieces, p % pieces * col_chunk);
+            guarded(&failure, row..row + 1, || {
+                let local_a = &a[row * y..(row + 1) * y];
+                gemm::gemm_cols_dispatch(local_a, b, 1, y, z, start..start + part.len(), part);
+            });
+        });
+    }
+    match failure.into_inner().unwrap() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+// 与 try_gemm 相同，工作线程 panic 时在调用线程上重新 panic，信息中带有失败的行范围
+pub(crate) fn gemm<T, S>(
+    a: &[T],
+    b: &[T],
+    x: usize,
+    y: usize,
+    z: usize,
+    c: &mut [S],
+    parallel: usize,
+) where
+    T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    S: Output<T> + Send,
+{
+    if let Err(err) = try_gemm(a, b, x, y, z, c, parallel) {
+        panic!("{}", err);
+    }
+}
+
+// 并行转置 rows×cols 的 src，按结果的行（src 的列）切块，out 可以尚未初始化
+pub(crate) fn transpose<T, S>(src: &[T], rows: usize, cols: usize, out: &mut [S], parallel: usize)
+where
+    T: Clone + Send + Sync,
+    S: Output<T> + Send,
+{
+    let parallel = validate(parallel, cols);
+    if out.is_empty() {
+        return;
+    }
+    let block_rows = cols.div_ceil(parallel);
+    for_each_chunk(out, block_rows * rows, |i, chunk| {
+        let start = i * block_rows;
+        let range = start..start + chunk.len() / rows;
+        transpose::transpose_rows(src, rows, cols, range, chunk);
+    });
+}
+
+// 运行时尺寸的 C = A·B：a 为 m×k、b 为 k×n，均为行优先；parallel 为 0 或 1 时串行
+// 供命令行工具等编译期不知道维度的场景使用
+pub fn dot_product_slices<T>(
+    a: &[T],
+    b: &[T],
+    m: usize,
+    k: usize,
+    n: usize,
+    parallel: usize,
+) -> crate::Result<Vec<T>>
+where
+    T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+{
+    // 维度来自运行时输入，乘积溢出时按 usize::MAX 报告
+    for (slice, rows, cols) in [(a, m, k), (b, k, n)] {
+        match rows.checked_mul(cols) {
+            Some(expected) if expected == slice.len() => {}
+            expected => {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: expected.unwrap_or(usize::MAX),
+                    got: slice.len(),
+                })
+            }
+        }
+    }
+    // k 为 0 时两个输入都为空，结果的 m * n 仍可能溢出，此时报告 n 列下允许的最大行数
+    if m.checked_mul(n).is_none() {
+        return Err(MatrixError::DimensionMismatch {
+            expected: usize::MAX / n,
+            got: m,
+        });
+    }
+    // SAFETY: 两种 gemm 都会写满全部 m * n 个元素
+    let data = unsafe {
+        gemm::uninit_vec(m * n, |c| match parallel {
+            0 | 1 => gemm::gemm_dispatch(a, b, m, k, n, c),
+            _ => gemm(a, b, m, k, n, c, parallel),
+        })
+    };
+    Ok(data)
+}
+
+// 按行分块并行构造 rows×cols 个元素，fill 接收块序号、块的起始行与块本身，必须写满整块
+fn build_rows<T, F>(rows: usize, cols: usize, parallel: usize, fill: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize, usize, &mut [MaybeUninit<T>]) + Sync,
+{
+    let block_rows = rows.div_ceil(validate(parallel, rows)).max(1);
+    // SAFETY: 每块都由 fill 写满；fill panic 时已写入的元素只会泄漏
+    unsafe {
+        gemm::uninit_vec(rows * cols, |data| {
+            for_each_chunk(data, block_rows * cols.max(1), |i, chunk| {
+                fill(i, i * block_rows, chunk)
+            })
+        })
+    }
+}
+
+// 为 len 项中的每一项准备 size 个零，按批次切块并行调用 f(下标, 结果)
+fn batched<T, F>(len: usize, size: usize, parallel: usize, f: F) -> Vec<Vec<T>>
+where
+    T: Zero + Clone + Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    let mut results: Vec<Vec<T>> = (0..len).map(|_| vec![T::zero(); size]).collect();
+    let chunk_len = len.div_ceil(validate(parallel, len));
+    for_each_chunk(&mut results, chunk_len, |i, chunk| {
+        for (k, out) in chunk.iter_mut().enumerate() {
+            f(i * chunk_len + k, out.as_mut_slice());
+        }
+    });
+    results
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    pub fn dot_product_with_config<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+        config: &ParallelConfig,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        match config.threads_for(X, Y, Z) {
+            1 => self.dot_product(matrix1),
+            threads => self.dot_product_in_parallel(matrix1, threads),
+        }
+    }
+
+    // 按矩阵规模和 CPU 核数自动选择串行或并行
+    pub fn dot_product_auto<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        self.dot_product_with_config(matrix1, &ParallelConfig::default())
+    }
+
+    pub fn map_in_parallel<U, F>(&self, f: F, parallel: usize) -> OwnedMatrix<S::Family, U, X, Y>
+    where
+        T: Sync,
+        U: Default + Send,
+        F: Fn(&T) -> U + Sync,
+    {
+        let mut result = OwnedMatrix::<S::Family, U, X, Y>::default();
+        let source = self.as_slice();
+        let chunk_len = (X * Y).div_ceil(validate(parallel, X * Y));
+        for_each_chunk(result.as_mut_slice(), chunk_len, |i, chunk| {
+            let start = i * chunk_len;
+            for (target, value) in chunk.iter_mut().zip(&source[start..]) {
+                *target = f(value);
+            }
+        });
+        result
+    }
+
+    pub fn add_in_parallel(&self, other: &Self, parallel: usize) -> OwnedMatrix<S::Family, T, X, Y>
+    where
+        T: Zero + Clone + Send + Sync,
+    {
+        let mut result = OwnedMatrix::<S::Family, T, X, Y>::zero();
+        let (a, b) = (self.as_slice(), other.as_slice());
+        let chunk_len = (X * Y).div_ceil(validate(parallel, X * Y));
+        for_each_chunk(result.as_mut_slice(), chunk_len, |i, chunk| {
+            let start = i * chunk_len;
+            for ((target, x), y) in chunk.iter_mut().zip(&a[start..]).zip(&b[start..]) {
+                *target = x.clone() + y.clone();
+            }
+        });
+        result
+    }
+}
+
+impl<T, const X: usize, const Y: usize, S: OwnedStorage<T>> MatrixBase<T, X, Y, S> {
+    // 按行分块，在所有 CPU 核上并行调用 f
+    pub fn from_fn_par<F>(f: F) -> Self
+    where
+        T: Send,
+        F: Fn(usize, usize) -> T + Sync,
+    {
+        let parallel = ParallelConfig::default().threads;
+        let data = build_rows(X, Y, parallel, |_, start, chunk| {
+            for (k, slot) in chunk.iter_mut().enumerate() {
+                let index = start * Y + k;
+                slot.write(f(index / Y, index % Y));
+            }
+        });
+        MatrixBase::try_from(data).expect("from_fn_par has exactly X * Y elements")
+    }
+
+    // 按行分成 threads 块，第 i 块使用 rng_factory(i) 返回的随机数发生器，按 Standard 分布取值
+    // 分块只取决于 threads，rng_factory 给出固定种子时结果可以复现
+    pub fn random_par<G, F>(rng_factory: F, threads: usize) -> Self
+    where
+        T: Send,
+        Standard: Distribution<T>,
+        G: Rng,
+        F: Fn(usize) -> G + Sync,
+    {
+        let data = build_rows(X, Y, threads, |i, _, chunk| {
+            let mut rng = rng_factory(i);
+            for slot in chunk {
+                slot.write(rng.gen());
+            }
+        });
+        MatrixBase::try_from(data).expect("random_par has exactly X * Y elements")
+    }
+
+    // 批量乘法 lhs[i]·rhs[i]：按批次切块并行，每个乘法在一个线程内串行完成，
+    // 适合大量小矩阵；两个切片长度不同时返回 DimensionMismatch
+    pub fn dot_product_batched<const Z: usize>(
+        lhs: &[Self],
+        rhs: &[OwnedMatrix<S::Family, T, Y, Z>],
+        parallel: usize,
+    ) -> crate::Result<Vec<OwnedMatrix<S::Family, T, X, Z>>>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        if lhs.len() != rhs.len() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: lhs.len(),
+                got: rhs.len(),
+            });
+        }
+        // 只把元素切片交给工作线程，不要求存储类型本身可以跨线程共享
+        let a: Vec<&[T]> = lhs.iter().map(|m| m.as_slice()).collect();
+        let b: Vec<&[T]> = rhs.iter().map(|m| m.as_slice()).collect();
+        let results = batched(lhs.len(), X * Z, parallel, |i, out| {
+            gemm::gemm_dispatch(a[i], b[i], X, Y, Z, out)
+        });
+        Ok(into_matrices(results))
+    }
+
+    // 所有 lhs[i] 乘同一个 rhs
+    pub fn dot_product_batched_shared<const Z: usize>(
+        lhs: &[Self],
+        rhs: &MatrixBase<T, Y, Z, impl Storage<T>>,
+        parallel: usize,
+    ) -> Vec<OwnedMatrix<S::Family, T, X, Z>>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        let a: Vec<&[T]> = lhs.iter().map(|m| m.as_slice()).collect();
+        let b = rhs.as_slice();
+        let results = batched(lhs.len(), X * Z, parallel, |i, out| {
+            gemm::gemm_dispatch(a[i], b, X, Y, Z, out)
+        });
+        into_matrices(results)
+    }
+}
+
+fn into_matrices<T, const R: usize, const C: usize, S: OwnedStorage<T>>(
+    results: Vec<Vec<T>>,
+) -> Vec<MatrixBase<T, R, C, S>> {
+    results
+        .into_iter()
+        .map(|data| MatrixBase::try_from(data).expect("product has exactly R * C elements"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_map_and_add_in_parallel() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(
+            m.map_in_parallel(|x| x * 10, 4),
+            Matrix::from([[10, 20, 30], [40, 50, 60]])
+        );
+        let d = DynMatrics::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(
+            d.add_in_parallel(&d, 2),
+            DynMatrics::from([[2.0, 4.0], [6.0, 8.0], [10.0, 12.0]])
+        );
+        assert_eq!(
+            m.map_in_parallel(|&x| x % 2 == 0, 100),
+            Matrix::from([[false, true, false], [true, false, true]])
+        );
+    }
+
+    #[test]
+    fn test_parallel_construction() {
+        let expected = Matrix::<usize, 37, 5>::from_fn(|i, j| i * 100 + j);
+        assert_eq!(Matrix::from_fn_par(|i, j| i * 100 + j), expected);
+        assert_eq!(
+            DynMatrics::<usize, 37, 5>::from_fn_par(|i, j| i * 100 + j).as_slice(),
+            expected.as_slice()
+        );
+
+        let seeded = |i: usize| crate::bench_utils::seeded_rng(i as u64);
+        let a = Matrix::<f64, 50, 8>::random_par(seeded, 4);
+        assert_eq!(a, Matrix::random_par(seeded, 4));
+        assert!(a.as_slice().iter().all(|x| (0.0..1.0).contains(x)));
+        // 第一块与单个 rng 顺序生成的结果相同
+        let b = DynMatrics::<u32, 50, 8>::random_par(seeded, 1);
+        assert_eq!(b, DynMatrics::random(&mut seeded(0)));
+    }
+
+    #[test]
+    fn test_two_dimensional_partition() {
+        // 行数少于线程数时按列切分
+        let a: Vec<i64> = (0..3 * 20).map(|i| i % 5 - 2).collect();
+        let b: Vec<i64> = (0..20 * 17).map(|i| i % 3 - 1).collect();
+        let a = Matrix::<i64, 3, 20>::try_from(a).unwrap();
+        let b = Matrix::<i64, 20, 17>::try_from(b).unwrap();
+        let expected = a.dot_product(&b);
+        for parallel in [1, 2, 3, 4, 7, 16, 64] {
+            assert_eq!(a.dot_product_in_parallel(&b, parallel), expected);
+        }
+        let row = DynMatrics::from([[1.0, 2.0, 3.0]]);
+        assert_eq!(
+            row.dot_product_in_parallel(&row.transpose(), 8),
+            DynMatrics::from([[14.0]])
+        );
+    }
+
+    #[test]
+    fn test_parallel_config() {
+        let config = ParallelConfig::new().threads(8).min_work_per_thread(1000);
+        assert_eq!(config.threads_for(10, 10, 10), 1);
+        assert_eq!(config.threads_for(100, 10, 10), 8);
+        // 不超过输出元素个数
+        assert_eq!(config.threads_for(1, 100_000, 2), 2);
+        assert_eq!(
+            ParallelConfig::new().threads(0).threads_for(100, 100, 100),
+            1
+        );
+        // 工作量超出 usize 时不溢出
+        let huge = usize::MAX / 2;
+        assert_eq!(config.threads_for(huge, huge, huge), 8);
+
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let tiny = ParallelConfig::new().threads(4).min_work_per_thread(1);
+        assert_eq!(a.dot_product_with_config(&a, &tiny), a.dot_product(&a));
+        assert_eq!(a.dot_product_auto(&a), a.dot_product(&a));
+    }
+
+    #[test]
+    fn test_parallel_argument_validation() {
+        let a = DynMatrics::from([[1, 2], [3, 4]]);
+        // 线程数多于输出元素时截断
+        assert_eq!(a.dot_product_in_parallel(&a, 1000), a.dot_product(&a));
+        assert_eq!(a.map_in_parallel(|x| x + 1, 1000)[1][1], 5);
+        // 0 按串行处理
+        assert_eq!(a.dot_product_in_parallel(&a, 0), a.dot_product(&a));
+        assert_eq!(a.transpose_in_parallel(0), a.transpose());
+    }
+
+    #[test]
+    fn test_dynamic_scheduling() {
+        let mut data = vec![0usize; 25];
+        let parts = data.chunks_mut(3).collect();
+        let owners = Mutex::new(Vec::new());
+        let run = || {
+            for_each_part_dynamic(parts, 2, |i, part| {
+                // 第一个块远慢于其余块，其余块应由另一个线程领取
+                let cost = if i == 0 { 50 } else { 1 };
+                std::thread::sleep(std::time::Duration::from_millis(cost));
+                part.fill(i + 1);
+                owners
+                    .lock()
+                    .unwrap()
+                    .push((i, std::thread::current().id()));
+            })
+        };
+        // 全局线程池在单核机器上只有一个线程，这里放进两个线程的池中运行
+        #[cfg(feature = "rayon")]
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap()
+            .install(run);
+        #[cfg(not(feature = "rayon"))]
+        run();
+        let expected: Vec<usize> = (0..25).map(|k| k / 3 + 1).collect();
+        assert_eq!(data, expected);
+
+        let owners = owners.into_inner().unwrap();
+        assert_eq!(owners.len(), 9);
+        let slow = owners.iter().find(|(i, _)| *i == 0).unwrap().1;
+        let by_slow = owners.iter().filter(|(_, id)| *id == slow).count();
+        // 处理慢块的线程不应再领到大部分块
+        assert!(by_slow < owners.len() / 2, "{:?}", owners);
+    }
+
+    #[test]
+    fn test_dynamic_workers_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let active = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let mut data = vec![0usize; 32];
+        let parts = data.chunks_mut(2).collect();
+        for_each_part_dynamic(parts, 2, |i, part| {
+            let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            part.fill(i);
+            active.fetch_sub(1, Ordering::SeqCst);
+        });
+        // 同时处理的块数不能超过 workers
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(data, (0..32).map(|k| k / 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_dot_product_slices() {
+        let a = [1, 2, 3, 4, 5, 6];
+        let b = [1, 0, 0, 1, 1, 1];
+        let expected = Matrix::from([[1, 2, 3], [4, 5, 6]])
+            .dot_product(&Matrix::from([[1, 0], [0, 1], [1, 1]]))
+            .into_vec();
+        for parallel in [1, 3] {
+            assert_eq!(
+                dot_product_slices(&a, &b, 2, 3, 2, parallel).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            dot_product_slices(&a, &b, 3, 3, 2, 1),
+            Err(MatrixError::DimensionMismatch {
+                expected: 9,
+                got: 6
+            })
+        );
+        // 0 与其它并行接口一样按串行处理
+        assert_eq!(dot_product_slices(&a, &b, 2, 3, 2, 0).unwrap(), expected);
+        // m * k 溢出时不能回绕成一个恰好匹配的长度
+        let huge = 1 << (usize::BITS / 2);
+        assert_eq!(
+            dot_product_slices::<i32>(&[], &[], huge, huge, 0, 1),
+            Err(MatrixError::DimensionMismatch {
+                expected: usize::MAX,
+                got: 0
+            })
+        );
+        assert!(dot_product_slices::<i32>(&[], &[], huge, 0, huge, 1).is_err());
+    }
+
+    // 乘数为 13 时 panic 的元素类型
+    #[derive(Debug, Clone, PartialEq)]
+    struct Fragile(i32);
+
+    impl std::ops::Add for Fragile {
+        type Output = Fragile;
+
+        fn add(self, rhs: Fragile) -> Fragile {
+            Fragile(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul for Fragile {
+        type Output = Fragile;
+
+        fn mul(self, rhs: Fragile) -> Fragile {
+            assert!(self.0 != 13, "unlucky element");
+            Fragile(self.0 * rhs.0)
+        }
+    }
+
+    impl Zero for Fragile {
+        fn zero() -> Self {
+            Fragile(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    #[test]
+    fn test_worker_panic() {
+        let a = Matrix::<Fragile, 40, 3>::from_fn(|i, _| Fragile(i as i32));
+        let b = Matrix::<Fragile, 3, 2>::from_fn(|_, _| Fragile(1));
+        match a.try_dot_product_in_parallel(&b, 4) {
+            Err(MatrixError::WorkerPanicked {
+                start_row,
+                end_row,
+                message,
+            }) => {
+                assert!((start_row..end_row).contains(&13));
+                assert_eq!(message, "unlucky element");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        let ok = Matrix::<Fragile, 12, 3>::from_fn(|i, _| Fragile(i as i32));
+        assert_eq!(
+            ok.try_dot_product_in_parallel(&b, 4),
+            Ok(ok.dot_product(&b))
+        );
+
+        // 不返回 Result 的版本在调用线程上 panic，信息中带有行范围
+        let payload = std::panic::catch_unwind(|| a.dot_product_in_parallel(&b, 4)).unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("worker panicked while computing rows "));
+        assert!(message.ends_with(": unlucky element"));
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            let mut names = vec![String::new(); 2];
+            for_each_chunk(&mut names, 1, |_, name| {
+                name[0] = std::thread::current()
+                    .name()
+                    .unwrap_or_default()
+                    .to_string();
+            });
+            assert!(names
+                .iter()
+                .all(|name| name.starts_with("matrix-parallel-")));
+        }
+    }
+
+    #[test]
+    fn test_dot_product_batched() {
+        let lhs: Vec<_> = (0..37)
+            .map(|k| Matrix::<i64, 4, 4>::from_fn(|i, j| (i * 4 + j + k) as i64 % 9))
+            .collect();
+        let rhs: Vec<_> = (0..37)
+            .map(|k| Matrix::<i64, 4, 2>::from_fn(|i, j| (i + j * k) as i64 % 5 - 2))
+            .collect();
+        let products = Matrix::dot_product_batched(&lhs, &rhs, 4).unwrap();
+        assert_eq!(products.len(), 37);
+        for ((a, b), c) in lhs.iter().zip(&rhs).zip(&products) {
+            assert_eq!(&a.dot_product(b), c);
+        }
+        let shared = Matrix::dot_product_batched_shared(&lhs, &rhs[3], 5);
+        for (a, c) in lhs.iter().zip(&shared) {
+            assert_eq!(a.dot_product(&rhs[3]), *c);
+        }
+
+        assert_eq!(
+            Matrix::dot_product_batched(&lhs[1..], &rhs, 2),
+            Err(MatrixError::DimensionMismatch {
+                expected: 36,
+                got: 37
+            })
+        );
+        let empty: [DynMatrics<i64, 2, 2>; 0] = [];
+        assert!(DynMatrics::dot_product_batched_shared(
+            &empty,
+            &DynMatrics::<i64, 2, 1>::zero(),
+            3
+        )
+        .is_empty());
+    }
+}
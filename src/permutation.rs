@@ -0,0 +1,178 @@
+use num_traits::{One, Zero};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{Matrix, MatrixError, Result};
+
+// 检查 indices 是否为 0..len 的一个排列：越界报告 IndexOutOfBounds，重复报告 InvalidPermutation
+// 运行时长度的重排（如 DynMatrix::reorder_rows）也用它检查
+pub(crate) fn check(indices: &[usize]) -> Result<()> {
+    let mut seen = alloc::vec![false; indices.len()];
+    for (position, &index) in indices.iter().enumerate() {
+        if index >= indices.len() {
+            return Err(MatrixError::IndexOutOfBounds {
+                row: position,
+                col: index,
+            });
+        }
+        if core::mem::replace(&mut seen[index], true) {
+            return Err(MatrixError::InvalidPermutation { position, index });
+        }
+    }
+    Ok(())
+}
+
+// 置换矩阵只存下标：对应的稠密矩阵 P 第 i 行只在第 indices[i] 列为 1
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Permutation<const N: usize> {
+    indices: [usize; N],
+}
+
+impl<const N: usize> Default for Permutation<N> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<const N: usize> TryFrom<[usize; N]> for Permutation<N> {
+    type Error = MatrixError;
+
+    fn try_from(indices: [usize; N]) -> Result<Self> {
+        check(&indices)?;
+        Ok(Permutation { indices })
+    }
+}
+
+impl<const N: usize> Permutation<N> {
+    pub fn identity() -> Self {
+        Permutation {
+            indices: core::array::from_fn(|i| i),
+        }
+    }
+
+    // 均匀随机的置换，可用于打乱数据行
+    pub fn random(rng: &mut impl Rng) -> Self {
+        let mut permutation = Self::identity();
+        permutation.indices.shuffle(rng);
+        permutation
+    }
+
+    // 交换 i、j 两行的置换
+    pub fn swap(i: usize, j: usize) -> Self {
+        let mut permutation = Self::identity();
+        permutation.indices.swap(i, j);
+        permutation
+    }
+
+    pub fn indices(&self) -> &[usize; N] {
+        &self.indices
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut inverse = [0; N];
+        for (i, &j) in self.indices.iter().enumerate() {
+            inverse[j] = i;
+        }
+        Permutation { indices: inverse }
+    }
+
+    // self·other，先对矩阵应用 other 再应用 self
+    pub fn compose(&self, other: &Permutation<N>) -> Self {
+        Permutation {
+            indices: core::array::from_fn(|i| other.indices[self.indices[i]]),
+        }
+    }
+
+    // 奇置换为 -1，偶置换为 1，即 det(P)
+    pub fn sign(&self) -> i8 {
+        let mut visited = [false; N];
+        let mut sign = 1;
+        for start in 0..N {
+            let mut i = start;
+            let mut length = 0;
+            while !visited[i] {
+                visited[i] = true;
+                i = self.indices[i];
+                length += 1;
+            }
+            // 长度为 L 的轮换由 L - 1 次对换组成
+            if length > 0 && length % 2 == 0 {
+                sign = -sign;
+            }
+        }
+        sign
+    }
+
+    pub fn to_dense<T: Zero + One>(&self) -> Matrix<T, N, N> {
+        Matrix::from_fn(|i, j| {
+            if self.indices[i] == j {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    // P·M：结果第 i 行为 M 的第 indices[i] 行，只复制不做乘法
+    pub fn apply_rows<T: Clone, const C: usize>(
+        &self,
+        matrix: &Matrix<T, N, C>,
+    ) -> Matrix<T, N, C> {
+        Matrix::from_fn(|i, j| matrix[self.indices[i]][j].clone())
+    }
+
+    // M·Pᵀ：结果第 j 列为 M 的第 indices[j] 列
+    pub fn apply_cols<T: Clone, const R: usize>(
+        &self,
+        matrix: &Matrix<T, R, N>,
+    ) -> Matrix<T, R, N> {
+        Matrix::from_fn(|i, j| matrix[i][self.indices[j]].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::bench_utils::seeded_rng;
+
+    #[test]
+    fn test_matches_dense() {
+        let p = Permutation::try_from([2, 0, 1]).unwrap();
+        let q = Permutation::swap(0, 1);
+        let m = Matrix::<i32, 3, 3>::from_fn(|i, j| (i * 3 + j) as i32);
+        let dense = p.to_dense::<i32>();
+        assert_eq!(p.apply_rows(&m), dense.dot_product(&m));
+        assert_eq!(p.apply_cols(&m), m.dot_product(&dense.transpose()));
+        assert_eq!(
+            p.compose(&q).to_dense::<i32>(),
+            dense.dot_product(&q.to_dense())
+        );
+        assert_eq!(p.compose(&p.inverse()), Permutation::identity());
+        assert_eq!((p.sign(), q.sign()), (1, -1));
+
+        #[cfg(feature = "std")]
+        {
+            let shuffled = Permutation::<8>::random(&mut seeded_rng(7));
+            assert_eq!(
+                shuffled.compose(&shuffled.inverse()),
+                Permutation::default()
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_indices() {
+        assert_eq!(
+            Permutation::try_from([0, 3, 1]),
+            Err(MatrixError::IndexOutOfBounds { row: 1, col: 3 })
+        );
+        assert_eq!(
+            Permutation::try_from([1, 1, 0]),
+            Err(MatrixError::InvalidPermutation {
+                position: 1,
+                index: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::cell::Cell;
+use std::ops::Mul;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+
+use num_traits::Zero;
+
+use crate::{gemm, GemmElement, MatrixBase, OwnedMatrix, Storage};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+static NEXT_POOL_ID: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    // 当前线程所属池的编号，0 表示不是池中的工作线程
+    static CURRENT_POOL: Cell<usize> = const { Cell::new(0) };
+}
+
+// 记录尚未完成的任务数，以及其中是否有任务 panic
+struct Latch {
+    state: Mutex<(usize, bool)>,
+    done: Condvar,
+}
+
+impl Latch {
+    fn new() -> Self {
+        Latch {
+            state: Mutex::new((0, false)),
+            done: Condvar::new(),
+        }
+    }
+
+    fn count_up(&self) {
+        self.state.lock().unwrap().0 += 1;
+    }
+
+    fn count_down(&self, panicked: bool) {
+        let mut state = self.state.lock().unwrap();
+        state.0 -= 1;
+        state.1 |= panicked;
+        if state.0 == 0 {
+            self.done.notify_all();
+        }
+    }
+
+    // 返回是否有任务 panic
+    fn wait(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        while state.0 > 0 {
+            state = self.done.wait(state).unwrap();
+        }
+        state.1
+    }
+}
+
+// 离开作用域时（包括 panic 展开时）等待已提交的任务全部结束，
+// 保证任务借用的数据不会先于任务失效
+struct WaitOnDrop<'a>(&'a Latch);
+
+impl Drop for WaitOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.wait();
+    }
+}
+
+// 常驻工作线程，多次并行乘法复用同一组线程，避免每次创建和回收线程
+// 池内的任务再次使用同一个池时直接在当前线程上顺序执行，不会因等待自身而死锁
+pub struct MatrixThreadPool {
+    id: usize,
+    sender: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl MatrixThreadPool {
+    pub fn new(threads: usize) -> Self {
+        Self::try_new(threads).expect("failed to spawn matrix worker thread")
+    }
+
+    // 系统无法再创建线程时返回错误，已创建的工作线程随之退出
+    pub fn try_new(threads: usize) -> std::io::Result<Self> {
+        let threads = threads.max(1);
+        let id = NEXT_POOL_ID.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let mut pool = MatrixThreadPool {
+            id,
+            sender: Some(sender),
+            workers: Vec::with_capacity(threads),
+        };
+        for i in 0..threads {
+            let receiver = Arc::clone(&receiver);
+            let worker = std::thread::Builder::new()
+                .name(format!("matrix-worker-{}", i))
+                .spawn(move || {
+                    CURRENT_POOL.with(|pool| pool.set(id));
+                    loop {
+                        let job = receiver.lock().unwrap().recv();
+                        match job {
+                            Ok(job) => job(),
+                            Err(_) => break,
+                        }
+                    }
+                })?;
+            pool.workers.push(worker);
+        }
+        Ok(pool)
+    }
+
+    pub fn threads(&self) -> usize {
+        self.workers.len()
+    }
+
+    // 把一个独立的任务交给池中某个空闲线程，不等待它完成；池已关闭时返回 false
+    // 任务中的 panic 会结束执行它的工作线程，调用方应自行捕获
+    #[cfg(feature = "async")]
+    pub(crate) fn execute(&self, job: impl FnOnce() + Send + 'static) -> bool {
+        let sender = self.sender.as_ref().expect("pool is alive until dropped");
+        sender.send(Box::new(job)).is_ok()
+    }
+
+    // 与 parallel::for_each_chunk 语义相同，但在池中的线程上执行；所有块完成后才返回
+    pub(crate) fn for_each_chunk<T, F>(&self, data: &mut [T], chunk_len: usize, f: F)
+    where
+        T: Send,
+        F: Fn(usize, &mut [T]) + Sync,
+    {
+        if data.is_empty() {
+            return;
+        }
+        if CURRENT_POOL.with(Cell::get) == self.id {
+            data.chunks_mut(chunk_len)
+                .enumerate()
+                .for_each(|(i, chunk)| f(i, chunk));
+            return;
+        }
+        let latch = Arc::new(Latch::new());
+        let f = &f;
+        let sender = self.sender.as_ref().expect("pool is alive until dropped");
+        let guard = WaitOnDrop(&latch);
+        for (i, chunk) in data.chunks_mut(chunk_len).enumerate() {
+            let job_latch = Arc::clone(&latch);
+            let job: Box<dyn FnOnce() + Send + '_> = Box::new(move || {
+                let panicked = catch_unwind(AssertUnwindSafe(|| f(i, chunk))).is_err();
+                job_latch.count_down(panicked);
+            });
+            // SAFETY: guard 在本函数返回或 panic 展开前等待所有已提交的任务结束，
+            // 任务借用的 data 和 f 在此之前一直有效
+            let job: Job = unsafe { std::mem::transmute(job) };
+            latch.count_up();
+            if sender.send(job).is_err() {
+                // 未提交的任务随错误一起被丢弃，不会再执行
+                latch.count_down(true);
+                break;
+            }
+        }
+        drop(guard);
+        if latch.wait() {
+            panic!("a matrix worker panicked during a parallel operation");
+        }
+    }
+}
+
+impl Drop for MatrixThreadPool {
+    fn drop(&mut self) {
+        // 关闭通道后工作线程的 recv 返回错误并退出
+        self.sender.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // 按池中线程数划分行
+    pub fn dot_product_with_pool<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+        pool: &MatrixThreadPool,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone + Send + Sync + GemmElement,
+    {
+        let mut result = OwnedMatrix::<S::Family, T, X, Z>::zero();
+        let matrix0 = self.as_slice();
+        let matrix1_data = matrix1.as_slice();
+        let chunk_size = X.div_ceil(pool.threads());
+        pool.for_each_chunk(result.as_mut_slice(), chunk_size * Z, |i, chunk| {
+            let start_index = i * chunk_size;
+            let rows = chunk.len() / Z;
+            let local_matrix0 = &matrix0[start_index * Y..(start_index + rows) * Y];
+            gemm::gemm_dispatch(local_matrix0, matrix1_data, rows, Y, Z, chunk);
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_pool_reuse() {
+        let pool = MatrixThreadPool::new(3);
+        assert_eq!(pool.threads(), 3);
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9], [1, 0, 1]]);
+        let b = Matrix::from([[1, 0], [0, 1], [2, 2]]);
+        let expected = a.dot_product(&b);
+        for _ in 0..100 {
+            assert_eq!(a.dot_product_with_pool(&b, &pool), expected);
+        }
+        let d = DynMatrics::from([[1.0, 2.0]]);
+        assert_eq!(
+            d.dot_product_with_pool(&d.transpose(), &pool),
+            DynMatrics::from([[5.0]])
+        );
+    }
+
+    #[test]
+    fn test_pool_propagates_panic() {
+        let pool = MatrixThreadPool::new(2);
+        let mut data = vec![0; 8];
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            pool.for_each_chunk(&mut data, 2, |i, _| assert!(i != 1, "boom"));
+        }));
+        assert!(result.is_err());
+        // 发生 panic 后池仍然可用
+        pool.for_each_chunk(&mut data, 3, |i, chunk| chunk.fill(i));
+        assert_eq!(data, vec![0, 0, 0, 1, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn test_nested_use_runs_inline() {
+        // 任务中再次使用同一个池：单线程池若把内层任务排队会永远等待
+        let pool = MatrixThreadPool::new(1);
+        let mut data = vec![0; 4];
+        pool.for_each_chunk(&mut data, 2, |i, chunk| {
+            pool.for_each_chunk(chunk, 1, |j, x| x[0] = i * 10 + j);
+        });
+        assert_eq!(data, vec![0, 1, 10, 11]);
+    }
+}
@@ -0,0 +1,200 @@
+// Python 绑定与 NumPy 数组互转。扩展模块的构建方式：
+//   cargo rustc --release --lib --features python,pyo3/extension-module --crate-type cdylib
+// 再把 target/release/libmatrix.so 复制为 matrix.so（Windows 下为 matrix.pyd）即可 import matrix
+use numpy::ndarray::Array2;
+use numpy::{Element, IntoPyArray, PyArray2, PyReadonlyArray2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::dynamic::DynMatrix;
+use crate::{
+    dot_product_slices, MatrixBase, MatrixError, OwnedStorage, ParallelConfig, Result, Storage,
+};
+
+impl From<MatrixError> for PyErr {
+    fn from(err: MatrixError) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+fn check_shape(shape: (usize, usize), rows: usize, cols: usize) -> Result<()> {
+    for (got, expected) in [(shape.0, rows), (shape.1, cols)] {
+        if got != expected {
+            return Err(MatrixError::DimensionMismatch { expected, got });
+        }
+    }
+    Ok(())
+}
+
+fn to_pyarray<'py, T: Element>(
+    py: Python<'py>,
+    (rows, cols): (usize, usize),
+    data: Vec<T>,
+) -> Bound<'py, PyArray2<T>> {
+    Array2::from_shape_vec((rows, cols), data)
+        .expect("data has rows * cols elements")
+        .into_pyarray(py)
+}
+
+impl<T: Element + Clone, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    // 数组形状必须为 R×C，不要求内存连续
+    pub fn from_numpy(array: &PyReadonlyArray2<'_, T>) -> Result<Self>
+    where
+        S: OwnedStorage<T>,
+    {
+        let view = array.as_array();
+        check_shape(view.dim(), R, C)?;
+        Ok(MatrixBase::from_fn(|i, j| view[[i, j]].clone()))
+    }
+
+    pub fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<T>> {
+        to_pyarray(py, (R, C), self.as_slice().to_vec())
+    }
+}
+
+impl<T: Element + Clone> DynMatrix<T> {
+    pub fn from_numpy(array: &PyReadonlyArray2<'_, T>) -> Self {
+        let view = array.as_array();
+        let (rows, cols) = view.dim();
+        DynMatrix::from_fn(rows, cols, |i, j| view[[i, j]].clone())
+    }
+
+    pub fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<T>> {
+        to_pyarray(py, self.shape(), self.as_slice().to_vec())
+    }
+}
+
+// threads 为 0 时按矩阵规模和 CPU 核数自动选择
+fn resolve_threads(threads: usize, m: usize, k: usize, n: usize) -> usize {
+    match threads {
+        0 => ParallelConfig::default().threads_for(m, k, n),
+        threads => threads,
+    }
+}
+
+// 在 Python 中导出为 matrix.Matrix，元素为 float64
+#[pyclass(name = "Matrix")]
+pub struct PyMatrix {
+    inner: DynMatrix<f64>,
+}
+
+#[pymethods]
+impl PyMatrix {
+    #[new]
+    fn new(array: PyReadonlyArray2<'_, f64>) -> Self {
+        PyMatrix {
+            inner: DynMatrix::from_numpy(&array),
+        }
+    }
+
+    #[staticmethod]
+    fn zeros(rows: usize, cols: usize) -> Self {
+        PyMatrix {
+            inner: DynMatrix::zeros(rows, cols),
+        }
+    }
+
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        self.inner.shape()
+    }
+
+    fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        self.inner.to_numpy(py)
+    }
+
+    // 计算期间释放 GIL，其他 Python 线程可以继续运行
+    #[pyo3(signature = (other, threads = 0))]
+    fn dot(&self, py: Python<'_>, other: &PyMatrix, threads: usize) -> PyResult<PyMatrix> {
+        let (a, b) = (&self.inner, &other.inner);
+        let threads = resolve_threads(threads, a.rows(), a.cols(), b.cols());
+        let inner = py.detach(|| match threads {
+            1 => a.dot_product(b),
+            threads => a.dot_product_in_parallel(b, threads),
+        })?;
+        Ok(PyMatrix { inner })
+    }
+
+    fn __matmul__(&self, py: Python<'_>, other: &PyMatrix) -> PyResult<PyMatrix> {
+        self.dot(py, other, 0)
+    }
+
+    fn __repr__(&self) -> String {
+        let (rows, cols) = self.inner.shape();
+        format!("Matrix(shape=({}, {}))", rows, cols)
+    }
+}
+
+// 直接对两个 float64 二维数组做乘法并返回新数组，便于与 numpy.dot 对比
+#[pyfunction]
+#[pyo3(signature = (a, b, threads = 0))]
+fn dot_product<'py>(
+    py: Python<'py>,
+    a: PyReadonlyArray2<'py, f64>,
+    b: PyReadonlyArray2<'py, f64>,
+    threads: usize,
+) -> PyResult<Bound<'py, PyArray2<f64>>> {
+    let (a, b) = (DynMatrix::from_numpy(&a), DynMatrix::from_numpy(&b));
+    let (m, k, n) = (a.rows(), a.cols(), b.cols());
+    if b.rows() != k {
+        return Err(MatrixError::DimensionMismatch {
+            expected: k,
+            got: b.rows(),
+        }
+        .into());
+    }
+    let threads = resolve_threads(threads, m, k, n);
+    let data = py.detach(|| dot_product_slices(a.as_slice(), b.as_slice(), m, k, n, threads))?;
+    Ok(to_pyarray(py, (m, n), data))
+}
+
+#[pymodule]
+fn matrix(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMatrix>()?;
+    module.add_function(wrap_pyfunction!(dot_product, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+    use numpy::{PyArrayMethods, PyUntypedArrayMethods};
+
+    #[test]
+    fn test_py_matrix_dot() {
+        Python::initialize();
+        Python::attach(|py| {
+            let a = PyMatrix {
+                inner: DynMatrix::from([[1.0, 2.0], [3.0, 4.0]]),
+            };
+            let product = a.__matmul__(py, &a).unwrap();
+            assert_eq!(product.inner, DynMatrix::from([[7.0, 10.0], [15.0, 22.0]]));
+            assert_eq!(a.dot(py, &product, 3).unwrap().shape(), (2, 2));
+            assert!(a.dot(py, &PyMatrix::zeros(3, 1), 1).is_err());
+            assert_eq!(a.__repr__(), "Matrix(shape=(2, 2))");
+        });
+    }
+
+    #[test]
+    #[ignore = "requires numpy in the Python environment"]
+    fn test_numpy_round_trip() {
+        Python::initialize();
+        Python::attach(|py| {
+            let m = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+            let array = m.to_numpy(py);
+            assert_eq!(array.shape(), [2, 3]);
+            let readonly = array.readonly();
+            assert_eq!(Matrix::<f64, 2, 3>::from_numpy(&readonly).unwrap(), m);
+            assert!(DynMatrics::<f64, 3, 2>::from_numpy(&readonly).is_err());
+
+            let transposed = m.transpose().to_numpy(py);
+            let product = dot_product(py, readonly, transposed.readonly(), 2).unwrap();
+            assert_eq!(
+                Matrix::<f64, 2, 2>::from_numpy(&product.readonly()).unwrap(),
+                m.dot_product(&m.transpose())
+            );
+        });
+    }
+}
@@ -0,0 +1,261 @@
+use core::ops::Mul;
+
+use num_traits::Float;
+
+use crate::{Matrix, MatrixError, Result};
+
+// 单位四元数 w + xi + yj + zk 表示三维旋转，与 transform 模块相同采用右手系、列向量约定
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Quaternion<T> {
+    pub w: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// 正交性与行列式的允许误差，取机器精度的平方根
+fn tolerance<T: Float>() -> T {
+    T::epsilon().sqrt()
+}
+
+// 分量顺序为 [w, x, y, z]
+impl<T: Float> From<[T; 4]> for Quaternion<T> {
+    fn from([w, x, y, z]: [T; 4]) -> Self {
+        Quaternion { w, x, y, z }
+    }
+}
+
+impl<T: Float> Quaternion<T> {
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Quaternion { w, x, y, z }
+    }
+
+    pub fn identity() -> Self {
+        Quaternion::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    // 绕 axis 逆时针旋转 angle 弧度，axis 不必是单位向量
+    pub fn from_axis_angle(axis: [T; 3], angle: T) -> Self {
+        let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        let two = T::one() + T::one();
+        let (sin, cos) = (angle / two).sin_cos();
+        let scale = sin / norm;
+        Quaternion::new(cos, axis[0] * scale, axis[1] * scale, axis[2] * scale)
+    }
+
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        Quaternion::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    // 对单位四元数即为逆旋转
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    // 球面线性插值，t = 0 为 self，t = 1 为 other；总是沿较短的弧插值
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = *other;
+        if cos < T::zero() {
+            cos = -cos;
+            other = Quaternion::new(-other.w, -other.x, -other.y, -other.z);
+        }
+        // 夹角很小时 sin 接近零，退化为线性插值
+        let (a, b) = if cos > T::one() - tolerance() {
+            (T::one() - t, t)
+        } else {
+            let theta = cos.acos();
+            let sin = theta.sin();
+            (
+                ((T::one() - t) * theta).sin() / sin,
+                (t * theta).sin() / sin,
+            )
+        };
+        Quaternion::new(
+            a * self.w + b * other.w,
+            a * self.x + b * other.x,
+            a * self.y + b * other.y,
+            a * self.z + b * other.z,
+        )
+        .normalize()
+    }
+
+    // 先归一化，因此非单位四元数也得到合法的旋转矩阵
+    pub fn to_rotation_matrix(&self) -> Matrix<T, 3, 3> {
+        let Quaternion { w, x, y, z } = self.normalize();
+        let two = T::one() + T::one();
+        let one = T::one();
+        Matrix::from([
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ])
+    }
+
+    // 不是旋转矩阵（见 is_rotation）时返回 NotRotation
+    pub fn from_rotation_matrix(matrix: &Matrix<T, 3, 3>) -> Result<Self> {
+        if !matrix.is_rotation() {
+            return Err(MatrixError::NotRotation);
+        }
+        let m = |i: usize, j: usize| matrix[i][j];
+        let one = T::one();
+        let two = one + one;
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+        // 选取最大的对角组合开方，避免除以接近零的数
+        let q = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            Quaternion::new(
+                s / (two * two),
+                (m(2, 1) - m(1, 2)) / s,
+                (m(0, 2) - m(2, 0)) / s,
+                (m(1, 0) - m(0, 1)) / s,
+            )
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            let s = (one + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * two;
+            Quaternion::new(
+                (m(2, 1) - m(1, 2)) / s,
+                s / (two * two),
+                (m(0, 1) + m(1, 0)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+            )
+        } else if m(1, 1) > m(2, 2) {
+            let s = (one + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * two;
+            Quaternion::new(
+                (m(0, 2) - m(2, 0)) / s,
+                (m(0, 1) + m(1, 0)) / s,
+                s / (two * two),
+                (m(1, 2) + m(2, 1)) / s,
+            )
+        } else {
+            let s = (one + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * two;
+            Quaternion::new(
+                (m(1, 0) - m(0, 1)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+                (m(1, 2) + m(2, 1)) / s,
+                s / (two * two),
+            )
+        };
+        Ok(q.normalize())
+    }
+
+    // 旋转向量 v，等价于 to_rotation_matrix() 左乘 v
+    pub fn rotate(&self, v: [T; 3]) -> [T; 3] {
+        let p = Quaternion::new(T::zero(), v[0], v[1], v[2]);
+        let r = *self * p * self.conjugate();
+        [r.x, r.y, r.z]
+    }
+}
+
+// Hamilton 积：先作用 rhs 再作用 self，与矩阵乘法的顺序一致
+impl<T: Float> Mul for Quaternion<T> {
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl<T: Float> TryFrom<&Matrix<T, 3, 3>> for Quaternion<T> {
+    type Error = MatrixError;
+
+    fn try_from(matrix: &Matrix<T, 3, 3>) -> Result<Self> {
+        Quaternion::from_rotation_matrix(matrix)
+    }
+}
+
+impl<T: Float> From<Quaternion<T>> for Matrix<T, 3, 3> {
+    fn from(q: Quaternion<T>) -> Self {
+        q.to_rotation_matrix()
+    }
+}
+
+impl<T: Float> Matrix<T, 3, 3> {
+    // RᵀR = I 且 det(R) = 1，允许 sqrt(ε) 的误差；镜像（det = -1）不算旋转
+    pub fn is_rotation(&self) -> bool {
+        let m = |i: usize, j: usize| self[i][j];
+        let orthogonal = (0..3).all(|i| {
+            (0..3).all(|j| {
+                let dot = (0..3).fold(T::zero(), |sum, k| sum + m(k, i) * m(k, j));
+                let expected = if i == j { T::one() } else { T::zero() };
+                (dot - expected).abs() <= tolerance()
+            })
+        });
+        let determinant = m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+            - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+            + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0));
+        orthogonal && (determinant - T::one()).abs() <= tolerance()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let q = Quaternion::from_axis_angle([1.0, 2.0, -0.5], 2.5);
+        let r = q.to_rotation_matrix();
+        assert!(r.is_rotation());
+        let back = Quaternion::try_from(&r).unwrap();
+        // q 与 -q 表示同一个旋转
+        assert!((back.dot(&q).abs() - 1.0).abs() < 1e-12);
+
+        let v = [0.3, -1.0, 2.0];
+        let rotated = r.dot_product(&Matrix::from([[v[0]], [v[1]], [v[2]]]));
+        for (i, value) in q.rotate(v).into_iter().enumerate() {
+            assert!((value - rotated[i][0]).abs() < 1e-12);
+        }
+        assert!((q * q.conjugate())
+            .to_rotation_matrix()
+            .approx_eq(&Matrix::from(Quaternion::identity()), 1e-12));
+
+        // 绕 x 轴转 180°，迹为 -1，走对角分支
+        let flip = Quaternion::from_axis_angle([1.0, 0.0, 0.0], PI).to_rotation_matrix();
+        assert!(Quaternion::from_rotation_matrix(&flip).is_ok());
+
+        let mirror = Matrix::from([[-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert!(!mirror.is_rotation());
+        assert_eq!(
+            Quaternion::from_rotation_matrix(&mirror),
+            Err(MatrixError::NotRotation)
+        );
+    }
+
+    #[test]
+    fn test_slerp() {
+        let a = Quaternion::<f64>::identity();
+        let b = Quaternion::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2);
+        let half = a.slerp(&b, 0.5);
+        let expected = Quaternion::from_axis_angle([0.0, 0.0, 1.0], FRAC_PI_2 / 2.0);
+        assert!((half.dot(&expected) - 1.0).abs() < 1e-12);
+        assert!((a.slerp(&b, 1.0).dot(&b) - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,85 @@
+use rand::distributions::uniform::SampleUniform;
+use rand::distributions::{Distribution, Standard, Uniform};
+use rand::Rng;
+
+use crate::dynamic::DynMatrix;
+use crate::{MatrixBase, OwnedStorage};
+
+// 随机矩阵构造；传入带种子的 rng（如 bench_utils::seeded_rng）即可复现
+impl<T, const R: usize, const C: usize, S: OwnedStorage<T>> MatrixBase<T, R, C, S> {
+    // 按 rand 的 Standard 分布取值，浮点数落在 [0, 1)
+    pub fn random(rng: &mut impl Rng) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        Self::random_with(rng, Standard)
+    }
+
+    // range 可以是 lo..hi 或 lo..=hi
+    pub fn random_range(rng: &mut impl Rng, range: impl Into<Uniform<T>>) -> Self
+    where
+        T: SampleUniform,
+    {
+        Self::random_with(rng, range.into())
+    }
+
+    pub fn random_with(rng: &mut impl Rng, dist: impl Distribution<T>) -> Self {
+        MatrixBase::from_fn(|_, _| dist.sample(rng))
+    }
+}
+
+impl<T> DynMatrix<T> {
+    pub fn random(rows: usize, cols: usize, rng: &mut impl Rng) -> Self
+    where
+        Standard: Distribution<T>,
+    {
+        Self::random_with(rows, cols, rng, Standard)
+    }
+
+    pub fn random_range(
+        rows: usize,
+        cols: usize,
+        rng: &mut impl Rng,
+        range: impl Into<Uniform<T>>,
+    ) -> Self
+    where
+        T: SampleUniform,
+    {
+        Self::random_with(rows, cols, rng, range.into())
+    }
+
+    pub fn random_with(
+        rows: usize,
+        cols: usize,
+        rng: &mut impl Rng,
+        dist: impl Distribution<T>,
+    ) -> Self {
+        DynMatrix::from_fn(rows, cols, |_, _| dist.sample(rng))
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::bench_utils::seeded_rng;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+    use rand::distributions::Bernoulli;
+
+    #[test]
+    fn test_random_constructors() {
+        let a = Matrix::<f64, 4, 5>::random(&mut seeded_rng(1));
+        assert_eq!(a, Matrix::random(&mut seeded_rng(1)));
+        assert!(a.as_slice().iter().all(|x| (0.0..1.0).contains(x)));
+
+        let b = DynMatrics::<i32, 3, 3>::random_range(&mut seeded_rng(2), -5..=5);
+        assert!(b.as_slice().iter().all(|x| (-5..=5).contains(x)));
+
+        let mask =
+            DynMatrix::<bool>::random_with(2, 8, &mut seeded_rng(3), Bernoulli::new(1.0).unwrap());
+        assert_eq!(mask, DynMatrix::filled(2, 8, true));
+        let r = DynMatrix::<u8>::random_range(3, 2, &mut seeded_rng(4), 10..20);
+        assert_eq!(r.shape(), (3, 2));
+        assert!(r.as_slice().iter().all(|x| (10..20).contains(x)));
+    }
+}
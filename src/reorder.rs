@@ -0,0 +1,190 @@
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::dynamic::DynMatrix;
+use crate::permutation::{self, Permutation};
+use crate::{MatrixBase, MatrixError, Result, StorageMut};
+
+// 就地重排行优先的 rows×cols 数据，使新的第 i 行为原来的第 order[i] 行
+// 沿置换的每个环依次交换，只需 O(rows) 的额外空间；调用方保证 order 是一个排列
+fn permute_rows<T>(data: &mut [T], cols: usize, order: &[usize]) {
+    let rows = order.len();
+    let mut placed = vec![false; rows];
+    for start in 0..rows {
+        let mut i = start;
+        while !placed[i] {
+            placed[i] = true;
+            let next = order[i];
+            if next != start {
+                let (head, tail) = data.split_at_mut(i.max(next) * cols);
+                let lower = &mut head[i.min(next) * cols..][..cols];
+                lower.swap_with_slice(&mut tail[..cols]);
+            }
+            i = next;
+        }
+    }
+}
+
+// 稳定排序得到的行顺序
+fn sorted_order<T, K: Ord>(
+    data: &[T],
+    rows: usize,
+    cols: usize,
+    mut key: impl FnMut(&[T]) -> K,
+) -> Vec<usize> {
+    let keys: Vec<K> = (0..rows)
+        .map(|i| key(&data[i * cols..(i + 1) * cols]))
+        .collect();
+    let mut order: Vec<usize> = (0..rows).collect();
+    order.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+    order
+}
+
+// 不可比较的值（如 NaN）排在最后，其余按 partial_cmp 升序
+fn compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    let incomparable = |x: &T| x.partial_cmp(x).is_none();
+    match (incomparable(a), incomparable(b)) {
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        (x, y) => x.cmp(&y),
+    }
+}
+
+fn sort_by_column<T: PartialOrd>(data: &mut [T], cols: usize, col: usize) {
+    assert!(
+        col < cols,
+        "column {} out of bounds for {} columns",
+        col,
+        cols
+    );
+    let rows = data.len() / cols;
+    let mut order: Vec<usize> = (0..rows).collect();
+    order.sort_by(|&a, &b| compare(&data[a * cols + col], &data[b * cols + col]));
+    permute_rows(data, cols, &order);
+}
+
+impl<T, const R: usize, const C: usize, S: StorageMut<T>> MatrixBase<T, R, C, S> {
+    // order 必须是 0..R 的一个排列，否则不做修改并返回错误
+    pub fn reorder_rows(&mut self, order: &[usize]) -> Result<()> {
+        let order: [usize; R] = order
+            .try_into()
+            .map_err(|_| MatrixError::DimensionMismatch {
+                expected: R,
+                got: order.len(),
+            })?;
+        let permutation = Permutation::try_from(order)?;
+        permute_rows(self.as_mut_slice(), C, permutation.indices());
+        Ok(())
+    }
+
+    // 稳定排序：键相同的行保持原来的先后
+    pub fn sort_rows_by_key<K: Ord>(&mut self, key: impl FnMut(&[T]) -> K) {
+        let order = sorted_order(self.as_slice(), R, C, key);
+        permute_rows(self.as_mut_slice(), C, &order);
+    }
+
+    // 按第 col 列升序的稳定排序，NaN 排在最后
+    pub fn sort_rows_by_column(&mut self, col: usize)
+    where
+        T: PartialOrd,
+    {
+        sort_by_column(self.as_mut_slice(), C, col);
+    }
+}
+
+impl<T> DynMatrix<T> {
+    // 行数只在运行时确定，不能构造 Permutation，检查规则与 Permutation::try_from 相同
+    pub fn reorder_rows(&mut self, order: &[usize]) -> Result<()> {
+        if order.len() != self.rows() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.rows(),
+                got: order.len(),
+            });
+        }
+        permutation::check(order)?;
+        let cols = self.cols();
+        permute_rows(self.as_mut_slice(), cols, order);
+        Ok(())
+    }
+
+    pub fn sort_rows_by_key<K: Ord>(&mut self, key: impl FnMut(&[T]) -> K) {
+        let (rows, cols) = self.shape();
+        let order = sorted_order(self.as_slice(), rows, cols, key);
+        permute_rows(self.as_mut_slice(), cols, &order);
+    }
+
+    pub fn sort_rows_by_column(&mut self, col: usize)
+    where
+        T: PartialOrd,
+    {
+        let cols = self.cols();
+        sort_by_column(self.as_mut_slice(), cols, col);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_reorder_and_sort_rows() {
+        let mut m = Matrix::<i32, 4, 2>::from([[0, 0], [1, 10], [2, 20], [3, 30]]);
+        m.reorder_rows(&[2, 0, 3, 1]).unwrap();
+        assert_eq!(m, Matrix::from([[2, 20], [0, 0], [3, 30], [1, 10]]));
+        m.sort_rows_by_key(|row| row[0]);
+        assert_eq!(m, Matrix::from([[0, 0], [1, 10], [2, 20], [3, 30]]));
+
+        // 按标签列排序，相同标签保持原顺序，NaN 排在最后
+        let mut samples = DynMatrix::from([
+            [1.0, 0.5],
+            [0.0, 0.1],
+            [f64::NAN, 0.9],
+            [1.0, 0.2],
+            [0.0, 0.3],
+        ]);
+        samples.sort_rows_by_column(0);
+        let features: Vec<f64> = (0..5).map(|i| samples[i][1]).collect();
+        assert_eq!(features, [0.1, 0.3, 0.5, 0.2, 0.9]);
+
+        let mut d = DynMatrics::from([[1u8, 2, 3], [4, 5, 6]]);
+        d.sort_rows_by_key(|row| core::cmp::Reverse(row[2]));
+        assert_eq!(d, DynMatrics::from([[4, 5, 6], [1, 2, 3]]));
+    }
+
+    #[test]
+    fn test_reorder_rejects_invalid_orders() {
+        let mut m = DynMatrix::from([[1], [2], [3]]);
+        assert_eq!(
+            m.reorder_rows(&[0, 2, 0]),
+            Err(MatrixError::InvalidPermutation {
+                position: 2,
+                index: 0
+            })
+        );
+        assert_eq!(
+            m.reorder_rows(&[0, 3, 1]),
+            Err(MatrixError::IndexOutOfBounds { row: 1, col: 3 })
+        );
+        assert_eq!(
+            m.reorder_rows(&[1, 0]),
+            Err(MatrixError::DimensionMismatch {
+                expected: 3,
+                got: 2
+            })
+        );
+        // 出错时不修改矩阵
+        assert_eq!(m, DynMatrix::from([[1], [2], [3]]));
+
+        let mut s = Matrix::<i32, 2, 1>::from([[1], [2]]);
+        assert_eq!(
+            s.reorder_rows(&[1, 1]),
+            Err(MatrixError::InvalidPermutation {
+                position: 1,
+                index: 1
+            })
+        );
+        s.reorder_rows(&[1, 0]).unwrap();
+        assert_eq!(s, Matrix::from([[2], [1]]));
+    }
+}
@@ -0,0 +1,120 @@
+use core::ops::{Bound, Range, RangeBounds};
+
+use crate::dynamic::DynMatrix;
+use crate::{MatrixBase, MatrixView, Storage};
+
+// rows()/cols() 已经是 MatrixOps 中的行数、列数，区间选取用 row_range/col_range
+
+// 与切片下标相同的越界规则
+fn bounds(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "range {}..{} out of bounds for length {}",
+        start,
+        end,
+        len
+    );
+    start..end
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    // 从 start 行起连续 N 行，不复制
+    pub fn row_block<const N: usize>(&self, start: usize) -> MatrixView<'_, T, N, C> {
+        assert!(
+            start + N <= R,
+            "rows {}..{} out of bounds",
+            start,
+            start + N
+        );
+        MatrixView::from_slice(&self.as_slice()[start * C..(start + N) * C])
+            .expect("block has exactly N * C elements")
+    }
+
+    pub fn row_range(&self, range: impl RangeBounds<usize>) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        let rows = bounds(range, R);
+        let data = self.as_slice()[rows.start * C..rows.end * C].to_vec();
+        DynMatrix::new(rows.len(), C, data).expect("range has exactly rows * C elements")
+    }
+
+    pub fn col_range(&self, range: impl RangeBounds<usize>) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        let cols = bounds(range, C);
+        DynMatrix::from_fn(R, cols.len(), |i, j| self[i][cols.start + j].clone())
+    }
+
+    // 按给定顺序取出若干行，下标可以重复
+    pub fn select_rows(&self, indices: &[usize]) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        for &i in indices {
+            assert!(i < R, "row {} out of bounds", i);
+        }
+        DynMatrix::from_fn(indices.len(), C, |i, j| self[indices[i]][j].clone())
+    }
+
+    pub fn select_cols(&self, indices: &[usize]) -> DynMatrix<T>
+    where
+        T: Clone,
+    {
+        for &j in indices {
+            assert!(j < C, "column {} out of bounds", j);
+        }
+        DynMatrix::from_fn(R, indices.len(), |i, j| self[i][indices[j]].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_ranges() {
+        let a = Matrix::<i32, 4, 3>::from_fn(|i, j| (i * 3 + j) as i32);
+        let block = a.row_block::<2>(1);
+        assert_eq!(block.as_slice(), [3, 4, 5, 6, 7, 8]);
+        assert_eq!(a.row_range(1..3).as_slice(), block.as_slice());
+        assert_eq!(a.row_range(..).as_slice(), a.as_slice());
+        assert_eq!(a.row_range(4..).shape(), (0, 3));
+        assert_eq!(a.col_range(1..=2).as_slice(), [1, 2, 4, 5, 7, 8, 10, 11]);
+
+        let d = DynMatrics::from([[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(d.row_range(2..).as_slice(), [5, 6]);
+        assert_eq!(d.col_range(..1).as_slice(), [1, 3, 5]);
+    }
+
+    #[test]
+    fn test_select() {
+        let a = Matrix::<i32, 3, 3>::from_fn(|i, j| (i * 3 + j) as i32);
+        let rows = a.select_rows(&[2, 0, 2]);
+        assert_eq!(rows.shape(), (3, 3));
+        assert_eq!(rows.as_slice(), [6, 7, 8, 0, 1, 2, 6, 7, 8]);
+        assert_eq!(a.select_cols(&[1]).as_slice(), [1, 4, 7]);
+        assert_eq!(a.select_cols(&[]).shape(), (3, 0));
+
+        let d = DynMatrics::from([[1, 2], [3, 4]]);
+        assert_eq!(d.select_cols(&[1, 0]).as_slice(), [2, 1, 4, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_select_out_of_bounds() {
+        Matrix::<i32, 2, 2>::filled(0).select_rows(&[2]);
+    }
+}
@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// result[i][j] = add(...add(add(zero, mul(a[i][0], b[0][j])), mul(a[i][1], b[1][j]))...)
+// b 先转置，使内层循环在两侧都顺序访问内存
+fn semiring_product<T: Clone>(
+    a: &[T],
+    b: &[T],
+    (x, y, z): (usize, usize, usize),
+    add: impl Fn(T, T) -> T,
+    mul: impl Fn(T, T) -> T,
+    zero: T,
+) -> Vec<T> {
+    let bt: Vec<T> = (0..z * y).map(|p| b[p % y * z + p / y].clone()).collect();
+    (0..x * z)
+        .map(|p| {
+            let (row, col) = (&a[p / z * y..][..y], &bt[p % z * y..][..y]);
+            row.iter().zip(col).fold(zero.clone(), |sum, (u, v)| {
+                add(sum, mul(u.clone(), v.clone()))
+            })
+        })
+        .collect()
+}
+
+// 用户给定半环 (add, mul, zero) 上的矩阵乘法，zero 须是 add 的单位元
+// 例如 (min, +, +∞) 为热带半环、(||, &&, false) 为布尔半环、(max, *, 0) 为 max-times 半环
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    pub fn dot_product_semiring<const Z: usize, S1: Storage<T>>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, S1>,
+        add: impl Fn(T, T) -> T,
+        mul: impl Fn(T, T) -> T,
+        zero: T,
+    ) -> OwnedMatrix<S::Family, T, X, Z>
+    where
+        T: Clone,
+    {
+        let (a, b) = (self.as_slice(), matrix1.as_slice());
+        let data = semiring_product(a, b, (X, Y, Z), add, mul, zero);
+        MatrixBase::try_from(data).expect("product has exactly X * Z elements")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_semirings() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from([[7, 8], [9, 10], [11, 12]]);
+        // 普通的 (+, *, 0) 与 dot_product 一致
+        assert_eq!(
+            a.dot_product_semiring(&b, |x, y| x + y, |x, y| x * y, 0),
+            a.dot_product(&b)
+        );
+        // 热带半环 (min, +)
+        assert_eq!(
+            a.dot_product_semiring(&b, |x: i32, y| x.min(y), |x, y| x + y, i32::MAX),
+            Matrix::from([[8, 9], [11, 12]])
+        );
+        // max-times
+        assert_eq!(
+            a.dot_product_semiring(&b, |x: i32, y| x.max(y), |x, y| x * y, 0),
+            Matrix::from([[33, 36], [66, 72]])
+        );
+        // 布尔半环
+        let e = DynMatrics::from([[false, true], [false, false]]);
+        assert_eq!(
+            e.dot_product_semiring(&e, |x, y| x || y, |x, y| x && y, false),
+            DynMatrics::from([[false, false], [false, false]])
+        );
+        assert_eq!(
+            e.dot_product_semiring(&e.transpose(), |x, y| x || y, |x, y| x && y, false),
+            DynMatrics::from([[true, false], [false, false]])
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::dynamic::DynMatrix;
+use crate::{MatrixBase, MatrixError, OwnedStorage, Storage};
+
+// 序列化格式：{ "rows": R, "cols": C, "data": [按行展开的元素] }
+#[derive(Serialize)]
+struct MatrixRef<'a, T> {
+    rows: usize,
+    cols: usize,
+    data: &'a [T],
+}
+
+#[derive(Deserialize)]
+struct MatrixOwned<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> MatrixOwned<T> {
+    fn into_data<E: serde::de::Error>(self, rows: usize, cols: usize) -> Result<Vec<T>, E> {
+        if self.rows != rows {
+            return Err(E::custom(MatrixError::DimensionMismatch {
+                expected: rows,
+                got: self.rows,
+            }));
+        }
+        if self.cols != cols {
+            return Err(E::custom(MatrixError::DimensionMismatch {
+                expected: cols,
+                got: self.cols,
+            }));
+        }
+        Ok(self.data)
+    }
+}
+
+impl<T: Serialize, const R: usize, const C: usize, S: Storage<T>> Serialize
+    for MatrixBase<T, R, C, S>
+{
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        MatrixRef {
+            rows: R,
+            cols: C,
+            data: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const R: usize, const C: usize, S: OwnedStorage<T>> Deserialize<'de>
+    for MatrixBase<T, R, C, S>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = MatrixOwned::deserialize(deserializer)?.into_data(R, C)?;
+        MatrixBase::try_from(data).map_err(D::Error::custom)
+    }
+}
+
+impl<T: Serialize> Serialize for DynMatrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRef {
+            rows: self.rows(),
+            cols: self.cols(),
+            data: self.as_slice(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DynMatrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let owned = MatrixOwned::deserialize(deserializer)?;
+        DynMatrix::new(owned.rows, owned.cols, owned.data).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::{DynMatrics, DynMatrix};
+    use crate::Matrix;
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1,2,3,4,5,6]}"#);
+        assert_eq!(serde_json::from_str::<Matrix<i32, 2, 3>>(&json).unwrap(), m);
+    }
+
+    #[test]
+    fn test_dyn_round_trip() {
+        let m = DynMatrics::from([[1.5, 2.5], [3.5, 4.5]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DynMatrics<f64, 2, 2>>(&json).unwrap(),
+            m
+        );
+    }
+
+    #[test]
+    fn test_shape_validation() {
+        let json = r#"{"rows":3,"cols":2,"data":[1,2,3,4,5,6]}"#;
+        assert!(serde_json::from_str::<Matrix<i32, 2, 3>>(json).is_err());
+        let json = r#"{"rows":2,"cols":3,"data":[1,2,3,4,5]}"#;
+        assert!(serde_json::from_str::<DynMatrics<i32, 2, 3>>(json).is_err());
+    }
+
+    #[test]
+    fn test_runtime_round_trip() {
+        let m = DynMatrix::from([[1, 2, 3], [4, 5, 6]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1,2,3,4,5,6]}"#);
+        assert_eq!(serde_json::from_str::<DynMatrix<i32>>(&json).unwrap(), m);
+        let json = r#"{"rows":2,"cols":2,"data":[1,2,3]}"#;
+        assert!(serde_json::from_str::<DynMatrix<i32>>(json).is_err());
+        // rows * cols 溢出时报错而不是 panic
+        let json = r#"{"rows":4294967296,"cols":4294967296,"data":[]}"#;
+        assert!(serde_json::from_str::<DynMatrix<i32>>(json).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// 向量化内核的元素类型：y += alpha * x
+// 按 i-k-j 顺序调用时，每个结果元素的累加顺序与 dot_product 相同，浮点结果逐位一致
+pub trait SimdElement: Copy + Default {
+    fn axpy(alpha: Self, x: &[Self], y: &mut [Self]);
+}
+
+// 运行时选中的内核名称，便于基准测试确认实际走的路径
+pub fn active_kernel() -> &'static str {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx512f") {
+            return "avx512f";
+        }
+        if is_x86_feature_detected!("avx2") {
+            return "avx2";
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            return "neon";
+        }
+    }
+    "scalar"
+}
+
+fn axpy_float<T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>>(
+    alpha: T,
+    x: &[T],
+    y: &mut [T],
+) {
+    for (target, &value) in y.iter_mut().zip(x) {
+        *target = *target + alpha * value;
+    }
+}
+
+// 与向量指令一致，整数溢出时回绕
+fn axpy_i32(alpha: i32, x: &[i32], y: &mut [i32]) {
+    for (target, &value) in y.iter_mut().zip(x) {
+        *target = target.wrapping_add(alpha.wrapping_mul(value));
+    }
+}
+
+// 生成一个按 $lanes 宽度向量化、尾部回退到标量的 axpy 内核
+#[allow(unused_macros)]
+macro_rules! kernel {
+    ($name:ident, $feature:literal, $t:ty, $lanes:expr, $scalar:path,
+     $set1:ident, $load:ident, $store:ident, $mul:ident, $add:ident) => {
+        #[target_feature(enable = $feature)]
+        pub unsafe fn $name(alpha: $t, x: &[$t], y: &mut [$t]) {
+            let n = x.len().min(y.len());
+            let a = $set1(alpha);
+            let mut i = 0;
+            while i + $lanes <= n {
+                let xv = $load(x.as_ptr().add(i) as *const _);
+                let yv = $load(y.as_ptr().add(i) as *const _);
+                $store(y.as_mut_ptr().add(i) as *mut _, $add(yv, $mul(a, xv)));
+                i += $lanes;
+            }
+            $scalar(alpha, &x[i..n], &mut y[i..n]);
+        }
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    kernel!(
+        axpy_f32_avx2,
+        "avx2",
+        f32,
+        8,
+        super::axpy_float,
+        _mm256_set1_ps,
+        _mm256_loadu_ps,
+        _mm256_storeu_ps,
+        _mm256_mul_ps,
+        _mm256_add_ps
+    );
+    kernel!(
+        axpy_f64_avx2,
+        "avx2",
+        f64,
+        4,
+        super::axpy_float,
+        _mm256_set1_pd,
+        _mm256_loadu_pd,
+        _mm256_storeu_pd,
+        _mm256_mul_pd,
+        _mm256_add_pd
+    );
+    kernel!(
+        axpy_i32_avx2,
+        "avx2",
+        i32,
+        8,
+        super::axpy_i32,
+        _mm256_set1_epi32,
+        _mm256_loadu_si256,
+        _mm256_storeu_si256,
+        _mm256_mullo_epi32,
+        _mm256_add_epi32
+    );
+    kernel!(
+        axpy_f32_avx512,
+        "avx512f",
+        f32,
+        16,
+        super::axpy_float,
+        _mm512_set1_ps,
+        _mm512_loadu_ps,
+        _mm512_storeu_ps,
+        _mm512_mul_ps,
+        _mm512_add_ps
+    );
+    kernel!(
+        axpy_f64_avx512,
+        "avx512f",
+        f64,
+        8,
+        super::axpy_float,
+        _mm512_set1_pd,
+        _mm512_loadu_pd,
+        _mm512_storeu_pd,
+        _mm512_mul_pd,
+        _mm512_add_pd
+    );
+    kernel!(
+        axpy_i32_avx512,
+        "avx512f",
+        i32,
+        16,
+        super::axpy_i32,
+        _mm512_set1_epi32,
+        _mm512_loadu_si512,
+        _mm512_storeu_si512,
+        _mm512_mullo_epi32,
+        _mm512_add_epi32
+    );
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use std::arch::aarch64::*;
+
+    kernel!(
+        axpy_f32_neon,
+        "neon",
+        f32,
+        4,
+        super::axpy_float,
+        vdupq_n_f32,
+        vld1q_f32,
+        vst1q_f32,
+        vmulq_f32,
+        vaddq_f32
+    );
+    kernel!(
+        axpy_f64_neon,
+        "neon",
+        f64,
+        2,
+        super::axpy_float,
+        vdupq_n_f64,
+        vld1q_f64,
+        vst1q_f64,
+        vmulq_f64,
+        vaddq_f64
+    );
+    kernel!(
+        axpy_i32_neon,
+        "neon",
+        i32,
+        4,
+        super::axpy_i32,
+        vdupq_n_s32,
+        vld1q_s32,
+        vst1q_s32,
+        vmulq_s32,
+        vaddq_s32
+    );
+}
+
+macro_rules! impl_simd_element {
+    ($t:ty, $scalar:path, $avx2:ident, $avx512:ident, $neon:ident) => {
+        impl SimdElement for $t {
+            fn axpy(alpha: Self, x: &[Self], y: &mut [Self]) {
+                #[cfg(target_arch = "x86_64")]
+                {
+                    if is_x86_feature_detected!("avx512f") {
+                        // SAFETY: 已在运行时确认 CPU 支持 avx512f
+                        return unsafe { x86::$avx512(alpha, x, y) };
+                    }
+                    if is_x86_feature_detected!("avx2") {
+                        // SAFETY: 已在运行时确认 CPU 支持 avx2
+                        return unsafe { x86::$avx2(alpha, x, y) };
+                    }
+                }
+                #[cfg(target_arch = "aarch64")]
+                {
+                    if std::arch::is_aarch64_feature_detected!("neon") {
+                        // SAFETY: 已在运行时确认 CPU 支持 neon
+                        return unsafe { neon::$neon(alpha, x, y) };
+                    }
+                }
+                $scalar(alpha, x, y)
+            }
+        }
+    };
+}
+
+impl_simd_element!(
+    f32,
+    axpy_float,
+    axpy_f32_avx2,
+    axpy_f32_avx512,
+    axpy_f32_neon
+);
+impl_simd_element!(
+    f64,
+    axpy_float,
+    axpy_f64_avx2,
+    axpy_f64_avx512,
+    axpy_f64_neon
+);
+impl_simd_element!(i32, axpy_i32, axpy_i32_avx2, axpy_i32_avx512, axpy_i32_neon);
+
+impl<T: SimdElement, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    pub fn dot_product_simd<const Z: usize>(
+        &self,
+        matrix1: &MatrixBase<T, Y, Z, impl Storage<T>>,
+    ) -> OwnedMatrix<S::Family, T, X, Z> {
+        let mut result = OwnedMatrix::<S::Family, T, X, Z>::default();
+        let a = self.as_slice();
+        let b = matrix1.as_slice();
+        for (i, row) in result.as_mut_slice().chunks_mut(Z.max(1)).enumerate() {
+            for k in 0..Y {
+                T::axpy(a[i * Y + k], &b[k * Z..(k + 1) * Z], row);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_simd_matches_scalar() {
+        // 列数不是向量宽度的整数倍，覆盖尾部处理
+        let a: Vec<f32> = (0..5 * 19).map(|i| (i as f32 * 0.37).sin()).collect();
+        let b: Vec<f32> = (0..19 * 21).map(|i| (i as f32 * 0.11).cos()).collect();
+        let a = Matrix::<f32, 5, 19>::try_from(a).unwrap();
+        let b = Matrix::<f32, 19, 21>::try_from(b).unwrap();
+        assert_eq!(a.dot_product_simd(&b), a.dot_product(&b));
+
+        let a =
+            DynMatrics::<f64, 3, 4>::try_from((0..12).map(f64::from).collect::<Vec<_>>()).unwrap();
+        let b =
+            DynMatrics::<f64, 4, 9>::try_from((0..36).map(f64::from).collect::<Vec<_>>()).unwrap();
+        assert_eq!(a.dot_product_simd(&b), a.dot_product(&b));
+    }
+
+    #[test]
+    fn test_simd_i32() {
+        let a = Matrix::<i32, 4, 17>::try_from((0..68).collect::<Vec<_>>()).unwrap();
+        let b = Matrix::<i32, 17, 33>::try_from((0..561).map(|i| i % 7 - 3).collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(a.dot_product_simd(&b), a.dot_product(&b));
+        assert!(["avx512f", "avx2", "neon", "scalar"].contains(&active_kernel()));
+    }
+}
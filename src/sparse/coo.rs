@@ -0,0 +1,163 @@
+use alloc::vec::Vec;
+
+use num_traits::Zero;
+
+use super::{CscMatrix, CsrMatrix};
+use crate::{Matrix, MatrixError, Result};
+
+// 增量组装用的坐标格式，重复项在转换为 CSR/CSC 时累加
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T> CooMatrix<T> {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        CooMatrix {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> Self {
+        CooMatrix {
+            rows,
+            cols,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn entries(&self) -> &[(usize, usize, T)] {
+        &self.entries
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<()> {
+        if row >= self.rows || col >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds { row, col });
+        }
+        self.entries.push((row, col, value));
+        Ok(())
+    }
+
+    // 将单元矩阵散布到全局矩阵的 (row_indices[i], col_indices[j]) 位置
+    pub fn push_block<const R: usize, const C: usize>(
+        &mut self,
+        row_indices: &[usize; R],
+        col_indices: &[usize; C],
+        block: &Matrix<T, R, C>,
+    ) -> Result<()>
+    where
+        T: Clone,
+    {
+        if let Some(&row) = row_indices.iter().find(|&&row| row >= self.rows) {
+            return Err(MatrixError::IndexOutOfBounds { row, col: 0 });
+        }
+        if let Some(&col) = col_indices.iter().find(|&&col| col >= self.cols) {
+            return Err(MatrixError::IndexOutOfBounds { row: 0, col });
+        }
+        for (i, &row) in row_indices.iter().enumerate() {
+            for (j, &col) in col_indices.iter().enumerate() {
+                self.entries.push((row, col, block[i][j].clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<T> CooMatrix<T>
+where
+    T: Zero + Clone,
+{
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix::from_triplets(self.rows, self.cols, &self.entries)
+            .expect("entries are bounds-checked on push")
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let transposed: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(i, j, v)| (*j, *i, v.clone()))
+            .collect();
+        let transposed = CsrMatrix::from_triplets(self.cols, self.rows, &transposed)
+            .expect("entries are bounds-checked on push");
+        CscMatrix::from_transposed(transposed)
+    }
+}
+
+impl<T: Clone> From<&CsrMatrix<T>> for CooMatrix<T> {
+    fn from(matrix: &CsrMatrix<T>) -> Self {
+        CooMatrix {
+            rows: matrix.rows(),
+            cols: matrix.cols(),
+            entries: matrix.iter().map(|(i, j, v)| (i, j, v.clone())).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_duplicates_are_summed() {
+        let mut coo = CooMatrix::new(2, 3);
+        coo.push(1, 2, 1.5).unwrap();
+        coo.push(0, 0, 1.0).unwrap();
+        coo.push(1, 2, 2.0).unwrap();
+        assert_eq!(coo.nnz(), 3);
+        assert_eq!(
+            coo.push(2, 0, 1.0).unwrap_err(),
+            MatrixError::IndexOutOfBounds { row: 2, col: 0 }
+        );
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 2);
+        assert_eq!(csr.get(1, 2), Some(&3.5));
+
+        let csc = coo.to_csc();
+        assert_eq!(csc.col_offsets(), &[0, 1, 1, 2]);
+        assert_eq!(csc.row_indices(), &[0, 1]);
+        assert_eq!(csc.get(1, 2), Some(&3.5));
+        assert_eq!(csc.to_csr(), csr);
+    }
+
+    #[test]
+    fn test_finite_element_assembly() {
+        // 两个一维线性单元共享中间节点
+        let element = Matrix::from([[1, -1], [-1, 1]]);
+        let mut coo = CooMatrix::new(3, 3);
+        coo.push_block(&[0, 1], &[0, 1], &element).unwrap();
+        coo.push_block(&[1, 2], &[1, 2], &element).unwrap();
+        assert!(coo.push_block(&[2, 3], &[2, 3], &element).is_err());
+
+        let stiffness = Matrix::<i32, 3, 3>::try_from(&coo.to_csr()).unwrap();
+        assert_eq!(
+            stiffness,
+            Matrix::from([[1, -1, 0], [-1, 2, -1], [0, -1, 1]])
+        );
+        let dense = Matrix::<i32, 3, 3>::try_from(&coo.to_csc()).unwrap();
+        assert_eq!(dense, stiffness);
+        assert_eq!(coo.to_csc().dot_vector(&[1, 2, 3]).unwrap(), vec![-1, 0, 1]);
+    }
+}
@@ -0,0 +1,185 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use super::CsrMatrix;
+use crate::{Matrix, MatrixError, Result};
+
+// 压缩列存储，内部即转置矩阵的 CSR 表示
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    transposed: CsrMatrix<T>,
+}
+
+impl<T> CscMatrix<T> {
+    pub(crate) fn from_transposed(transposed: CsrMatrix<T>) -> Self {
+        CscMatrix { transposed }
+    }
+
+    pub fn from_csc_parts(
+        rows: usize,
+        cols: usize,
+        col_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        CsrMatrix::from_csr_parts(cols, rows, col_offsets, row_indices, values)
+            .map(Self::from_transposed)
+            .map_err(|err| match err {
+                MatrixError::IndexOutOfBounds { row, col } => {
+                    MatrixError::IndexOutOfBounds { row: col, col: row }
+                }
+                err => err,
+            })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.transposed.cols()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.transposed.rows()
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.transposed.nnz()
+    }
+
+    pub fn col_offsets(&self) -> &[usize] {
+        self.transposed.row_offsets()
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        self.transposed.col_indices()
+    }
+
+    pub fn values(&self) -> &[T] {
+        self.transposed.values()
+    }
+
+    pub fn col(&self, j: usize) -> (&[usize], &[T]) {
+        self.transposed.row(j)
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.transposed.get(j, i)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.transposed.iter().map(|(j, i, value)| (i, j, value))
+    }
+
+    // 与 CsrMatrix::transpose 一样借用 self；CSC 的转置即内部的 CSR，只需复制
+    pub fn transpose(&self) -> CsrMatrix<T>
+    where
+        T: Clone,
+    {
+        self.transposed.clone()
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    where
+        T: Clone,
+    {
+        self.transposed.transpose()
+    }
+}
+
+impl<T: Clone> From<&CsrMatrix<T>> for CscMatrix<T> {
+    fn from(matrix: &CsrMatrix<T>) -> Self {
+        CscMatrix::from_transposed(matrix.transpose())
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<&Matrix<T, R, C>> for CscMatrix<T>
+where
+    T: Zero + PartialEq + Clone,
+{
+    fn from(matrix: &Matrix<T, R, C>) -> Self {
+        CscMatrix::from_transposed(CsrMatrix::from(&matrix.transpose()))
+    }
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<&CscMatrix<T>> for Matrix<T, R, C>
+where
+    T: Zero + Clone,
+{
+    type Error = MatrixError;
+
+    fn try_from(matrix: &CscMatrix<T>) -> Result<Self> {
+        Matrix::try_from(&matrix.to_csr())
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Zero + Mul<Output = T> + Clone,
+{
+    pub fn dot_vector(&self, vector: &[T]) -> Result<Vec<T>> {
+        if vector.len() != self.cols() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.cols(),
+                got: vector.len(),
+            });
+        }
+        let mut result = vec![T::zero(); self.rows()];
+        for (i, j, value) in self.iter() {
+            result[i] = result[i].clone() + value.clone() * vector[j].clone();
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dense() -> Matrix<i32, 3, 4> {
+        Matrix::from([[1, 0, 2, 0], [0, 0, 0, 3], [0, 4, 5, 0]])
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let dense = dense();
+        let csc = CscMatrix::from(&dense);
+        assert_eq!((csc.rows(), csc.cols(), csc.nnz()), (3, 4, 5));
+        assert_eq!(csc.col_offsets(), &[0, 1, 2, 4, 5]);
+        assert_eq!(csc.row_indices(), &[0, 2, 0, 2, 1]);
+        assert_eq!(csc.get(2, 2), Some(&5));
+        assert_eq!(csc.get(1, 2), None);
+        assert_eq!(Matrix::<i32, 3, 4>::try_from(&csc).unwrap(), dense);
+        assert!(Matrix::<i32, 4, 3>::try_from(&csc).is_err());
+
+        let csr = CsrMatrix::from(&dense);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(CscMatrix::from(&csr), csc);
+        assert_eq!(csc.transpose(), CsrMatrix::from(&dense.transpose()));
+        let mut entries: Vec<_> = csc.iter().map(|(i, j, &v)| (i, j, v)).collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            csr.iter().map(|(i, j, &v)| (i, j, v)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parts_and_dot_vector() {
+        let csc = CscMatrix::from_csc_parts(
+            3,
+            4,
+            vec![0, 1, 2, 4, 5],
+            vec![0, 2, 0, 2, 1],
+            vec![1, 4, 2, 5, 3],
+        )
+        .unwrap();
+        assert_eq!(csc, CscMatrix::from(&dense()));
+        assert_eq!(csc.col(2), (&[0, 2][..], &[2, 5][..]));
+        assert_eq!(csc.dot_vector(&[1, 1, 1, 1]).unwrap(), vec![3, 3, 9]);
+        assert!(csc.dot_vector(&[1, 1, 1]).is_err());
+        assert_eq!(
+            CscMatrix::from_csc_parts(2, 2, vec![0, 1, 2], vec![0, 2], vec![1, 2]).unwrap_err(),
+            MatrixError::IndexOutOfBounds { row: 2, col: 1 }
+        );
+    }
+}
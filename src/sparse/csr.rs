@@ -0,0 +1,491 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+#[cfg(feature = "std")]
+use crate::io::mtx::MtxTriplets;
+#[cfg(feature = "std")]
+use crate::parallel;
+use crate::{Matrix, MatrixError, Result};
+
+// 压缩行存储：第 i 行的非零元为 col_indices/values[row_offsets[i]..row_offsets[i + 1]]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    rows: usize,
+    cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        CsrMatrix {
+            rows,
+            cols,
+            row_offsets: vec![0; rows + 1],
+            col_indices: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    pub fn from_csr_parts(
+        rows: usize,
+        cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self> {
+        if row_offsets.len() != rows + 1 {
+            return Err(MatrixError::DimensionMismatch {
+                expected: rows + 1,
+                got: row_offsets.len(),
+            });
+        }
+        if col_indices.len() != values.len() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: col_indices.len(),
+                got: values.len(),
+            });
+        }
+        if row_offsets[0] != 0 || row_offsets[rows] != values.len() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: values.len(),
+                got: row_offsets[rows],
+            });
+        }
+        for i in 0..rows {
+            let (start, end) = (row_offsets[i], row_offsets[i + 1]);
+            if start > end || end > col_indices.len() {
+                return Err(MatrixError::IndexOutOfBounds { row: i, col: end });
+            }
+            let row = &col_indices[start..end];
+            if let Some(&col) = row.iter().find(|&&col| col >= cols) {
+                return Err(MatrixError::IndexOutOfBounds { row: i, col });
+            }
+            if let Some(pair) = row.windows(2).find(|pair| pair[0] >= pair[1]) {
+                return Err(MatrixError::IndexOutOfBounds {
+                    row: i,
+                    col: pair[1],
+                });
+            }
+        }
+        Ok(CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        (&self.col_indices[range.clone()], &self.values[range])
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i >= self.rows {
+            return None;
+        }
+        let (cols, values) = self.row(i);
+        cols.binary_search(&j).ok().map(|index| &values[index])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.rows).flat_map(move |i| {
+            let (cols, values) = self.row(i);
+            cols.iter()
+                .zip(values)
+                .map(move |(&j, value)| (i, j, value))
+        })
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<T>
+    where
+        T: Clone,
+    {
+        let mut counts = vec![0; self.cols + 1];
+        for &j in &self.col_indices {
+            counts[j + 1] += 1;
+        }
+        for j in 0..self.cols {
+            counts[j + 1] += counts[j];
+        }
+        let row_offsets = counts.clone();
+        let mut slots: Vec<Option<(usize, T)>> = vec![None; self.nnz()];
+        for (i, j, value) in self.iter() {
+            slots[counts[j]] = Some((i, value.clone()));
+            counts[j] += 1;
+        }
+        let (col_indices, values) = slots.into_iter().map(Option::unwrap).unzip();
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Zero + Clone,
+{
+    // 重复的 (行, 列) 会被累加
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, T)]) -> Result<Self> {
+        if let Some(&(row, col, _)) = triplets.iter().find(|(i, j, _)| *i >= rows || *j >= cols) {
+            return Err(MatrixError::IndexOutOfBounds { row, col });
+        }
+        let mut sorted = triplets.to_vec();
+        sorted.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut row_offsets = vec![0; rows + 1];
+        let mut col_indices = Vec::with_capacity(sorted.len());
+        let mut values: Vec<T> = Vec::with_capacity(sorted.len());
+        let mut last = None;
+        for (i, j, value) in sorted {
+            if last == Some((i, j)) {
+                let sum = values.last_mut().unwrap();
+                *sum = sum.clone() + value;
+                continue;
+            }
+            last = Some((i, j));
+            row_offsets[i + 1] += 1;
+            col_indices.push(j);
+            values.push(value);
+        }
+        for i in 0..rows {
+            row_offsets[i + 1] += row_offsets[i];
+        }
+        Ok(CsrMatrix {
+            rows,
+            cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    pub fn dot_dense<const X: usize, const Y: usize, const Z: usize>(
+        &self,
+        matrix1: &Matrix<T, Y, Z>,
+    ) -> Result<Matrix<T, X, Z>>
+    where
+        T: Mul<Output = T>,
+    {
+        self.check_dense_shape(X, Y)?;
+        let mut result = Matrix::<T, X, Z>::zero();
+        for i in 0..X {
+            self.row_dot_dense(i, matrix1, &mut result[i]);
+        }
+        Ok(result)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn dot_dense_in_parallel<const X: usize, const Y: usize, const Z: usize>(
+        &self,
+        matrix1: &Matrix<T, Y, Z>,
+        parallel: usize,
+    ) -> Result<Matrix<T, X, Z>>
+    where
+        T: Mul<Output = T> + Send + Sync,
+    {
+        self.check_dense_shape(X, Y)?;
+        let mut result = Matrix::<T, X, Z>::zero();
+        let chunk_size = X.div_ceil(parallel::validate(parallel, X));
+        parallel::for_each_chunk(result.as_mut_slice(), chunk_size * Z, |i, chunk| {
+            for (local_index, row) in chunk.chunks_mut(Z).enumerate() {
+                self.row_dot_dense(i * chunk_size + local_index, matrix1, row);
+            }
+        });
+        Ok(result)
+    }
+
+    // 稀疏·稀疏乘法（Gustavson 算法，逐行使用稠密累加器）
+    pub fn dot(&self, matrix1: &CsrMatrix<T>) -> Result<CsrMatrix<T>>
+    where
+        T: Mul<Output = T>,
+    {
+        if self.cols != matrix1.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.cols,
+                got: matrix1.rows,
+            });
+        }
+        let mut row_offsets = Vec::with_capacity(self.rows + 1);
+        row_offsets.push(0);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        let mut accumulator = vec![T::zero(); matrix1.cols];
+        let mut occupied = vec![false; matrix1.cols];
+        let mut touched = Vec::new();
+
+        for i in 0..self.rows {
+            let (a_cols, a_values) = self.row(i);
+            for (&k, a) in a_cols.iter().zip(a_values) {
+                let (b_cols, b_values) = matrix1.row(k);
+                for (&j, b) in b_cols.iter().zip(b_values) {
+                    if !occupied[j] {
+                        occupied[j] = true;
+                        touched.push(j);
+                    }
+                    accumulator[j] = accumulator[j].clone() + a.clone() * b.clone();
+                }
+            }
+            touched.sort_unstable();
+            for &j in &touched {
+                col_indices.push(j);
+                values.push(core::mem::replace(&mut accumulator[j], T::zero()));
+                occupied[j] = false;
+            }
+            touched.clear();
+            row_offsets.push(values.len());
+        }
+
+        Ok(CsrMatrix {
+            rows: self.rows,
+            cols: matrix1.cols,
+            row_offsets,
+            col_indices,
+            values,
+        })
+    }
+
+    fn check_dense_shape(&self, rows: usize, inner: usize) -> Result<()> {
+        if self.rows != rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: rows,
+                got: self.rows,
+            });
+        }
+        if self.cols != inner {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.cols,
+                got: inner,
+            });
+        }
+        Ok(())
+    }
+
+    fn row_dot_dense<const Y: usize, const Z: usize>(
+        &self,
+        i: usize,
+        matrix1: &Matrix<T, Y, Z>,
+        out: &mut [T],
+    ) where
+        T: Mul<Output = T>,
+    {
+        let (cols, values) = self.row(i);
+        for (&k, value) in cols.iter().zip(values) {
+            for (sum, b) in out.iter_mut().zip(&matrix1[k]) {
+                *sum = sum.clone() + value.clone() * b.clone();
+            }
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<&Matrix<T, R, C>> for CsrMatrix<T>
+where
+    T: Zero + PartialEq + Clone,
+{
+    fn from(matrix: &Matrix<T, R, C>) -> Self {
+        let zero = T::zero();
+        let mut row_offsets = Vec::with_capacity(R + 1);
+        row_offsets.push(0);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+        for i in 0..R {
+            for (j, value) in matrix[i].iter().enumerate() {
+                if *value != zero {
+                    col_indices.push(j);
+                    values.push(value.clone());
+                }
+            }
+            row_offsets.push(values.len());
+        }
+        CsrMatrix {
+            rows: R,
+            cols: C,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<&CsrMatrix<T>> for Matrix<T, R, C>
+where
+    T: Zero + Clone,
+{
+    type Error = MatrixError;
+
+    fn try_from(matrix: &CsrMatrix<T>) -> Result<Self> {
+        if matrix.rows != R {
+            return Err(MatrixError::DimensionMismatch {
+                expected: R,
+                got: matrix.rows,
+            });
+        }
+        if matrix.cols != C {
+            return Err(MatrixError::DimensionMismatch {
+                expected: C,
+                got: matrix.cols,
+            });
+        }
+        let mut result = Matrix::zero();
+        for (i, j, value) in matrix.iter() {
+            result[i][j] = value.clone();
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> TryFrom<MtxTriplets<T>> for CsrMatrix<T>
+where
+    T: Zero + Clone,
+{
+    type Error = MatrixError;
+
+    fn try_from(triplets: MtxTriplets<T>) -> Result<Self> {
+        CsrMatrix::from_triplets(triplets.rows, triplets.cols, &triplets.entries)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone> From<&CsrMatrix<T>> for MtxTriplets<T> {
+    fn from(matrix: &CsrMatrix<T>) -> Self {
+        MtxTriplets {
+            rows: matrix.rows,
+            cols: matrix.cols,
+            entries: matrix.iter().map(|(i, j, v)| (i, j, v.clone())).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> CsrMatrix<i32> {
+        CsrMatrix::from_triplets(3, 3, &[(0, 0, 1), (2, 1, 3), (0, 2, 2), (2, 1, 1)]).unwrap()
+    }
+
+    #[test]
+    fn test_from_triplets() {
+        let m = sample();
+        assert_eq!(m.nnz(), 3);
+        assert_eq!(m.row_offsets(), &[0, 2, 2, 3]);
+        assert_eq!(m.col_indices(), &[0, 2, 1]);
+        assert_eq!(m.get(2, 1), Some(&4));
+        assert_eq!(m.get(1, 1), None);
+        assert_eq!(
+            CsrMatrix::from_triplets(2, 2, &[(2, 0, 1)]).unwrap_err(),
+            MatrixError::IndexOutOfBounds { row: 2, col: 0 }
+        );
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Matrix::from([[1, 0, 2], [0, 0, 0], [0, 4, 0]]);
+        let sparse = CsrMatrix::from(&dense);
+        assert_eq!(sparse, sample());
+        assert_eq!(Matrix::<i32, 3, 3>::try_from(&sparse).unwrap(), dense);
+        assert!(Matrix::<i32, 2, 3>::try_from(&sparse).is_err());
+    }
+
+    #[test]
+    fn test_dot_dense() {
+        let sparse = sample();
+        let b = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+        let expected = Matrix::from([[1, 0, 2], [0, 0, 0], [0, 4, 0]]).dot_product(&b);
+        assert_eq!(sparse.dot_dense::<3, 3, 2>(&b).unwrap(), expected);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            sparse.dot_dense_in_parallel::<3, 3, 2>(&b, 2).unwrap(),
+            expected
+        );
+        assert!(sparse.dot_dense::<2, 3, 2>(&b).is_err());
+    }
+
+    #[test]
+    fn test_dot_sparse() {
+        let a = sample();
+        let dense = Matrix::from([[1, 0, 2], [0, 0, 0], [0, 4, 0]]);
+        let product = a.dot(&a.transpose()).unwrap();
+        let transposed = Matrix::from([[1, 0, 0], [0, 0, 4], [2, 0, 0]]);
+        assert_eq!(
+            Matrix::<i32, 3, 3>::try_from(&product).unwrap(),
+            dense.dot_product(&transposed)
+        );
+    }
+
+    #[test]
+    fn test_from_csr_parts_validation() {
+        assert!(CsrMatrix::from_csr_parts(2, 2, vec![0, 1, 2], vec![1, 0], vec![1, 2]).is_ok());
+        assert!(CsrMatrix::from_csr_parts(2, 2, vec![0, 2, 2], vec![1, 0], vec![1, 2]).is_err());
+        assert!(CsrMatrix::from_csr_parts(2, 2, vec![0, 1, 2], vec![1, 2], vec![1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_non_copy_elements() {
+        use num_bigint::BigInt;
+
+        // 乘积超出 i128 范围
+        let big = BigInt::from(u128::MAX);
+        let triplets = [
+            (0, 0, big.clone()),
+            (1, 1, BigInt::from(2)),
+            (0, 0, BigInt::from(1)),
+        ];
+        let a = CsrMatrix::from_triplets(2, 2, &triplets).unwrap();
+        let square = a.dot(&a).unwrap();
+        let expected = Matrix::from([
+            [(&big + 1) * (&big + 1), BigInt::from(0)],
+            [BigInt::from(0), BigInt::from(4)],
+        ]);
+        assert_eq!(Matrix::<BigInt, 2, 2>::try_from(&square).unwrap(), expected);
+        assert_eq!(CsrMatrix::from(&expected), square);
+
+        let b = Matrix::from([[BigInt::from(1)], [big.clone()]]);
+        assert_eq!(
+            a.dot_dense::<2, 2, 1>(&b).unwrap(),
+            Matrix::from([[&big + 1], [&big * 2]])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_mtx_triplets() {
+        let triplets = MtxTriplets::from(&sample());
+        assert_eq!(triplets.entries, vec![(0, 0, 1), (0, 2, 2), (2, 1, 4)]);
+        assert_eq!(CsrMatrix::try_from(triplets).unwrap(), sample());
+    }
+}
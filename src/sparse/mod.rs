@@ -0,0 +1,7 @@
+mod coo;
+mod csc;
+mod csr;
+
+pub use coo::CooMatrix;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
@@ -0,0 +1,176 @@
+use num_traits::Float;
+
+use crate::{GemmElement, MatrixBase, OwnedMatrix, Storage};
+
+// 把 usize 样本数转成浮点
+fn count<T: Float>(n: usize) -> T {
+    T::from(n).expect("sample count fits in a float")
+}
+
+// 第一个满足 better(x, 当前最优) 的位置，相等时保留靠前的；空迭代器返回 None
+// PartialOrd 下 NaN 与任何值比较都为假，除非排在首位，否则不会被选中
+fn position_by<'a, T: 'a>(
+    values: impl Iterator<Item = &'a T>,
+    better: impl Fn(&T, &T) -> bool,
+) -> Option<usize> {
+    let mut best: Option<(usize, &T)> = None;
+    for (index, value) in values.enumerate() {
+        if best.is_none_or(|(_, current)| better(value, current)) {
+            best = Some((index, value));
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+// 行是样本、列是特征；方差与协方差使用无偏估计（除以 R - 1），R < 2 时结果为 NaN
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S>
+where
+    T: Float + GemmElement,
+{
+    pub fn column_means(&self) -> OwnedMatrix<S::Family, T, 1, C> {
+        MatrixBase::from_fn(|_, j| (0..R).fold(T::zero(), |sum, i| sum + self[i][j]) / count(R))
+    }
+
+    pub fn column_std_devs(&self) -> OwnedMatrix<S::Family, T, 1, C> {
+        let means = self.column_means();
+        MatrixBase::from_fn(|_, j| {
+            let squares = (0..R).fold(T::zero(), |sum, i| {
+                let d = self[i][j] - means[0][j];
+                sum + d * d
+            });
+            (squares / count(R - 1)).sqrt()
+        })
+    }
+
+    // 每列减去均值
+    pub fn centered(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        let means = self.column_means();
+        MatrixBase::from_fn(|i, j| self[i][j] - means[0][j])
+    }
+
+    // Xcᵀ·Xc / (R - 1)，Xc 为中心化后的数据
+    pub fn covariance_matrix(&self) -> OwnedMatrix<S::Family, T, C, C> {
+        let centered = self.centered();
+        let scatter = centered.transpose().dot_product(&centered);
+        let n = count::<T>(R) - T::one();
+        MatrixBase::from_fn(|i, j| scatter[i][j] / n)
+    }
+
+    // 皮尔逊相关系数；常数列的方差为零，对应行列为 NaN
+    pub fn correlation_matrix(&self) -> OwnedMatrix<S::Family, T, C, C> {
+        let cov = self.covariance_matrix();
+        MatrixBase::from_fn(|i, j| {
+            if i == j && cov[i][i] > T::zero() {
+                T::one()
+            } else {
+                cov[i][j] / (cov[i][i] * cov[j][j]).sqrt()
+            }
+        })
+    }
+
+    // 逐列 z-score：(x - 均值) / 标准差；常数列没有尺度可言，结果置零
+    pub fn standardize(&self) -> OwnedMatrix<S::Family, T, R, C> {
+        let (means, stds) = (self.column_means(), self.column_std_devs());
+        MatrixBase::from_fn(|i, j| {
+            if stds[0][j] == T::zero() {
+                T::zero()
+            } else {
+                (self[i][j] - means[0][j]) / stds[0][j]
+            }
+        })
+    }
+}
+
+// 分类时常用：对乘积的每一行取概率最大的列
+impl<T: PartialOrd, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    fn row_position(&self, i: usize, better: fn(&T, &T) -> bool) -> usize {
+        position_by(self[i].iter(), better).expect("argmax/argmin of an empty row")
+    }
+
+    fn col_position(&self, j: usize, better: fn(&T, &T) -> bool) -> usize {
+        position_by((0..R).map(|i| &self[i][j]), better).expect("argmax/argmin of an empty column")
+    }
+
+    pub fn row_argmax(&self) -> [usize; R] {
+        core::array::from_fn(|i| self.row_position(i, |a, b| a > b))
+    }
+
+    pub fn row_argmin(&self) -> [usize; R] {
+        core::array::from_fn(|i| self.row_position(i, |a, b| a < b))
+    }
+
+    pub fn col_argmax(&self) -> [usize; C] {
+        core::array::from_fn(|j| self.col_position(j, |a, b| a > b))
+    }
+
+    pub fn col_argmin(&self) -> [usize; C] {
+        core::array::from_fn(|j| self.col_position(j, |a, b| a < b))
+    }
+
+    // 按行优先顺序的第一个最大值所在的 (行, 列)
+    pub fn argmax(&self) -> (usize, usize) {
+        let index =
+            position_by(self.as_slice().iter(), |a, b| a > b).expect("argmax of an empty matrix");
+        (index / C, index % C)
+    }
+
+    pub fn argmin(&self) -> (usize, usize) {
+        let index =
+            position_by(self.as_slice().iter(), |a, b| a < b).expect("argmin of an empty matrix");
+        (index / C, index % C)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_covariance_and_correlation() {
+        // 第二列是第一列的两倍，第三列与第一列反向
+        let data = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 2.0], [3.0, 6.0, 1.0]]);
+        assert_eq!(data.column_means(), Matrix::from([[2.0, 4.0, 2.0]]));
+        assert_eq!(data.column_std_devs(), Matrix::from([[1.0, 2.0, 1.0]]));
+        assert_eq!(
+            data.covariance_matrix(),
+            Matrix::from([[1.0, 2.0, -1.0], [2.0, 4.0, -2.0], [-1.0, -2.0, 1.0]])
+        );
+        assert_eq!(
+            data.correlation_matrix(),
+            Matrix::from([[1.0, 1.0, -1.0], [1.0, 1.0, -1.0], [-1.0, -1.0, 1.0]])
+        );
+        let dynamic = DynMatrics::from([[1.0, 2.0, 3.0], [2.0, 4.0, 2.0], [3.0, 6.0, 1.0]]);
+        assert_eq!(
+            dynamic.covariance_matrix().as_slice(),
+            data.covariance_matrix().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_standardize() {
+        let data = Matrix::from([[1.0f32, 5.0], [3.0, 5.0], [5.0, 5.0]]);
+        let z = data.standardize();
+        assert_eq!(z, Matrix::from([[-1.0, 0.0], [0.0, 0.0], [1.0, 0.0]]));
+        // 标准化后的协方差即原数据的相关系数
+        assert_eq!(z.covariance_matrix()[0][0], 1.0);
+        assert!(data.correlation_matrix()[0][1].is_nan());
+    }
+
+    #[test]
+    fn test_argmax_argmin() {
+        let scores = Matrix::from([[0.1, 0.7, 0.2], [0.5, 0.1, 0.5], [0.3, 0.3, 0.4]]);
+        // 相等时取靠前的位置
+        assert_eq!(scores.row_argmax(), [1, 0, 2]);
+        assert_eq!(scores.row_argmin(), [0, 1, 0]);
+        assert_eq!(scores.col_argmax(), [1, 0, 1]);
+        assert_eq!(scores.col_argmin(), [0, 1, 0]);
+        assert_eq!(scores.argmax(), (0, 1));
+        assert_eq!(scores.argmin(), (0, 0));
+
+        let labels = DynMatrics::from([[3u8, 9], [9, 1]]);
+        assert_eq!(labels.argmax(), (0, 1));
+        assert_eq!(labels.argmin(), (1, 1));
+        assert_eq!(labels.col_argmax(), [1, 0]);
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(feature = "std")]
+use crate::parallel::{for_each_chunk, validate};
+use crate::{Matrix, MatrixBase, Storage};
+
+// 邻域越出矩阵时的取值方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum Boundary<T> {
+    // 越界位置取固定值，如零填充
+    Constant(T),
+    // 取最近的边缘元素
+    Clamp,
+    // 周期边界，从另一侧绕回
+    Wrap,
+}
+
+impl<T> Boundary<T> {
+    // 把越界下标映射回 [0, len)，Constant 时返回 None
+    fn resolve(&self, index: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+        match self {
+            _ if (0..len).contains(&index) => Some(index as usize),
+            Boundary::Constant(_) => None,
+            Boundary::Clamp => Some(index.clamp(0, len - 1) as usize),
+            Boundary::Wrap => Some(index.rem_euclid(len) as usize),
+        }
+    }
+}
+
+// 所有完整落在矩阵内的 KR×KC 窗口，按左上角行优先遍历
+pub struct Windows<'a, T, const KR: usize, const KC: usize> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    next: usize,
+}
+
+impl<'a, T, const KR: usize, const KC: usize> Iterator for Windows<'a, T, KR, KC> {
+    type Item = [[&'a T; KC]; KR];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let across = (self.cols + 1).checked_sub(KC)?;
+        let down = (self.rows + 1).checked_sub(KR)?;
+        if self.next >= across * down {
+            return None;
+        }
+        let (row, col) = (self.next / across, self.next % across);
+        self.next += 1;
+        let (data, cols) = (self.data, self.cols);
+        Some(core::array::from_fn(|i| {
+            core::array::from_fn(|j| &data[(row + i) * cols + col + j])
+        }))
+    }
+}
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn windows<const KR: usize, const KC: usize>(&self) -> Windows<'_, T, KR, KC> {
+        Windows {
+            data: self.as_slice(),
+            rows: R,
+            cols: C,
+            next: 0,
+        }
+    }
+
+    // 以 (row, col) 为中心的 KR×KC 邻域，中心位于 (KR / 2, KC / 2)
+    fn neighborhood<const KR: usize, const KC: usize>(
+        &self,
+        row: usize,
+        col: usize,
+        boundary: &Boundary<T>,
+    ) -> [[T; KC]; KR]
+    where
+        T: Clone,
+    {
+        core::array::from_fn(|i| {
+            let r = boundary.resolve(row as isize + i as isize - (KR / 2) as isize, R);
+            core::array::from_fn(|j| {
+                let c = boundary.resolve(col as isize + j as isize - (KC / 2) as isize, C);
+                match (r, c, boundary) {
+                    (Some(r), Some(c), _) => self[r][c].clone(),
+                    (_, _, Boundary::Constant(value)) => value.clone(),
+                    _ => unreachable!("only constant boundaries leave indices unresolved"),
+                }
+            })
+        })
+    }
+
+    // 每个输出元素由对应位置的邻域计算，如热传导的一步迭代、模糊或元胞自动机
+    pub fn stencil_apply<const KR: usize, const KC: usize, U>(
+        &self,
+        boundary: Boundary<T>,
+        f: impl Fn(&[[T; KC]; KR]) -> U,
+    ) -> Matrix<U, R, C>
+    where
+        T: Clone,
+    {
+        Matrix::from_fn(|i, j| f(&self.neighborhood(i, j, &boundary)))
+    }
+
+    // 按行分块并行
+    #[cfg(feature = "std")]
+    pub fn stencil_apply_in_parallel<const KR: usize, const KC: usize, U>(
+        &self,
+        boundary: Boundary<T>,
+        f: impl Fn(&[[T; KC]; KR]) -> U + Sync,
+        parallel: usize,
+    ) -> Matrix<U, R, C>
+    where
+        T: Clone + Sync,
+        S: Sync,
+        U: Default + Send,
+    {
+        let mut result = Matrix::<U, R, C>::default();
+        let rows_per_chunk = R.div_ceil(validate(parallel, R));
+        for_each_chunk(result.as_mut_slice(), rows_per_chunk * C, |chunk, part| {
+            for (offset, target) in part.iter_mut().enumerate() {
+                let (i, j) = (chunk * rows_per_chunk + offset / C, offset % C);
+                *target = f(&self.neighborhood(i, j, &boundary));
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+
+    #[test]
+    fn test_windows() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let sums: Vec<i32> = m
+            .windows::<2, 2>()
+            .map(|w| w.iter().flatten().copied().sum())
+            .collect();
+        assert_eq!(sums, vec![12, 16, 24, 28]);
+        assert_eq!(m.windows::<3, 1>().count(), 3);
+        assert_eq!(m.windows::<4, 1>().count(), 0);
+    }
+
+    #[test]
+    fn test_stencil_boundaries() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let sum = |n: &[[i32; 3]; 3]| n.iter().flatten().sum::<i32>();
+        assert_eq!(
+            m.stencil_apply(Boundary::Constant(0), sum),
+            Matrix::from([[12, 21, 16], [12, 21, 16]])
+        );
+        assert_eq!(
+            m.stencil_apply(Boundary::Clamp, sum),
+            Matrix::from([[21, 27, 33], [30, 36, 42]])
+        );
+        // 周期边界下每个邻域恰好覆盖所有三列
+        assert_eq!(
+            m.stencil_apply(Boundary::Wrap, sum),
+            Matrix::from([[36, 36, 36], [27, 27, 27]])
+        );
+    }
+
+    #[test]
+    fn test_heat_step_in_parallel() {
+        let mut grid = Matrix::<f64, 17, 9>::default();
+        grid[8][4] = 100.0;
+        let laplacian = |n: &[[f64; 3]; 3]| {
+            n[1][1] + 0.25 * (n[0][1] + n[2][1] + n[1][0] + n[1][2] - 4.0 * n[1][1])
+        };
+        let step = grid.stencil_apply(Boundary::Constant(0.0), laplacian);
+        assert_eq!(step[8][4], 0.0);
+        assert_eq!(step[7][4], 25.0);
+        #[cfg(feature = "std")]
+        for threads in [1, 3, 32] {
+            let parallel =
+                grid.stencil_apply_in_parallel(Boundary::Constant(0.0), laplacian, threads);
+            assert_eq!(parallel, step);
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::marker::PhantomData;
+
+use crate::{Matrix, MatrixBase, MatrixError, Result};
+
+// 矩阵元素的存储后端，元素按行优先连续存放，长度为 R * C
+// Family 决定运算结果放在哪种存储中：Vec 存储的矩阵运算后仍是 Vec 存储，其余存储的结果放在堆上数组中
+pub trait Storage<T> {
+    type Family: StorageFamily;
+
+    fn as_slice(&self) -> &[T];
+}
+
+pub trait StorageMut<T>: Storage<T> {
+    fn as_mut_slice(&mut self) -> &mut [T];
+}
+
+// 拥有元素、可以由 Vec 构造的存储
+pub trait OwnedStorage<T>: StorageMut<T> + Sized {
+    // data 的长度必须与存储的形状一致
+    fn from_vec(data: Vec<T>) -> Self;
+
+    fn into_vec(self) -> Vec<T>;
+}
+
+// 一族自有存储，Storage<T, R, C> 是其中 R×C 矩阵的存储；
+// 结果的存储仍属于同一族，因此连续运算的结果类型保持不变
+pub trait StorageFamily {
+    type Storage<T, const R: usize, const C: usize>: OwnedStorage<T> + Storage<T, Family = Self>;
+}
+
+// Box<[[T; C]; R]>，即 Matrix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxFamily;
+
+// Vec<T>，即 DynMatrics
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VecFamily;
+
+impl StorageFamily for BoxFamily {
+    type Storage<T, const R: usize, const C: usize> = Box<[[T; C]; R]>;
+}
+
+impl StorageFamily for VecFamily {
+    type Storage<T, const R: usize, const C: usize> = Vec<T>;
+}
+
+// 存储族 F 中的 R×C 矩阵，泛型算法的返回类型
+pub type OwnedMatrix<F, T, const R: usize, const C: usize> =
+    MatrixBase<T, R, C, <F as StorageFamily>::Storage<T, R, C>>;
+
+// 默认存储：堆上的定长数组
+impl<T, const R: usize, const C: usize> Storage<T> for Box<[[T; C]; R]> {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        self.as_flattened()
+    }
+}
+
+impl<T, const R: usize, const C: usize> StorageMut<T> for Box<[[T; C]; R]> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_flattened_mut()
+    }
+}
+
+impl<T, const R: usize, const C: usize> OwnedStorage<T> for Box<[[T; C]; R]> {
+    fn from_vec(data: Vec<T>) -> Self {
+        assert_eq!(data.len(), R * C, "storage needs exactly R * C elements");
+        let data = Box::into_raw(data.into_boxed_slice()) as *mut [[T; C]; R];
+        // SAFETY: 长度已校验，`[[T; C]; R]` 与 `[T; R * C]` 内存布局相同
+        unsafe { Box::from_raw(data) }
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        let data = Box::into_raw(self) as *mut T;
+        // SAFETY: 同上
+        unsafe { Box::from_raw(core::ptr::slice_from_raw_parts_mut(data, R * C)) }.into_vec()
+    }
+}
+
+// 内联数组，可用于 const/static 矩阵
+impl<T, const R: usize, const C: usize> Storage<T> for [[T; C]; R] {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        self.as_flattened()
+    }
+}
+
+impl<T, const R: usize, const C: usize> StorageMut<T> for [[T; C]; R] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_flattened_mut()
+    }
+}
+
+impl<T> Storage<T> for Vec<T> {
+    type Family = VecFamily;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut<T> for Vec<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> OwnedStorage<T> for Vec<T> {
+    fn from_vec(data: Vec<T>) -> Self {
+        data
+    }
+
+    fn into_vec(self) -> Vec<T> {
+        self
+    }
+}
+
+// 借用的切片，即矩阵视图
+impl<T> Storage<T> for &[T] {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Storage<T> for &mut [T] {
+    type Family = BoxFamily;
+
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut<T> for &mut [T] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+pub type MatrixView<'a, T, const R: usize, const C: usize> = MatrixBase<T, R, C, &'a [T]>;
+pub type MatrixViewMut<'a, T, const R: usize, const C: usize> = MatrixBase<T, R, C, &'a mut [T]>;
+pub type ArrayMatrix<T, const R: usize, const C: usize> = MatrixBase<T, R, C, [[T; C]; R]>;
+
+impl<T, const R: usize, const C: usize, S: Storage<T>> MatrixBase<T, R, C, S> {
+    pub fn from_storage(data: S) -> Result<Self> {
+        if data.as_slice().len() != R * C {
+            return Err(MatrixError::DimensionMismatch {
+                expected: R * C,
+                got: data.as_slice().len(),
+            });
+        }
+        Ok(Self {
+            data,
+            _element: PhantomData,
+        })
+    }
+
+    pub fn view(&self) -> MatrixView<'_, T, R, C> {
+        MatrixBase {
+            data: self.as_slice(),
+            _element: PhantomData,
+        }
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T, R, C>
+    where
+        S: StorageMut<T>,
+    {
+        MatrixBase {
+            data: self.as_mut_slice(),
+            _element: PhantomData,
+        }
+    }
+
+    // 复制为默认存储的矩阵
+    pub fn to_matrix(&self) -> Matrix<T, R, C>
+    where
+        T: Clone,
+    {
+        Matrix::try_from(self.as_slice().to_vec()).expect("storage has exactly R * C elements")
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> MatrixView<'a, T, R, C> {
+    pub fn from_slice(data: &'a [T]) -> Result<Self> {
+        MatrixBase::from_storage(data)
+    }
+}
+
+impl<'a, T, const R: usize, const C: usize> MatrixViewMut<'a, T, R, C> {
+    pub fn from_mut_slice(data: &'a mut [T]) -> Result<Self> {
+        MatrixBase::from_storage(data)
+    }
+}
+
+impl<T, const R: usize, const C: usize> ArrayMatrix<T, R, C> {
+    // 不经过堆分配，可以在 const 上下文中使用
+    pub const fn from_array(data: [[T; C]; R]) -> Self {
+        MatrixBase {
+            data,
+            _element: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MatrixOps;
+    use alloc::vec;
+
+    const IDENTITY: ArrayMatrix<i32, 2, 2> = ArrayMatrix::from_array([[1, 0], [0, 1]]);
+
+    #[test]
+    fn test_views_share_algorithms() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let data = [1, 0, 0, 1, 1, 1];
+        let view = MatrixView::<_, 3, 2>::from_slice(&data).unwrap();
+        assert_eq!(m.dot_product(&view), Matrix::from([[4, 5], [10, 11]]));
+        assert_eq!(view.transpose(), Matrix::from([[1, 0, 1], [0, 1, 1]]));
+        assert_eq!(view[2], [1, 1]);
+        assert_eq!(IDENTITY.dot_product(&m.view()), m);
+        assert_eq!(IDENTITY.to_matrix(), Matrix::from([[1, 0], [0, 1]]));
+        assert!(MatrixView::<i32, 2, 2>::from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_mutable_view() {
+        let mut m = Matrix::from([[1, 2], [3, 4]]);
+        let mut view = m.view_mut();
+        view[0][1] = 20;
+        assert_eq!(view[0], [1, 20]);
+        let a = Matrix::from([[1, 1], [0, 1]]);
+        a.dot_product_into(&IDENTITY, &mut view);
+        assert_eq!(m, Matrix::from([[1, 1], [0, 1]]));
+
+        let mut data = vec![0; 4];
+        let mut out = MatrixViewMut::<_, 2, 2>::from_mut_slice(&mut data).unwrap();
+        a.dot_product_into(&a, &mut out);
+        assert_eq!(MatrixOps::get(&out, 0, 1), Some(&2));
+        assert_eq!(data, vec![1, 2, 0, 1]);
+        let owned = MatrixBase::<_, 2, 2, _>::from_storage(data).unwrap();
+        assert_eq!(owned.to_matrix(), a.dot_product(&a));
+    }
+}
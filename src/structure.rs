@@ -0,0 +1,185 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Sub};
+
+use num_traits::{Float, One, Zero};
+
+use crate::{MatrixBase, OwnedMatrix, Storage};
+
+// |a - b| <= tol，只要求 Sub 与 PartialOrd，整数与浮点数都适用
+fn within<T>(a: &T, b: &T, tol: &T) -> bool
+where
+    T: Sub<Output = T> + PartialOrd + Clone,
+{
+    let diff = if a > b {
+        a.clone() - b.clone()
+    } else {
+        b.clone() - a.clone()
+    };
+    diff <= *tol
+}
+
+// 对 n×n 矩阵做 Cholesky 分解 A = L·Lᵀ，只读取下三角；主元不为正时失败
+fn cholesky_succeeds<T: Float>(a: &[T], n: usize) -> bool {
+    let mut l = Vec::with_capacity(n * n);
+    l.resize(n * n, T::zero());
+    for i in 0..n {
+        for j in 0..=i {
+            let sum = (0..j).fold(a[i * n + j], |sum, k| sum - l[i * n + k] * l[j * n + k]);
+            if i == j {
+                if sum <= T::zero() || sum.is_nan() {
+                    return false;
+                }
+                l[i * n + i] = sum.sqrt();
+            } else {
+                l[i * n + j] = sum / l[j * n + j];
+            }
+        }
+    }
+    true
+}
+
+// 分解前常用的结构变换，结果仍是稠密矩阵；紧凑存储见 triangular 与 symmetric
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S> {
+    // 保留对角线及其上方，其余置零
+    pub fn upper_triangle(&self) -> OwnedMatrix<S::Family, T, N, N>
+    where
+        T: Zero + Clone,
+    {
+        MatrixBase::from_fn(|i, j| {
+            if j >= i {
+                self[i][j].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    // 保留对角线及其下方，其余置零
+    pub fn lower_triangle(&self) -> OwnedMatrix<S::Family, T, N, N>
+    where
+        T: Zero + Clone,
+    {
+        MatrixBase::from_fn(|i, j| {
+            if j <= i {
+                self[i][j].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    // (A + Aᵀ) / 2，整数元素按 Div 的规则取整
+    pub fn symmetrize(&self) -> OwnedMatrix<S::Family, T, N, N>
+    where
+        T: Add<Output = T> + Div<Output = T> + One + Clone,
+    {
+        let two = T::one() + T::one();
+        MatrixBase::from_fn(|i, j| (self[i][j].clone() + self[j][i].clone()) / two.clone())
+    }
+
+    pub fn is_symmetric(&self, tol: T) -> bool
+    where
+        T: Sub<Output = T> + PartialOrd + Clone,
+    {
+        (0..N).all(|i| (i + 1..N).all(|j| within(&self[i][j], &self[j][i], &tol)))
+    }
+
+    pub fn is_diagonal(&self, tol: T) -> bool
+    where
+        T: Zero + Sub<Output = T> + PartialOrd + Clone,
+    {
+        let zero = T::zero();
+        (0..N).all(|i| (0..N).all(|j| i == j || within(&self[i][j], &zero, &tol)))
+    }
+
+    pub fn is_identity(&self, tol: T) -> bool
+    where
+        T: Zero + One + Sub<Output = T> + PartialOrd + Clone,
+    {
+        let one = T::one();
+        self.is_diagonal(tol.clone()) && (0..N).all(|i| within(&self[i][i], &one, &tol))
+    }
+
+    // Aᵀ·A ≈ I，逐对比较列的内积，不构造乘积矩阵
+    pub fn is_orthogonal(&self, tol: T) -> bool
+    where
+        T: Zero + One + Mul<Output = T> + Sub<Output = T> + PartialOrd + Clone,
+    {
+        (0..N).all(|i| {
+            (i..N).all(|j| {
+                let dot = (0..N).fold(T::zero(), |sum, k| {
+                    sum + self[k][i].clone() * self[k][j].clone()
+                });
+                let expected = if i == j { T::one() } else { T::zero() };
+                within(&dot, &expected, &tol)
+            })
+        })
+    }
+
+    // 对称且 Cholesky 分解成功；对称性按精确相等判断，有舍入误差时先 symmetrize
+    pub fn is_positive_definite(&self) -> bool
+    where
+        T: Float,
+    {
+        self.is_symmetric(T::zero()) && cholesky_succeeds(self.as_slice(), N)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_triangles_and_symmetry() {
+        let a = Matrix::<i32, 3, 3>::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert_eq!(
+            a.upper_triangle(),
+            Matrix::from([[1, 2, 3], [0, 5, 6], [0, 0, 9]])
+        );
+        assert_eq!(
+            a.lower_triangle(),
+            Matrix::from([[1, 0, 0], [4, 5, 0], [7, 8, 9]])
+        );
+
+        let s = a.symmetrize();
+        assert_eq!(s, Matrix::from([[1, 3, 5], [3, 5, 7], [5, 7, 9]]));
+        assert!(s.is_symmetric(0));
+        assert!(!a.is_symmetric(1));
+        assert!(a.is_symmetric(4));
+
+        let d = DynMatrics::from([[1.0, 2.0], [2.0 + 1e-9, 3.0]]);
+        assert!(!d.is_symmetric(0.0));
+        assert!(d.is_symmetric(1e-6));
+        assert!(d.symmetrize().is_symmetric(0.0));
+    }
+
+    #[test]
+    fn test_structural_predicates() {
+        let identity = Matrix::<f64, 3, 3>::one();
+        assert!(identity.is_identity(0.0));
+        assert!(identity.is_orthogonal(0.0));
+        assert!(identity.is_positive_definite());
+
+        let d = DynMatrics::from([[2.0, 1e-9], [0.0, -3.0]]);
+        assert!(!d.is_diagonal(0.0));
+        assert!(d.is_diagonal(1e-6));
+        assert!(!d.is_identity(1e-6));
+        assert!(!d.is_positive_definite());
+
+        // 旋转与置换都是正交矩阵
+        let (s, c) = (0.6, 0.8);
+        let rotation = Matrix::from([[c, -s], [s, c]]);
+        assert!(rotation.is_orthogonal(1e-12));
+        assert!(!Matrix::<f64, 2, 2>::from_fn(|i, j| rotation[i][j] * 2.0).is_orthogonal(1e-12));
+        let swap = Matrix::<i32, 2, 2>::from([[0, 1], [1, 0]]);
+        assert!(swap.is_orthogonal(0));
+        assert!(!swap.is_identity(0));
+
+        // 特征值为 1 和 3；[[1, 2], [2, 1]] 的特征值为 3 和 -1
+        assert!(Matrix::from([[2.0, 1.0], [1.0, 2.0]]).is_positive_definite());
+        assert!(!Matrix::from([[1.0, 2.0], [2.0, 1.0]]).is_positive_definite());
+        assert!(!Matrix::from([[1.0, 1.0], [1.0, 1.0]]).is_positive_definite());
+    }
+}
@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::triangular::UpperTriangular;
+use crate::{Matrix, MatrixError, Result};
+
+// 只存储上三角，(i, j) 与 (j, i) 共用同一元素
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SymmetricMatrix<T, const N: usize> {
+    upper: UpperTriangular<T, N>,
+}
+
+impl<T, const N: usize> SymmetricMatrix<T, N> {
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.upper.get(i.min(j), i.max(j))
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.upper.get_mut(i.min(j), i.max(j))
+    }
+
+    pub fn as_packed_slice(&self) -> &[T] {
+        self.upper.as_packed_slice()
+    }
+
+    fn at(&self, i: usize, j: usize) -> &T {
+        self.get(i, j).unwrap()
+    }
+
+    pub fn to_dense(&self) -> Matrix<T, N, N>
+    where
+        T: Zero + Clone,
+    {
+        let mut result = Matrix::zero();
+        for i in 0..N {
+            for j in 0..N {
+                result[i][j] = self.at(i, j).clone();
+            }
+        }
+        result
+    }
+
+    pub fn dot_product<const Z: usize>(&self, matrix1: &Matrix<T, N, Z>) -> Matrix<T, N, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let mut result = Matrix::<T, N, Z>::zero();
+        for i in 0..N {
+            for k in 0..N {
+                let value = self.at(i, k);
+                for (sum, b) in result[i].iter_mut().zip(&matrix1[k]) {
+                    *sum = sum.clone() + value.clone() * b.clone();
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T: Clone, const N: usize> From<&Matrix<T, N, N>> for SymmetricMatrix<T, N> {
+    // 只读取上三角部分
+    fn from(matrix: &Matrix<T, N, N>) -> Self {
+        SymmetricMatrix {
+            upper: UpperTriangular::from(matrix),
+        }
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for SymmetricMatrix<T, N> {
+    type Error = MatrixError;
+
+    fn try_from(data: Vec<T>) -> Result<Self> {
+        Ok(SymmetricMatrix {
+            upper: UpperTriangular::try_from(data)?,
+        })
+    }
+}
+
+fn packed_upper<T, const N: usize>(entry: impl Fn(usize, usize) -> T) -> SymmetricMatrix<T, N> {
+    let data: Vec<T> = (0..N)
+        .flat_map(|i| (i..N).map(move |j| (i, j)))
+        .map(|(i, j)| entry(i, j))
+        .collect();
+    SymmetricMatrix {
+        upper: UpperTriangular::try_from(data).expect("packed length is N * (N + 1) / 2"),
+    }
+}
+
+impl<T, const X: usize, const Y: usize> Matrix<T, X, Y> {
+    // A·Aᵀ：行向量两两内积，只计算上三角
+    pub fn gram_rows(&self) -> SymmetricMatrix<T, X>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        packed_upper(|i, j| {
+            self[i]
+                .iter()
+                .zip(&self[j])
+                .fold(T::zero(), |sum, (a, b)| sum + a.clone() * b.clone())
+        })
+    }
+
+    // Aᵀ·A：列向量两两内积，只计算上三角
+    pub fn gram_cols(&self) -> SymmetricMatrix<T, Y>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        packed_upper(|i, j| {
+            (0..X).fold(T::zero(), |sum, k| {
+                sum + self[k][i].clone() * self[k][j].clone()
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_storage() {
+        let m = Matrix::from([[1, 2, 3], [2, 4, 5], [3, 5, 6]]);
+        let s = SymmetricMatrix::from(&m);
+        assert_eq!(s.as_packed_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(s.get(2, 0), s.get(0, 2));
+        assert_eq!(s.to_dense(), m);
+        let b = Matrix::from([[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(s.dot_product(&b), m.dot_product(&b));
+    }
+
+    #[test]
+    fn test_gram() {
+        let a = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        let at = Matrix::from([[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(a.gram_rows().to_dense(), a.dot_product(&at));
+        assert_eq!(a.gram_cols().to_dense(), at.dot_product(&a));
+        assert_eq!(a.gram_rows().as_packed_slice().len(), 3);
+    }
+}
@@ -0,0 +1,70 @@
+use core::ops::Mul;
+
+use num_traits::Zero;
+
+use crate::{MatrixBase, Storage};
+
+// A 的第 i 行与 B 的第 i 列的内积，即 (A·B)[i][i]
+fn diag_entry<T, const Y: usize>(row: &[T], b: &[T], i: usize, x: usize) -> T
+where
+    T: Zero + Mul<Output = T> + Clone,
+{
+    (0..Y).fold(T::zero(), |sum, k| {
+        sum + row[k].clone() * b[k * x + i].clone()
+    })
+}
+
+// 只算乘积的对角线，O(X·Y) 次乘法且不分配 X×X 的结果
+impl<T, const N: usize, S: Storage<T>> MatrixBase<T, N, N, S> {
+    pub fn trace(&self) -> T
+    where
+        T: Zero + Clone,
+    {
+        (0..N).fold(T::zero(), |sum, i| sum + self[i][i].clone())
+    }
+}
+
+impl<T, const X: usize, const Y: usize, S: Storage<T>> MatrixBase<T, X, Y, S> {
+    // tr(A·B)，B 为 Y×X；tr(Aᵀ·B) 即 Frobenius 内积
+    pub fn trace_of_product(&self, matrix1: &MatrixBase<T, Y, X, impl Storage<T>>) -> T
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let b = matrix1.as_slice();
+        (0..X).fold(T::zero(), |sum, i| {
+            sum + diag_entry::<T, Y>(&self[i], b, i, X)
+        })
+    }
+
+    // diag(A·B)
+    pub fn diag_of_product(&self, matrix1: &MatrixBase<T, Y, X, impl Storage<T>>) -> [T; X]
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let b = matrix1.as_slice();
+        core::array::from_fn(|i| diag_entry::<T, Y>(&self[i], b, i, X))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dynamic::DynMatrics;
+    use crate::Matrix;
+
+    #[test]
+    fn test_matches_full_product() {
+        let a = Matrix::<i32, 3, 4>::from_fn(|i, j| (i * 4 + j) as i32 - 5);
+        let b = Matrix::<i32, 4, 3>::from_fn(|i, j| (i * j) as i32 % 3 - 1);
+        let product = a.dot_product(&b);
+        assert_eq!(a.trace_of_product(&b), product.trace());
+        assert_eq!(
+            a.diag_of_product(&b),
+            [product[0][0], product[1][1], product[2][2]]
+        );
+
+        let d = DynMatrics::from([[1.0, 2.0], [3.0, 4.0]]);
+        // Frobenius 内积 <D, D> = tr(Dᵀ·D)
+        assert_eq!(d.transpose().trace_of_product(&d), 30.0);
+        assert_eq!(d.diag_of_product(&d), [7.0, 22.0]);
+    }
+}
@@ -0,0 +1,219 @@
+use num_traits::Float;
+
+use crate::Matrix;
+
+// 图形学常用的齐次变换矩阵：右手坐标系，列向量约定（变换作用为 M·p，先作用的矩阵写在右边）
+// 二维变换为 3×3，三维变换为 4×4；角度均为弧度
+
+pub type Transform2 = Matrix<f32, 3, 3>;
+pub type Transform3 = Matrix<f32, 4, 4>;
+
+pub fn translation_2d(x: f32, y: f32) -> Transform2 {
+    Matrix::from([[1.0, 0.0, x], [0.0, 1.0, y], [0.0, 0.0, 1.0]])
+}
+
+// 逆时针旋转
+pub fn rotation_2d(angle: f32) -> Transform2 {
+    let (sin, cos) = Float::sin_cos(angle);
+    Matrix::from([[cos, -sin, 0.0], [sin, cos, 0.0], [0.0, 0.0, 1.0]])
+}
+
+pub fn scaling_2d(x: f32, y: f32) -> Transform2 {
+    Matrix::from([[x, 0.0, 0.0], [0.0, y, 0.0], [0.0, 0.0, 1.0]])
+}
+
+pub fn translation(x: f32, y: f32, z: f32) -> Transform3 {
+    Matrix::from([
+        [1.0, 0.0, 0.0, x],
+        [0.0, 1.0, 0.0, y],
+        [0.0, 0.0, 1.0, z],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+// 绕各坐标轴旋转，从轴的正方向看去为逆时针
+pub fn rotation_x(angle: f32) -> Transform3 {
+    let (sin, cos) = Float::sin_cos(angle);
+    Matrix::from([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, cos, -sin, 0.0],
+        [0.0, sin, cos, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+pub fn rotation_y(angle: f32) -> Transform3 {
+    let (sin, cos) = Float::sin_cos(angle);
+    Matrix::from([
+        [cos, 0.0, sin, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [-sin, 0.0, cos, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+pub fn rotation_z(angle: f32) -> Transform3 {
+    let (sin, cos) = Float::sin_cos(angle);
+    Matrix::from([
+        [cos, -sin, 0.0, 0.0],
+        [sin, cos, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+pub fn scaling(x: f32, y: f32, z: f32) -> Transform3 {
+    Matrix::from([
+        [x, 0.0, 0.0, 0.0],
+        [0.0, y, 0.0, 0.0],
+        [0.0, 0.0, z, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+// 透视投影，与 OpenGL 的 gluPerspective 相同：相机看向 -z，
+// 视锥体映射到 [-1, 1]³ 的裁剪空间，near 平面映射到 z = -1；fov_y 为竖直方向视角
+pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Transform3 {
+    let f = 1.0 / Float::tan(fov_y / 2.0);
+    let depth = near - far;
+    Matrix::from([
+        [f / aspect, 0.0, 0.0, 0.0],
+        [0.0, f, 0.0, 0.0],
+        [0.0, 0.0, (far + near) / depth, 2.0 * far * near / depth],
+        [0.0, 0.0, -1.0, 0.0],
+    ])
+}
+
+// 视图矩阵：把 eye 移到原点、视线方向 target - eye 转到 -z、up 转到 +y 所在平面
+pub fn look_at(eye: [f32; 3], target: [f32; 3], up: [f32; 3]) -> Transform3 {
+    let forward = normalize(sub(target, eye));
+    let side = normalize(cross(forward, up));
+    let up = cross(side, forward);
+    Matrix::from([
+        [side[0], side[1], side[2], -dot(side, eye)],
+        [up[0], up[1], up[2], -dot(up, eye)],
+        [-forward[0], -forward[1], -forward[2], dot(forward, eye)],
+        [0.0, 0.0, 0.0, 1.0],
+    ])
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = Float::sqrt(dot(v, v));
+    [v[0] / length, v[1] / length, v[2] / length]
+}
+
+// 齐次坐标 (p, w) 左乘 M，返回结果的前 N - 1 个分量与 w
+fn apply<const N: usize, const M: usize>(
+    matrix: &Matrix<f32, N, N>,
+    v: [f32; M],
+    w: f32,
+) -> ([f32; M], f32) {
+    let row = |i: usize| (0..M).fold(matrix[i][M] * w, |sum, j| sum + matrix[i][j] * v[j]);
+    (core::array::from_fn(row), row(M))
+}
+
+impl Transform2 {
+    // 点的 w = 1，受平移影响；结果除以 w
+    pub fn transform_point(&self, point: [f32; 2]) -> [f32; 2] {
+        let (p, w) = apply(self, point, 1.0);
+        p.map(|value| value / w)
+    }
+
+    // 方向向量的 w = 0，不受平移影响
+    pub fn transform_vector(&self, vector: [f32; 2]) -> [f32; 2] {
+        apply(self, vector, 0.0).0
+    }
+}
+
+impl Transform3 {
+    // 经过透视投影后即为透视除法得到的标准化设备坐标
+    pub fn transform_point(&self, point: [f32; 3]) -> [f32; 3] {
+        let (p, w) = apply(self, point, 1.0);
+        p.map(|value| value / w)
+    }
+
+    pub fn transform_vector(&self, vector: [f32; 3]) -> [f32; 3] {
+        apply(self, vector, 0.0).0
+    }
+
+    // 不做透视除法，返回完整的齐次坐标，供光栅化器在裁剪空间中裁剪
+    pub fn transform_homogeneous(&self, point: [f32; 4]) -> [f32; 4] {
+        core::array::from_fn(|i| (0..4).fold(0.0, |sum, j| sum + self[i][j] * point[j]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    fn assert_close<const N: usize>(a: [f32; N], b: [f32; N]) {
+        for (x, y) in a.iter().zip(&b) {
+            assert!((x - y).abs() < 1e-5, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_transforms_2d() {
+        let m = translation_2d(1.0, 2.0).dot_product(&rotation_2d(FRAC_PI_2));
+        assert_close(m.transform_point([1.0, 0.0]), [1.0, 3.0]);
+        assert_close(m.transform_vector([1.0, 0.0]), [0.0, 1.0]);
+        assert_close(scaling_2d(2.0, 3.0).transform_point([1.0, 1.0]), [2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_transforms_3d() {
+        assert_close(
+            rotation_x(FRAC_PI_2).transform_vector([0.0, 1.0, 0.0]),
+            [0.0, 0.0, 1.0],
+        );
+        assert_close(
+            rotation_y(FRAC_PI_2).transform_vector([0.0, 0.0, 1.0]),
+            [1.0, 0.0, 0.0],
+        );
+        assert_close(
+            rotation_z(FRAC_PI_2).transform_vector([1.0, 0.0, 0.0]),
+            [0.0, 1.0, 0.0],
+        );
+        let m = translation(1.0, 2.0, 3.0).dot_product(&scaling(2.0, 2.0, 2.0));
+        assert_close(m.transform_point([1.0, 1.0, 1.0]), [3.0, 4.0, 5.0]);
+        assert_close(m.transform_vector([1.0, 1.0, 1.0]), [2.0, 2.0, 2.0]);
+
+        // 相机在 (0, 0, 5) 看向原点，原点落在视线正前方 5 个单位处
+        let view = look_at([0.0, 0.0, 5.0], [0.0; 3], [0.0, 1.0, 0.0]);
+        assert_close(view.transform_point([0.0; 3]), [0.0, 0.0, -5.0]);
+        assert_close(view.transform_point([1.0, 0.0, 5.0]), [1.0, 0.0, 0.0]);
+
+        // near、far 平面分别映射到 z = -1、1
+        let projection = perspective(FRAC_PI_2, 2.0, 1.0, 10.0);
+        assert_close(
+            projection.transform_point([0.0, 1.0, -1.0]),
+            [0.0, 1.0, -1.0],
+        );
+        assert_close(
+            projection.transform_point([2.0, 0.0, -10.0]),
+            [0.1, 0.0, 1.0],
+        );
+        assert_eq!(
+            projection.transform_homogeneous([0.0, 0.0, -2.0, 1.0])[3],
+            2.0
+        );
+    }
+}
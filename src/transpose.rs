@@ -0,0 +1,59 @@
+use core::ops::Range;
+
+use crate::gemm::Output;
+
+// 分块边长：源与目标各一个 TILE×TILE 的块能同时留在 L1 中，
+// 逐元素转置时两侧总有一侧按列跨步访问，分块后跨步只发生在块内
+const TILE: usize = 32;
+
+// 转置行优先的 rows×cols 矩阵 src，只计算结果的 out_rows 这些行（即 src 的这些列）
+// out 只包含这些行，长度为 out_rows.len() * rows，可以尚未初始化
+pub(crate) fn transpose_rows<T, S>(
+    src: &[T],
+    rows: usize,
+    cols: usize,
+    out_rows: Range<usize>,
+    out: &mut [S],
+) where
+    T: Clone,
+    S: Output<T>,
+{
+    let start = out_rows.start;
+    for jb in out_rows.clone().step_by(TILE) {
+        let j_end = (jb + TILE).min(out_rows.end);
+        for ib in (0..rows).step_by(TILE) {
+            let i_end = (ib + TILE).min(rows);
+            for j in jb..j_end {
+                let target = &mut out[(j - start) * rows..][ib..i_end];
+                for (slot, i) in target.iter_mut().zip(ib..i_end) {
+                    slot.store(src[i * cols + j].clone(), true);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+
+    #[test]
+    fn test_transpose_large() {
+        // 行列都不是 TILE 的整数倍，覆盖边缘的不完整分块
+        let a = Matrix::<u32, 70, 45>::from_fn(|i, j| (i * 45 + j) as u32);
+        let expected = Matrix::<u32, 45, 70>::from_fn(|i, j| a[j][i]);
+        assert_eq!(a.transpose(), expected);
+
+        let mut out = Matrix::<u32, 45, 70>::default();
+        a.transpose_into(&mut out);
+        assert_eq!(out, expected);
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(a.transpose_in_parallel(4), expected);
+            let d =
+                crate::dynamic::DynMatrics::<u32, 70, 45>::try_from(a.clone().into_vec()).unwrap();
+            assert_eq!(d.transpose_in_parallel(3).as_slice(), expected.as_slice());
+        }
+    }
+}
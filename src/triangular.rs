@@ -0,0 +1,224 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Sub};
+
+use num_traits::Zero;
+
+use crate::{Matrix, MatrixError, Result};
+
+// 按行紧凑存储三角部分，共 N * (N + 1) / 2 个元素
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TriangularMatrix<T, const N: usize, const LOWER: bool> {
+    data: Vec<T>,
+}
+
+pub type LowerTriangular<T, const N: usize> = TriangularMatrix<T, N, true>;
+pub type UpperTriangular<T, const N: usize> = TriangularMatrix<T, N, false>;
+
+impl<T, const N: usize, const LOWER: bool> TriangularMatrix<T, N, LOWER> {
+    const LEN: usize = N * (N + 1) / 2;
+
+    fn contains(i: usize, j: usize) -> bool {
+        i < N && j < N && if LOWER { j <= i } else { j >= i }
+    }
+
+    fn offset(i: usize, j: usize) -> usize {
+        if LOWER {
+            i * (i + 1) / 2 + j
+        } else {
+            i * N - i * i.saturating_sub(1) / 2 + (j - i)
+        }
+    }
+
+    // 第 i 行中三角部分的列范围
+    fn row_range(i: usize) -> core::ops::Range<usize> {
+        if LOWER {
+            0..i + 1
+        } else {
+            i..N
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        Self::contains(i, j).then(|| &self.data[Self::offset(i, j)])
+    }
+
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        Self::contains(i, j).then(|| &mut self.data[Self::offset(i, j)])
+    }
+
+    pub fn as_packed_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn to_dense(&self) -> Matrix<T, N, N>
+    where
+        T: Zero + Clone,
+    {
+        let mut result = Matrix::zero();
+        for i in 0..N {
+            for j in Self::row_range(i) {
+                result[i][j] = self.data[Self::offset(i, j)].clone();
+            }
+        }
+        result
+    }
+
+    fn transposed<const OUT: bool>(&self) -> TriangularMatrix<T, N, OUT>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(Self::LEN);
+        for i in 0..N {
+            for j in TriangularMatrix::<T, N, OUT>::row_range(i) {
+                data.push(self.data[Self::offset(j, i)].clone());
+            }
+        }
+        TriangularMatrix { data }
+    }
+
+    // 只遍历三角部分，乘法次数约为稠密乘法的一半
+    pub fn dot_product<const Z: usize>(&self, matrix1: &Matrix<T, N, Z>) -> Matrix<T, N, Z>
+    where
+        T: Zero + Mul<Output = T> + Clone,
+    {
+        let mut result = Matrix::<T, N, Z>::zero();
+        for i in 0..N {
+            for k in Self::row_range(i) {
+                let value = &self.data[Self::offset(i, k)];
+                for (sum, b) in result[i].iter_mut().zip(&matrix1[k]) {
+                    *sum = sum.clone() + value.clone() * b.clone();
+                }
+            }
+        }
+        result
+    }
+
+    // 下三角前向代入，上三角回代，求解 T·X = B
+    pub fn solve_triangular<const Z: usize>(&self, b: &Matrix<T, N, Z>) -> Result<Matrix<T, N, Z>>
+    where
+        T: Zero
+            + PartialEq
+            + Add<Output = T>
+            + Sub<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+            + Clone,
+    {
+        let mut result = b.clone();
+        let order: Vec<usize> = if LOWER {
+            (0..N).collect()
+        } else {
+            (0..N).rev().collect()
+        };
+        for i in order {
+            let pivot = &self.data[Self::offset(i, i)];
+            if *pivot == T::zero() {
+                return Err(MatrixError::Singular);
+            }
+            for k in Self::row_range(i).filter(|&k| k != i) {
+                let factor = &self.data[Self::offset(i, k)];
+                for z in 0..Z {
+                    result[i][z] = result[i][z].clone() - factor.clone() * result[k][z].clone();
+                }
+            }
+            for value in result[i].iter_mut() {
+                *value = value.clone() / pivot.clone();
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl<T: Clone, const N: usize> LowerTriangular<T, N> {
+    pub fn transpose(&self) -> UpperTriangular<T, N> {
+        self.transposed()
+    }
+}
+
+impl<T: Clone, const N: usize> UpperTriangular<T, N> {
+    pub fn transpose(&self) -> LowerTriangular<T, N> {
+        self.transposed()
+    }
+}
+
+impl<T: Clone, const N: usize, const LOWER: bool> From<&Matrix<T, N, N>>
+    for TriangularMatrix<T, N, LOWER>
+{
+    // 忽略另一侧三角的元素
+    fn from(matrix: &Matrix<T, N, N>) -> Self {
+        let mut data = Vec::with_capacity(Self::LEN);
+        for i in 0..N {
+            for j in Self::row_range(i) {
+                data.push(matrix[i][j].clone());
+            }
+        }
+        TriangularMatrix { data }
+    }
+}
+
+impl<T, const N: usize, const LOWER: bool> TryFrom<Vec<T>> for TriangularMatrix<T, N, LOWER> {
+    type Error = MatrixError;
+
+    fn try_from(data: Vec<T>) -> Result<Self> {
+        if data.len() != Self::LEN {
+            return Err(MatrixError::DimensionMismatch {
+                expected: Self::LEN,
+                got: data.len(),
+            });
+        }
+        Ok(TriangularMatrix { data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_packed_layout() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let lower = LowerTriangular::from(&m);
+        let upper = UpperTriangular::from(&m);
+        assert_eq!(lower.as_packed_slice(), &[1, 4, 5, 7, 8, 9]);
+        assert_eq!(upper.as_packed_slice(), &[1, 2, 3, 5, 6, 9]);
+        assert_eq!(lower.get(2, 1), Some(&8));
+        assert_eq!(lower.get(1, 2), None);
+        assert_eq!(upper.get(1, 2), Some(&6));
+        assert_eq!(
+            lower.transpose().to_dense(),
+            Matrix::from([[1, 4, 7], [0, 5, 8], [0, 0, 9]])
+        );
+    }
+
+    #[test]
+    fn test_dot_product_matches_dense() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let b = Matrix::from([[1, 0], [2, 1], [0, 3]]);
+        let lower = LowerTriangular::from(&m);
+        let upper = UpperTriangular::from(&m);
+        assert_eq!(lower.dot_product(&b), lower.to_dense().dot_product(&b));
+        assert_eq!(upper.dot_product(&b), upper.to_dense().dot_product(&b));
+    }
+
+    #[test]
+    fn test_solve_triangular() {
+        let lower =
+            LowerTriangular::<f64, 3>::try_from(vec![2.0, 1.0, 4.0, 3.0, 2.0, 5.0]).unwrap();
+        let b = Matrix::from([[2.0, 4.0], [9.0, 6.0], [15.0, 22.0]]);
+        let x = lower.solve_triangular(&b).unwrap();
+        assert_eq!(lower.dot_product(&x), b);
+
+        let upper = lower.transpose();
+        let x = upper.solve_triangular(&b).unwrap();
+        assert!(upper.dot_product(&x).approx_eq(&b, 1e-12));
+
+        let singular = UpperTriangular::<f64, 2>::try_from(vec![1.0, 2.0, 0.0]).unwrap();
+        assert_eq!(
+            singular
+                .solve_triangular(&Matrix::from([[1.0], [1.0]]))
+                .unwrap_err(),
+            MatrixError::Singular
+        );
+    }
+}
@@ -0,0 +1,106 @@
+// 浏览器端使用的绑定，构建后用 wasm-bindgen 生成 JS 胶水代码：
+//   cargo rustc --release --lib --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//   wasm-bindgen --target web target/wasm32-unknown-unknown/release/matrix.wasm --out-dir pkg
+use js_sys::Float64Array;
+use wasm_bindgen::prelude::*;
+
+use crate::dynamic::DynMatrix;
+
+// 在 JavaScript 中导出为 Matrix，元素为 f64，按行优先存放
+#[wasm_bindgen(js_name = Matrix)]
+pub struct JsMatrix {
+    inner: DynMatrix<f64>,
+}
+
+#[wasm_bindgen(js_class = Matrix)]
+impl JsMatrix {
+    // data 通常传 Float64Array，长度必须为 rows * cols，否则抛出 Error
+    #[wasm_bindgen(constructor)]
+    pub fn new(rows: usize, cols: usize, data: &[f64]) -> Result<JsMatrix, JsError> {
+        Ok(JsMatrix {
+            inner: DynMatrix::new(rows, cols, data.to_vec())?,
+        })
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> JsMatrix {
+        JsMatrix {
+            inner: DynMatrix::zeros(rows, cols),
+        }
+    }
+
+    pub fn identity(n: usize) -> JsMatrix {
+        JsMatrix {
+            inner: DynMatrix::from_fn(n, n, |i, j| if i == j { 1.0 } else { 0.0 }),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.inner.rows()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        self.inner.cols()
+    }
+
+    // 越界时返回 undefined
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        self.inner.get(row, col).copied()
+    }
+
+    pub fn transpose(&self) -> JsMatrix {
+        JsMatrix {
+            inner: self.inner.transpose(),
+        }
+    }
+
+    // 形状不匹配时抛出 Error
+    pub fn multiply(&self, other: &JsMatrix) -> Result<JsMatrix, JsError> {
+        Ok(JsMatrix {
+            inner: self.inner.dot_product(&other.inner)?,
+        })
+    }
+
+    // 复制一份行优先数据到 JavaScript 堆
+    #[wasm_bindgen(js_name = toFloat64Array)]
+    pub fn to_float64_array(&self) -> Float64Array {
+        Float64Array::from(self.inner.as_slice())
+    }
+}
+
+impl From<DynMatrix<f64>> for JsMatrix {
+    fn from(inner: DynMatrix<f64>) -> Self {
+        JsMatrix { inner }
+    }
+}
+
+impl From<JsMatrix> for DynMatrix<f64> {
+    fn from(matrix: JsMatrix) -> Self {
+        matrix.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 只测不经过 JS 胶水代码的路径，构造 JsError 与 Float64Array 需要真正的 wasm 运行时
+    #[test]
+    fn test_js_matrix() {
+        let a = JsMatrix::new(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!((a.rows(), a.cols()), (2, 3));
+        assert_eq!(a.get(1, 2), Some(6.0));
+        assert_eq!(a.get(2, 0), None);
+
+        let product = a.multiply(&a.transpose()).unwrap();
+        assert_eq!(
+            DynMatrix::from(product),
+            DynMatrix::from([[14.0, 32.0], [32.0, 77.0]])
+        );
+        let same = JsMatrix::identity(2)
+            .multiply(&JsMatrix::zeros(2, 2))
+            .unwrap();
+        assert_eq!(DynMatrix::from(same), DynMatrix::zeros(2, 2));
+    }
+}